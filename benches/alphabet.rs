@@ -0,0 +1,52 @@
+extern crate bencher;
+
+use fpe::alphabet::{Alphabet, CharAlphabet};
+
+fn lookup(b: &mut bencher::Bencher, alpha: &CharAlphabet) {
+    let letters: Vec<char> = (0..alpha.len())
+        .step_by(7)
+        .map(|i| alpha.char_at(i).unwrap())
+        .collect();
+    b.iter(|| {
+        letters
+            .iter()
+            .map(|c| alpha.position_of(*c).unwrap())
+            .sum::<usize>()
+    });
+}
+
+fn table(b: &mut bencher::Bencher) {
+    lookup(
+        b,
+        &CharAlphabet::new(fpe::alphabet::PRINTABLE_ASCII).unwrap(),
+    );
+}
+
+fn runs(b: &mut bencher::Bencher) {
+    lookup(
+        b,
+        &CharAlphabet::from_ranges(&[
+            '\u{3400}'..='\u{4dbf}',
+            '\u{4e00}'..='\u{9fff}',
+        ])
+        .unwrap(),
+    );
+}
+
+fn scattered(b: &mut bencher::Bencher) {
+    // every other code point, so that each letter is its own run
+    let letters: String = ('\u{4e00}'..='\u{9fff}').step_by(2).collect();
+    lookup(b, &CharAlphabet::new(&letters).unwrap());
+}
+
+fn constant_time(b: &mut bencher::Bencher) {
+    lookup(
+        b,
+        &CharAlphabet::new(fpe::alphabet::PRINTABLE_ASCII)
+            .unwrap()
+            .constant_time(),
+    );
+}
+
+bencher::benchmark_group!(benches, table, runs, scattered, constant_time);
+bencher::benchmark_main!(benches);
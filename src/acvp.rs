@@ -0,0 +1,458 @@
+//! Evaluation of ACVP test vectors
+//!
+//! NIST's Automated Cryptographic Validation Protocol (ACVP) publishes
+//! test vectors for FF1 (`ACVP-AES-FF1`) and FF3-1 (`ACVP-AES-FF3-1`)
+//! as a pair of JSON documents: the prompt, which contains the inputs
+//! of each test, and the expected results. A [`VectorSet`] combines
+//! the two and runs each test against a context, producing a
+//! [`Report`] of the outcome.
+//!
+//! Only the algorithm functional tests (AFT) are supported. Either
+//! document may be a bare object or an array containing the object,
+//! as returned by the ACVP server.
+//!
+//! # Example
+//! ```rust
+//! use fpe::acvp::VectorSet;
+//!
+//! let prompt = r#"{
+//!     "algorithm": "ACVP-AES-FF1",
+//!     "testGroups": [{
+//!         "tgId": 1, "testType": "AFT", "direction": "encrypt",
+//!         "keyLen": 128, "radix": 10, "alphabet": "0123456789",
+//!         "tests": [{
+//!             "tcId": 1, "key": "2B7E151628AED2A6ABF7158809CF4F3C",
+//!             "tweak": "", "tweakLen": 0, "pt": "0123456789"
+//!         }]
+//!     }]
+//! }"#;
+//! let expected = r#"{
+//!     "testGroups": [{
+//!         "tgId": 1,
+//!         "tests": [{ "tcId": 1, "ct": "2433477484" }]
+//!     }]
+//! }"#;
+//!
+//! let report = VectorSet::parse(prompt, expected).unwrap().run();
+//! assert!(report.is_success() && report.total() == 1);
+//! ```
+
+use crate::cipher::{Algorithm, FormatPreservingCipher};
+use crate::error::{Error, ErrorKind};
+use crate::ff1::FF1;
+use crate::ff3_1::FF3_1;
+use crate::key::{self, SecretKey};
+use crate::result::Result;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// Whether a test encrypts or decrypts its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+/// A single test from a vector set
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    /// The id of the group containing the test
+    pub tg_id: u64,
+    /// The id of the test
+    pub tc_id: u64,
+    pub direction: Direction,
+    pub key: SecretKey,
+    pub tweak: Vec<u8>,
+    pub radix: usize,
+    pub alphabet: String,
+    /// The plaintext when encrypting, or the ciphertext when decrypting
+    pub input: String,
+    /// The expected output of the test
+    pub expected: String,
+}
+
+/// A test that did not produce the expected result
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub tg_id: u64,
+    pub tc_id: u64,
+    pub expected: String,
+    /// The output of the test, or the error that it produced
+    pub actual: Result<String>,
+}
+
+/// The outcome of running a vector set
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub algorithm: Algorithm,
+    /// The number of tests that produced the expected result
+    pub passed: usize,
+    pub failures: Vec<Failure>,
+}
+
+impl Report {
+    /// The number of tests that were run
+    pub fn total(&self) -> usize {
+        self.passed + self.failures.len()
+    }
+
+    /// Whether every test produced the expected result
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} of {} tests passed",
+            self.algorithm,
+            self.passed,
+            self.total()
+        )?;
+        for x in &self.failures {
+            write!(f, "\n  tgId {} tcId {}: ", x.tg_id, x.tc_id)?;
+            match &x.actual {
+                Ok(s) => write!(f, "expected {}, got {}", x.expected, s)?,
+                Err(e) => write!(f, "{}", e)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The tests of an ACVP vector set, with their expected results
+#[derive(Debug, Clone)]
+pub struct VectorSet {
+    algorithm: Algorithm,
+    cases: Vec<TestCase>,
+}
+
+impl VectorSet {
+    /// Combine the prompt and expected results of a vector set
+    ///
+    /// Every test in the prompt must have an expected result.
+    pub fn parse(prompt: &str, expected: &str) -> Result<Self> {
+        let prompt: PromptFile = document(prompt)?;
+        let expected: ExpectedFile = document(expected)?;
+
+        let algorithm = match prompt.algorithm.as_str() {
+            "ACVP-AES-FF1" => Algorithm::FF1,
+            "ACVP-AES-FF3-1" => Algorithm::FF3_1,
+            a => {
+                return Err(vector_error(format!(
+                    "unsupported algorithm: {}",
+                    a
+                )))
+            }
+        };
+
+        let mut results = BTreeMap::new();
+        for g in expected.test_groups {
+            for t in g.tests {
+                results.insert((g.tg_id, t.tc_id), t);
+            }
+        }
+
+        let mut cases = Vec::new();
+        for g in prompt.test_groups {
+            if g.test_type.as_deref().is_some_and(|t| t != "AFT") {
+                return Err(vector_error(format!(
+                    "unsupported test type in group {}",
+                    g.tg_id
+                )));
+            }
+            let direction = match g.direction.as_str() {
+                "encrypt" => Direction::Encrypt,
+                "decrypt" => Direction::Decrypt,
+                d => {
+                    return Err(vector_error(format!(
+                        "unknown direction: {}",
+                        d
+                    )))
+                }
+            };
+            let radix = g.radix.unwrap_or(g.alphabet.chars().count());
+
+            for t in g.tests {
+                let id = format!("group {} test {}", g.tg_id, t.tc_id);
+                let key = SecretKey::from(
+                    key::hex_decode(&t.key)
+                        .map_err(|_| vector_error(format!("key of {}", id)))?,
+                );
+                let tweak = key::hex_decode(&t.tweak)
+                    .map_err(|_| vector_error(format!("tweak of {}", id)))?
+                    .to_vec();
+                if g.key_len.is_some_and(|n| n != 8 * key.len())
+                    || t.tweak_len.is_some_and(|n| n != 8 * tweak.len())
+                {
+                    return Err(vector_error(format!("lengths of {}", id)));
+                }
+
+                let res =
+                    results.remove(&(g.tg_id, t.tc_id)).ok_or_else(|| {
+                        vector_error(format!("no result for {}", id))
+                    })?;
+                let (input, expected) = match direction {
+                    Direction::Encrypt => (t.pt, res.ct),
+                    Direction::Decrypt => (t.ct, res.pt),
+                };
+
+                cases.push(TestCase {
+                    tg_id: g.tg_id,
+                    tc_id: t.tc_id,
+                    direction,
+                    key,
+                    tweak,
+                    radix,
+                    alphabet: g.alphabet.clone(),
+                    input: input.ok_or_else(|| {
+                        vector_error(format!("input of {}", id))
+                    })?,
+                    expected: expected.ok_or_else(|| {
+                        vector_error(format!("result of {}", id))
+                    })?,
+                });
+            }
+        }
+
+        Ok(VectorSet { algorithm, cases })
+    }
+
+    /// The algorithm tested by the vector set
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// The tests in the vector set
+    pub fn cases(&self) -> &[TestCase] {
+        &self.cases
+    }
+
+    /// Run each test against a context created by the library
+    ///
+    /// The contexts are created without the restrictions of
+    /// [FIPS mode](crate::fips), if enabled.
+    pub fn run(&self) -> Report {
+        let algorithm = self.algorithm;
+        self.run_with(|tc| {
+            Ok(match algorithm {
+                Algorithm::FF1 => Box::new(
+                    FF1::builder()
+                        .key(&tc.key)
+                        .radix(tc.radix)
+                        .alphabet(tc.alphabet.as_str())
+                        .build_unapproved()?,
+                ),
+                Algorithm::FF3_1 => Box::new(
+                    FF3_1::builder()
+                        .key(&tc.key)
+                        .radix(tc.radix)
+                        .alphabet(tc.alphabet.as_str())
+                        .build_unapproved()?,
+                ),
+            })
+        })
+    }
+
+    /// Run each test against a context created by the caller
+    ///
+    /// The function is called for each test to create the context with
+    /// which the test is run. The tweak of the test is supplied to the
+    /// context with the input, so the context needn't have a default.
+    pub fn run_with<F>(&self, create: F) -> Report
+    where
+        F: Fn(&TestCase) -> Result<Box<dyn FormatPreservingCipher>>,
+    {
+        let mut report = Report {
+            algorithm: self.algorithm,
+            passed: 0,
+            failures: Vec::new(),
+        };
+
+        for tc in &self.cases {
+            let actual = create(tc).and_then(|ctx| match tc.direction {
+                Direction::Encrypt => ctx.encrypt(&tc.input, Some(&tc.tweak)),
+                Direction::Decrypt => ctx.decrypt(&tc.input, Some(&tc.tweak)),
+            });
+
+            match actual {
+                Ok(ref s) if *s == tc.expected => report.passed += 1,
+                _ => report.failures.push(Failure {
+                    tg_id: tc.tg_id,
+                    tc_id: tc.tc_id,
+                    expected: tc.expected.clone(),
+                    actual,
+                }),
+            }
+        }
+
+        report
+    }
+}
+
+fn vector_error(reason: impl ToString) -> Error {
+    Error::new(ErrorKind::InvalidTestVectors {
+        reason: reason.to_string(),
+    })
+}
+
+// parse a document that is either the object itself or an array
+// containing the object (along with, e.g., the acvp version)
+fn document<T: DeserializeOwned>(s: &str) -> Result<T> {
+    let v: serde_json::Value = serde_json::from_str(s).map_err(vector_error)?;
+    let v = match v {
+        serde_json::Value::Array(a) => a
+            .into_iter()
+            .find(|v| v.get("testGroups").is_some())
+            .ok_or_else(|| vector_error("no test groups"))?,
+        v => v,
+    };
+    serde_json::from_value(v).map_err(vector_error)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptFile {
+    algorithm: String,
+    test_groups: Vec<PromptGroup>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptGroup {
+    tg_id: u64,
+    test_type: Option<String>,
+    direction: String,
+    key_len: Option<usize>,
+    radix: Option<usize>,
+    alphabet: String,
+    tests: Vec<PromptCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptCase {
+    tc_id: u64,
+    key: String,
+    tweak: String,
+    tweak_len: Option<usize>,
+    pt: Option<String>,
+    ct: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpectedFile {
+    test_groups: Vec<ExpectedGroup>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpectedGroup {
+    tg_id: u64,
+    tests: Vec<ExpectedCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpectedCase {
+    tc_id: u64,
+    pt: Option<String>,
+    ct: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROMPT: &str = r#"[
+        {"acvVersion": "1.0"},
+        {
+            "vsId": 1,
+            "algorithm": "ACVP-AES-FF3-1",
+            "testGroups": [{
+                "tgId": 3,
+                "testType": "AFT",
+                "direction": "decrypt",
+                "keyLen": 128,
+                "radix": 10,
+                "alphabet": "0123456789",
+                "tests": [
+                    {
+                        "tcId": 7,
+                        "key": "AD41EC5D2356DEAE53AE76F50B4BA6D2",
+                        "tweak": "CF29DA1E18D970",
+                        "tweakLen": 56,
+                        "ct": "4716569208"
+                    },
+                    {
+                        "tcId": 8,
+                        "key": "AD41EC5D2356DEAE53AE76F50B4BA6D2",
+                        "tweak": "CF29DA1E18D970",
+                        "tweakLen": 56,
+                        "ct": "4716569208"
+                    }
+                ]
+            }]
+        }
+    ]"#;
+    const EXPECTED: &str = r#"{
+        "testGroups": [{
+            "tgId": 3,
+            "tests": [
+                {"tcId": 7, "pt": "6520935496"},
+                {"tcId": 8, "pt": "6520935497"}
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn report() -> Result<()> {
+        let vs = VectorSet::parse(PROMPT, EXPECTED)?;
+        assert!(vs.algorithm() == Algorithm::FF3_1);
+        assert!(vs.cases().len() == 2);
+        assert!(vs.cases()[0].direction == Direction::Decrypt);
+
+        let report = vs.run();
+        assert!(!report.is_success());
+        assert!(report.passed == 1 && report.total() == 2);
+        assert!(report.failures[0].tc_id == 8);
+        assert!(
+            report.failures[0].actual.as_deref().ok() == Some("6520935496")
+        );
+        assert!(report.to_string().contains("tgId 3 tcId 8"));
+
+        // failure to create the context is reported per test
+        let report = vs.run_with(|_| {
+            Err(Error::new(ErrorKind::MissingParameter { name: "key" }))
+        });
+        assert!(report.passed == 0 && report.failures.len() == 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() {
+        let missing = EXPECTED.replace("\"tcId\": 8", "\"tcId\": 9");
+        let bad_len = PROMPT.replace("\"keyLen\": 128", "\"keyLen\": 256");
+        let bad_alg = PROMPT.replace("FF3-1", "FF2");
+
+        for (p, e) in [
+            (PROMPT, missing.as_str()),
+            (bad_len.as_str(), EXPECTED),
+            (bad_alg.as_str(), EXPECTED),
+            ("[]", EXPECTED),
+        ] {
+            assert!(matches!(
+                VectorSet::parse(p, e).err().unwrap().kind(),
+                ErrorKind::InvalidTestVectors { .. }
+            ));
+        }
+    }
+}
@@ -1,65 +1,84 @@
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
+use crate::memory::{LockStatus, Protected};
 use crate::result::Result;
 
-use aes;
-use cbc;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
 
-use aes::cipher::BlockEncryptMut;
-use aes::cipher::BlockSizeUser;
-use aes::cipher::KeyIvInit;
-
-#[derive(Clone)]
-enum CbcType {
-    Aes128(cbc::Encryptor<aes::Aes128>),
-    Aes192(cbc::Encryptor<aes::Aes192>),
-    Aes256(cbc::Encryptor<aes::Aes256>),
+// the key schedules of these types are wiped when
+// dropped (via the zeroize feature of the aes crate)
+enum BlockCipher {
+    Aes128(aes::Aes128),
+    Aes192(aes::Aes192),
+    Aes256(aes::Aes256),
 }
 
-#[derive(Clone)]
+// the expanded key is held in protected memory when the
+// mlock feature is enabled. see the memory module
 pub struct Cipher {
-    enc: CbcType,
-    blksz: usize,
-}
-
-macro_rules! construct_cipher {
-    ($type:ident, $key:expr, $iv:expr) => {
-        Cipher {
-            blksz: aes::$type::block_size(),
-            enc: CbcType::$type(cbc::Encryptor::<aes::$type>::new(
-                $key.into(),
-                $iv.into(),
-            )),
-        }
-    };
+    enc: Protected<BlockCipher>,
 }
 
 impl Cipher {
     pub fn new(key: &[u8]) -> Result<Cipher> {
-        const IV: &[u8] = &[0u8; 16];
+        let enc = match key.len() {
+            16 => BlockCipher::Aes128(aes::Aes128::new(key.into())),
+            24 => BlockCipher::Aes192(aes::Aes192::new(key.into())),
+            32 => BlockCipher::Aes256(aes::Aes256::new(key.into())),
+            _ => {
+                return Err(Error::new(ErrorKind::InvalidKey {
+                    length: key.len(),
+                }))
+            }
+        };
 
-        Ok(match key.len() {
-            16 => construct_cipher!(Aes128, key, IV),
-            24 => construct_cipher!(Aes192, key, IV),
-            32 => construct_cipher!(Aes256, key, IV),
-            _ => return Err(Error::new("invalid key length")),
+        Ok(Cipher {
+            enc: Protected::new(enc),
         })
     }
 
-    pub fn encrypt_block(&mut self, src: &[u8], dst: &mut [u8]) {
-        match &mut self.enc {
-            CbcType::Aes128(e) => {
-                e.encrypt_block_b2b_mut(src.into(), dst.into())
+    // encrypts a single block (i.e. aes-ecb)
+    pub fn encrypt_block(&self, src: &[u8], dst: &mut [u8]) {
+        match &*self.enc {
+            BlockCipher::Aes128(e) => {
+                e.encrypt_block_b2b(src.into(), dst.into())
+            }
+            BlockCipher::Aes192(e) => {
+                e.encrypt_block_b2b(src.into(), dst.into())
+            }
+            BlockCipher::Aes256(e) => {
+                e.encrypt_block_b2b(src.into(), dst.into())
+            }
+        }
+    }
+
+    // decrypts a single block, as needed for key unwrapping
+    pub fn decrypt_block(&self, src: &[u8], dst: &mut [u8]) {
+        match &*self.enc {
+            BlockCipher::Aes128(e) => {
+                e.decrypt_block_b2b(src.into(), dst.into())
             }
-            CbcType::Aes192(e) => {
-                e.encrypt_block_b2b_mut(src.into(), dst.into())
+            BlockCipher::Aes192(e) => {
+                e.decrypt_block_b2b(src.into(), dst.into())
             }
-            CbcType::Aes256(e) => {
-                e.encrypt_block_b2b_mut(src.into(), dst.into())
+            BlockCipher::Aes256(e) => {
+                e.decrypt_block_b2b(src.into(), dst.into())
             }
         }
     }
 
     pub fn block_size(&self) -> usize {
-        self.blksz
+        16
+    }
+
+    pub fn key_len(&self) -> usize {
+        match &*self.enc {
+            BlockCipher::Aes128(_) => 16,
+            BlockCipher::Aes192(_) => 24,
+            BlockCipher::Aes256(_) => 32,
+        }
+    }
+
+    pub fn lock_status(&self) -> LockStatus {
+        self.enc.status()
     }
 }
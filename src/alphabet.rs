@@ -1,102 +1,876 @@
-use crate::error::Error;
+//! Alphabets of characters used for plaintext and ciphertext
+//!
+//! An alphabet is an ordered set of letters. Each letter's position
+//! within the alphabet is the numerical value of that letter when
+//! the input is converted to a number by the encryption algorithms.
+//!
+//! The [`Alphabet`] trait may be implemented by callers who wish to
+//! supply an alphabet that is, for example, defined by a function
+//! rather than a list of letters. The [`CharAlphabet`] structure
+//! implements the trait for alphabets specified as a string or
+//! built from ranges of Unicode code points, and [`SymbolAlphabet`]
+//! implements it for alphabets whose letters consist of more than
+//! one `char`.
+
+use crate::error::{Error, ErrorKind};
 use crate::result::Result;
 
-const DEFAULT_ALPHABET: &str =
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+pub mod input;
+pub mod unicode;
+
+/// The alphabet used when none is supplied by the caller
+pub const DEFAULT_ALPHABET: &str =
     "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
-struct Letter {
-    val: char,
+/// The decimal digits
+pub const DIGITS: &str = "0123456789";
+/// Hexadecimal digits, using lowercase letters
+pub const HEX_LOWER: &str = "0123456789abcdef";
+/// Hexadecimal digits, using uppercase letters
+pub const HEX_UPPER: &str = "0123456789ABCDEF";
+/// The base32 alphabet from RFC 4648
+pub const BASE32: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+/// Douglas Crockford's base32 alphabet
+pub const CROCKFORD_BASE32: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// The base58 alphabet used by Bitcoin
+pub const BASE58: &str =
+    "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// The URL- and filename-safe base64 alphabet from RFC 4648
+pub const BASE64URL: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+/// The printable ASCII characters, from space (0x20) to tilde (0x7e)
+pub const PRINTABLE_ASCII: &str = concat!(
+    " !\"#$%&'()*+,-./0123456789:;<=>?",
+    "@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_",
+    "`abcdefghijklmnopqrstuvwxyz{|}~",
+);
+/// The digits and lowercase letters. When used via
+/// [`Preset::AlphanumericCaseInsensitive`], uppercase
+/// letters are accepted as input as well
+pub const ALPHANUMERIC: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// An ordered set of letters
+///
+/// Implementations must ensure that `char_at` and `position_of` are
+/// inverses of each other for all positions less than `len`.
+///
+/// Letters consisting of more than one `char` are supported by
+/// overriding `match_prefix` and `write_letter`, which are used to
+/// split the input into letters and to produce the output. These
+/// must be inverses of each other as well. This is verified when an
+/// alphabet is used to create a context.
+pub trait Alphabet: Send + Sync {
+    /// The number of letters in the alphabet
+    fn len(&self) -> usize;
+
+    /// Whether the alphabet contains no letters
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The letter at the given position, or None if the position
+    /// is not less than the length of the alphabet
+    fn char_at(&self, pos: usize) -> Option<char>;
+
+    /// The position of the given letter, or None if the letter
+    /// is not part of the alphabet
+    fn position_of(&self, c: char) -> Option<usize>;
+
+    /// Match the letter at the beginning of the input
+    ///
+    /// Returns the position of the letter within the alphabet and the
+    /// length of the letter in bytes, or None if the input does not
+    /// begin with a letter from the alphabet. The default treats each
+    /// `char` as a letter.
+    fn match_prefix(&self, s: &str) -> Option<(usize, usize)> {
+        let c = s.chars().next()?;
+        self.position_of(c).map(|pos| (pos, c.len_utf8()))
+    }
+
+    /// Append the letter at the given position to the output
+    ///
+    /// Returns false if there is no letter at the given position.
+    fn write_letter(&self, pos: usize, out: &mut String) -> bool {
+        match self.char_at(pos) {
+            Some(c) => {
+                out.push(c);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether finding a letter takes the same amount of time
+    /// regardless of the letter
+    ///
+    /// [`InputOptions`](input::InputOptions) match the input using
+    /// lookups of their own, which are not constant time, so they
+    /// can't be used with such an alphabet.
+    fn is_constant_time(&self) -> bool {
+        false
+    }
+}
+
+/// Conversion into an alphabet
+///
+/// This trait is implemented for any [`Alphabet`] as well as for
+/// strings (and optional strings) of letters, so that the context
+/// constructors can accept any of them. `None` selects the
+/// [`DEFAULT_ALPHABET`].
+pub trait IntoAlphabet {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>>;
+}
+
+impl<A: Alphabet + 'static> IntoAlphabet for A {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>> {
+        Ok(Arc::new(self))
+    }
+}
+
+impl IntoAlphabet for Arc<dyn Alphabet> {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>> {
+        Ok(self)
+    }
+}
+
+// letters repeated in the string are only an error if they fall
+// within the radix, which isn't known until the context is created.
+// see limit()
+impl IntoAlphabet for &str {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>> {
+        Ok(Arc::new(CharAlphabet::from_vec(
+            self.chars().collect(),
+            false,
+        )?))
+    }
+}
+
+impl IntoAlphabet for Option<&str> {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>> {
+        self.unwrap_or(DEFAULT_ALPHABET).into_alphabet()
+    }
+}
+
+// a sequence of consecutive code points whose positions within the
+// alphabet are also consecutive, beginning with `pos`
+struct Run {
+    start: u32,
+    len: u32,
     pos: usize,
 }
 
-pub struct Alphabet {
+// marks an entry of a lookup table that isn't a letter
+const NO_LETTER: u16 = u16::MAX;
+
+// the structure used to find the position of a letter
+enum Lookup {
+    // a table indexed by code point, used when all
+    // letters are ASCII or Latin-1 characters
+    Table(Box<[u16; 256]>),
+    // runs of consecutive letters sorted by code point. alphabets
+    // built from ranges of code points consist of only a few runs
+    Runs(Vec<Run>),
+    // a scan of every letter that takes the same amount of time
+    // regardless of the letter or its position
+    ConstantTime,
+}
+
+/// An alphabet specified by a string of letters
+///
+/// The position of each letter is its position within the string.
+/// Alphabets consisting entirely of ASCII or Latin-1 characters use
+/// a lookup table to find letters. Others are searched as a sorted
+/// list of runs of consecutive code points, so that large alphabets
+/// built from ranges of code points remain compact.
+///
+/// Neither of those lookups takes the same amount of time for every
+/// letter. Where that could reveal information about the input, use
+/// [`CharAlphabet::constant_time`].
+pub struct CharAlphabet {
     by_pos: Vec<char>,
-    by_ltr: Vec<Letter>,
+    lookup: Lookup,
 }
 
-impl Alphabet {
-    pub fn new(
-        opt_s: Option<&str>,
-        opt_lim: Option<usize>,
-    ) -> Result<Alphabet> {
-        let s = match opt_s {
-            Some(s) => s,
-            None => DEFAULT_ALPHABET,
-        };
-        let lim = match opt_lim {
-            Some(l) => l,
-            None => 0,
+impl CharAlphabet {
+    /// Create an alphabet from the characters in a string
+    ///
+    /// Each character may appear in the string only once.
+    pub fn new(s: &str) -> Result<Self> {
+        CharAlphabet::from_vec(s.chars().collect(), true)
+    }
+
+    /// Create an alphabet from inclusive ranges of code points
+    ///
+    /// The letters of the alphabet are all of the code points within
+    /// the ranges, ordered by code point, so the positions of letters
+    /// don't depend on the order in which the ranges are given. Code
+    /// points covered by more than one range are included only once.
+    ///
+    /// Note that every code point in the ranges is included, even
+    /// those that are unassigned or that are combining marks. Use
+    /// [`CharAlphabet::from_categories`] to exclude them.
+    pub fn from_ranges(ranges: &[RangeInclusive<char>]) -> Result<Self> {
+        CharAlphabet::from_filtered_ranges(ranges, |_| true)
+    }
+
+    /// Create an alphabet from the code points within inclusive ranges
+    /// that belong to any of the given Unicode general categories
+    ///
+    /// The letters are ordered by code point, as with
+    /// [`CharAlphabet::from_ranges`]. Unassigned code points are never
+    /// included. The categories are taken from the version of Unicode
+    /// given by [`unicode::UNICODE_VERSION`], so the resulting alphabet
+    /// is the same regardless of the version of Rust in use.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fpe::alphabet::unicode::GeneralCategory;
+    /// use fpe::alphabet::{Alphabet, CharAlphabet};
+    ///
+    /// // the Han ideographs in the BMP
+    /// let han = CharAlphabet::from_categories(
+    ///     &['\u{3400}'..='\u{4dbf}', '\u{4e00}'..='\u{9fff}'],
+    ///     &[GeneralCategory::OtherLetter],
+    /// )
+    /// .unwrap();
+    /// assert!(han.len() == 27584);
+    /// ```
+    pub fn from_categories(
+        ranges: &[RangeInclusive<char>],
+        categories: &[unicode::GeneralCategory],
+    ) -> Result<Self> {
+        CharAlphabet::from_filtered_ranges(ranges, |c| {
+            unicode::general_category(c)
+                .is_some_and(|gc| categories.contains(&gc))
+        })
+    }
+
+    fn from_filtered_ranges(
+        ranges: &[RangeInclusive<char>],
+        f: impl Fn(char) -> bool,
+    ) -> Result<Self> {
+        let mut by_pos: Vec<char> = ranges
+            .iter()
+            .flat_map(|r| r.clone())
+            .filter(|c| f(*c))
+            .collect();
+        by_pos.sort_unstable();
+        by_pos.dedup();
+
+        CharAlphabet::from_vec(by_pos, true)
+    }
+
+    // when not strict, a repeated letter is found at the position of
+    // its first occurrence, and the later occurrences can't be read
+    fn from_vec(by_pos: Vec<char>, strict: bool) -> Result<Self> {
+        let mut by_ltr: Vec<(char, usize)> =
+            by_pos.iter().copied().zip(0..).collect();
+        by_ltr.sort_unstable();
+
+        for i in 1..by_ltr.len() {
+            if strict && by_ltr[i].0 == by_ltr[i - 1].0 {
+                return Err(Error::new(ErrorKind::DuplicateLetter {
+                    ch: by_ltr[i].0,
+                }));
+            }
+        }
+        by_ltr.dedup_by_key(|(c, _)| *c);
+
+        let lookup = match by_ltr.last() {
+            Some((c, _)) if (*c as u32) >= 256 => {
+                let mut runs = Vec::<Run>::new();
+                for (c, pos) in by_ltr {
+                    match runs.last_mut() {
+                        Some(r)
+                            if r.start + r.len == c as u32
+                                && r.pos + r.len as usize == pos =>
+                        {
+                            r.len += 1
+                        }
+                        _ => runs.push(Run {
+                            start: c as u32,
+                            len: 1,
+                            pos,
+                        }),
+                    }
+                }
+                Lookup::Runs(runs)
+            }
+            _ => {
+                let mut table = Box::new([NO_LETTER; 256]);
+                for (c, pos) in by_ltr {
+                    table[c as usize] = pos as u16;
+                }
+                Lookup::Table(table)
+            }
         };
 
-        let mut by_pos = Vec::<char>::new();
-        s.chars().for_each(|c| {
-            if lim == 0 || by_pos.len() < lim {
-                by_pos.push(c)
+        Ok(CharAlphabet { by_pos, lookup })
+    }
+
+    /// Look up letters in constant time
+    ///
+    /// Finding the position of a letter, or the letter at a position,
+    /// examines every letter of the alphabet, so the time taken does
+    /// not depend on the letter. This makes lookups slower, in
+    /// proportion to the length of the alphabet.
+    ///
+    /// A context using such an alphabet can't be given
+    /// [`InputOptions`](input::InputOptions).
+    pub fn constant_time(mut self) -> Self {
+        self.lookup = Lookup::ConstantTime;
+        self
+    }
+}
+
+// returns all ones if a == b and zero otherwise, without branching
+fn ct_eq_mask(a: u32, b: u32) -> u32 {
+    let x = a ^ b;
+    ((x | x.wrapping_neg()) >> 31).wrapping_sub(1)
+}
+
+impl Default for CharAlphabet {
+    fn default() -> Self {
+        CharAlphabet::new(DEFAULT_ALPHABET).unwrap()
+    }
+}
+
+impl Alphabet for CharAlphabet {
+    fn len(&self) -> usize {
+        self.by_pos.len()
+    }
+
+    fn char_at(&self, pos: usize) -> Option<char> {
+        match self.lookup {
+            Lookup::ConstantTime => {
+                if pos >= self.by_pos.len() {
+                    return None;
+                }
+
+                let mut c = 0;
+                for (i, l) in self.by_pos.iter().enumerate() {
+                    c |= *l as u32 & ct_eq_mask(i as u32, pos as u32);
+                }
+                char::from_u32(c)
             }
-        });
+            _ => self.by_pos.get(pos).copied(),
+        }
+    }
+
+    fn is_constant_time(&self) -> bool {
+        matches!(self.lookup, Lookup::ConstantTime)
+    }
 
-        if lim > 0 && lim > by_pos.len() {
-            return Err(Error::new("not enough letters in alphabet"));
+    fn position_of(&self, c: char) -> Option<usize> {
+        let c = c as u32;
+
+        match &self.lookup {
+            Lookup::Table(table) => match table.get(c as usize) {
+                Some(pos) if *pos != NO_LETTER => Some(*pos as usize),
+                _ => None,
+            },
+            Lookup::Runs(runs) => {
+                let i =
+                    runs.partition_point(|r| r.start <= c).checked_sub(1)?;
+                let r = &runs[i];
+                (c - r.start < r.len).then(|| r.pos + (c - r.start) as usize)
+            }
+            Lookup::ConstantTime => {
+                let (mut found, mut pos) = (0, 0);
+                for (i, l) in self.by_pos.iter().enumerate() {
+                    let m = ct_eq_mask(*l as u32, c);
+                    found |= m;
+                    pos |= i as u32 & m;
+                }
+                (found != 0).then_some(pos as usize)
+            }
         }
+    }
+}
 
-        let mut by_ltr = Vec::<Letter>::with_capacity(by_pos.len());
-        for c in &by_pos {
-            by_ltr.push(Letter {
-                val: *c,
-                pos: by_ltr.len(),
-            });
+/// An alphabet whose letters are arbitrary strings
+///
+/// This allows the use of letters that consist of more than one
+/// `char`, such as accented letters written with combining marks,
+/// emoji with modifiers, or multi-character tokens. The input is
+/// split into letters by repeatedly matching the longest letter at
+/// the beginning of the remaining input.
+///
+/// Sets of letters for which that matching could split a sequence of
+/// letters differently than the sequence was formed (such as `a`,
+/// `ab`, and `bc`, where `a` followed by `bc` would be read as `ab`
+/// followed by `c`) are rejected, as ciphertext produced with them
+/// couldn't always be decrypted.
+///
+/// # Example
+/// ```rust
+/// use fpe::alphabet::{Alphabet, SymbolAlphabet};
+///
+/// // "e" with a combining acute accent is a single letter
+/// let alpha = SymbolAlphabet::from_graphemes("abcde\u{301}").unwrap();
+/// assert!(alpha.len() == 5);
+/// assert!(alpha.match_prefix("e\u{301}a") == Some((4, 3)));
+/// ```
+pub struct SymbolAlphabet {
+    by_pos: Vec<String>,
+    by_sym: HashMap<String, usize>,
+    // the length, in chars, of the longest letter
+    max_len: usize,
+}
+
+impl SymbolAlphabet {
+    /// Create an alphabet from a list of letters
+    ///
+    /// The position of each letter is its position within the list.
+    pub fn new<S: AsRef<str>>(letters: &[S]) -> Result<Self> {
+        let by_pos: Vec<String> =
+            letters.iter().map(|l| l.as_ref().to_string()).collect();
+
+        let mut by_sym = HashMap::with_capacity(by_pos.len());
+        let mut max_len = 0;
+        for (i, l) in by_pos.iter().enumerate() {
+            if l.is_empty() {
+                return Err(Error::new(ErrorKind::EmptyLetter));
+            }
+            if by_sym.insert(l.clone(), i).is_some() {
+                return Err(Error::new(ErrorKind::DuplicateSymbol {
+                    symbol: l.clone(),
+                }));
+            }
+            max_len = std::cmp::max(max_len, l.chars().count());
         }
-        by_ltr.sort_by_key(|l| l.val);
 
-        for i in 1..by_ltr.len() {
-            if by_ltr[i].val == by_ltr[i - 1].val {
-                return Err(Error::new("duplicate letter(s) in alphabet"));
+        // if a letter is a prefix of another letter, then the remainder
+        // of the longer letter must not be able to begin a sequence of
+        // letters. otherwise the shorter letter followed by that
+        // sequence would be read (incorrectly) as the longer letter.
+        // once sorted, the letters that begin with a given letter
+        // immediately follow it, so only those need to be compared
+        let mut sorted: Vec<&str> = by_pos.iter().map(String::as_str).collect();
+        sorted.sort_unstable();
+        for (i, x) in sorted.iter().enumerate() {
+            for y in sorted[i + 1..].iter().take_while(|y| y.starts_with(x)) {
+                if SymbolAlphabet::can_begin(&sorted, &by_sym, &y[x.len()..]) {
+                    return Err(Error::new(ErrorKind::AmbiguousSymbols {
+                        prefix: x.to_string(),
+                        symbol: y.to_string(),
+                    }));
+                }
             }
         }
 
-        Ok(Alphabet {
-            by_ltr: by_ltr,
-            by_pos: by_pos,
+        Ok(SymbolAlphabet {
+            by_pos,
+            by_sym,
+            max_len,
         })
     }
 
-    pub fn len(&self) -> usize {
+    /// Create an alphabet from the extended grapheme clusters in a
+    /// string, each of which becomes a letter
+    pub fn from_graphemes(s: &str) -> Result<Self> {
+        SymbolAlphabet::new(&s.graphemes(true).collect::<Vec<&str>>())
+    }
+
+    // determine whether some sequence of letters begins with `s`.
+    // `sorted` holds the letters in sorted order, and `by_sym` maps
+    // them to their positions
+    fn can_begin(
+        sorted: &[&str],
+        by_sym: &HashMap<String, usize>,
+        s: &str,
+    ) -> bool {
+        // the offsets within `s` reachable by reading whole letters
+        // from its beginning. each is visited once, so that letters
+        // that are prefixes of one another don't cause the same
+        // remainder to be examined repeatedly
+        let mut reached = vec![false; s.len() + 1];
+        reached[0] = true;
+
+        for i in 0..=s.len() {
+            if !reached[i] {
+                continue;
+            }
+
+            // the first letter not less than the remainder is the
+            // only one that can begin with it
+            let rest = &s[i..];
+            let n = sorted.partition_point(|l| *l < rest);
+            if sorted.get(n).is_some_and(|l| l.starts_with(rest)) {
+                return true;
+            }
+
+            for (j, c) in rest.char_indices() {
+                let end = j + c.len_utf8();
+                if by_sym.contains_key(&rest[..end]) {
+                    reached[i + end] = true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl Alphabet for SymbolAlphabet {
+    fn len(&self) -> usize {
         self.by_pos.len()
     }
 
-    pub fn ltr(&self, c: char) -> Result<usize> {
-        match self.by_ltr.binary_search_by_key(&c, |l| l.val) {
-            Ok(i) => Ok(self.by_ltr[i].pos),
-            Err(_) => {
-                Err(Error::new(&format!("'{}' not found in alphabet", c)))
+    // letters consisting of more than a single char can't be
+    // returned here. they are only accessible via write_letter()
+    fn char_at(&self, pos: usize) -> Option<char> {
+        let mut chars = self.by_pos.get(pos)?.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    fn position_of(&self, c: char) -> Option<usize> {
+        self.by_sym.get(c.encode_utf8(&mut [0; 4]) as &str).copied()
+    }
+
+    fn match_prefix(&self, s: &str) -> Option<(usize, usize)> {
+        let ends: Vec<usize> = s
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .take(self.max_len)
+            .collect();
+
+        ends.iter()
+            .rev()
+            .find_map(|n| self.by_sym.get(&s[..*n]).map(|pos| (*pos, *n)))
+    }
+
+    fn write_letter(&self, pos: usize, out: &mut String) -> bool {
+        match self.by_pos.get(pos) {
+            Some(l) => {
+                out.push_str(l);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Commonly used alphabets, selectable by name
+///
+/// Presets may be passed anywhere an alphabet is accepted, and they
+/// may be parsed from their names, e.g. `"base58".parse::<Preset>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// The default alphabet
+    Default,
+    /// [`DIGITS`]
+    Digits,
+    /// [`HEX_LOWER`]
+    HexLower,
+    /// [`HEX_UPPER`]
+    HexUpper,
+    /// [`BASE32`]
+    Base32,
+    /// [`CROCKFORD_BASE32`]. As per the specification, lowercase
+    /// letters are accepted as input, as are `I` and `L` (decoded
+    /// as `1`) and `O` (decoded as `0`)
+    CrockfordBase32,
+    /// [`BASE58`]
+    Base58,
+    /// [`BASE64URL`]
+    Base64Url,
+    /// [`PRINTABLE_ASCII`]
+    PrintableAscii,
+    /// [`ALPHANUMERIC`], accepting uppercase letters as input.
+    /// Output is always lowercase
+    AlphanumericCaseInsensitive,
+}
+
+impl Preset {
+    /// All of the presets
+    pub const ALL: [Preset; 10] = [
+        Preset::Default,
+        Preset::Digits,
+        Preset::HexLower,
+        Preset::HexUpper,
+        Preset::Base32,
+        Preset::CrockfordBase32,
+        Preset::Base58,
+        Preset::Base64Url,
+        Preset::PrintableAscii,
+        Preset::AlphanumericCaseInsensitive,
+    ];
+
+    /// The name by which the preset can be selected
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::Default => "default",
+            Preset::Digits => "digits",
+            Preset::HexLower => "hex-lower",
+            Preset::HexUpper => "hex-upper",
+            Preset::Base32 => "base32",
+            Preset::CrockfordBase32 => "crockford-base32",
+            Preset::Base58 => "base58",
+            Preset::Base64Url => "base64url",
+            Preset::PrintableAscii => "printable-ascii",
+            Preset::AlphanumericCaseInsensitive => "alphanumeric-ci",
+        }
+    }
+
+    /// The letters of the alphabet, in order
+    pub fn letters(&self) -> &'static str {
+        match self {
+            Preset::Default => DEFAULT_ALPHABET,
+            Preset::Digits => DIGITS,
+            Preset::HexLower => HEX_LOWER,
+            Preset::HexUpper => HEX_UPPER,
+            Preset::Base32 => BASE32,
+            Preset::CrockfordBase32 => CROCKFORD_BASE32,
+            Preset::Base58 => BASE58,
+            Preset::Base64Url => BASE64URL,
+            Preset::PrintableAscii => PRINTABLE_ASCII,
+            Preset::AlphanumericCaseInsensitive => ALPHANUMERIC,
+        }
+    }
+
+    // maps alternate forms of input letters to those in the alphabet
+    fn alias(&self) -> Option<fn(char) -> char> {
+        match self {
+            Preset::CrockfordBase32 => Some(|c| match c {
+                'I' | 'i' | 'L' | 'l' => '1',
+                'O' | 'o' => '0',
+                _ => c.to_ascii_uppercase(),
+            }),
+            Preset::AlphanumericCaseInsensitive => {
+                Some(|c| c.to_ascii_lowercase())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Preset {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match Preset::ALL.iter().find(|p| p.name() == s) {
+            Some(p) => Ok(*p),
+            None => Err(Error::new(ErrorKind::UnknownPreset {
+                name: s.to_string(),
+            })),
+        }
+    }
+}
+
+impl IntoAlphabet for Preset {
+    fn into_alphabet(self) -> Result<Arc<dyn Alphabet>> {
+        let letters = CharAlphabet::new(self.letters())?;
+        Ok(match self.alias() {
+            None => Arc::new(letters),
+            Some(alias) => Arc::new(Aliased { letters, alias }),
+        })
+    }
+}
+
+// an alphabet that accepts alternate forms of its letters as input
+struct Aliased {
+    letters: CharAlphabet,
+    alias: fn(char) -> char,
+}
+
+impl Alphabet for Aliased {
+    fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    fn char_at(&self, pos: usize) -> Option<char> {
+        self.letters.char_at(pos)
+    }
+
+    fn position_of(&self, c: char) -> Option<usize> {
+        self.letters
+            .position_of(c)
+            .or_else(|| self.letters.position_of((self.alias)(c)))
+    }
+}
+
+// restricts an alphabet to its first `len` letters
+struct Limited {
+    alpha: Arc<dyn Alphabet>,
+    len: usize,
+}
+
+impl Alphabet for Limited {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn char_at(&self, pos: usize) -> Option<char> {
+        if pos < self.len {
+            self.alpha.char_at(pos)
+        } else {
+            None
+        }
+    }
+
+    fn position_of(&self, c: char) -> Option<usize> {
+        self.alpha.position_of(c).filter(|pos| *pos < self.len)
+    }
+
+    fn match_prefix(&self, s: &str) -> Option<(usize, usize)> {
+        self.alpha
+            .match_prefix(s)
+            .filter(|(pos, _)| *pos < self.len)
+    }
+
+    fn write_letter(&self, pos: usize, out: &mut String) -> bool {
+        pos < self.len && self.alpha.write_letter(pos, out)
+    }
+
+    fn is_constant_time(&self) -> bool {
+        self.alpha.is_constant_time()
+    }
+}
+
+// split the input into the letters of the alphabet, calling `f` for
+// each with the position of the letter within the alphabet (or None
+// if the character isn't part of a letter), the index (in characters,
+// not bytes) of the letter within the input, and its first character.
+// characters that aren't part of a letter are skipped one at a time.
+// a position beyond the end of the alphabet, which a user-supplied
+// alphabet might return, is treated as no letter at all. the scan
+// stops early if `f` returns false
+pub(crate) fn scan(
+    alpha: &dyn Alphabet,
+    s: &str,
+    mut f: impl FnMut(Option<usize>, usize, char) -> bool,
+) {
+    let mut rest = s;
+    let mut i = 0;
+
+    while let Some(c) = rest.chars().next() {
+        let (pos, n) = match alpha.match_prefix(rest) {
+            Some((pos, n))
+                if pos < alpha.len() && n > 0 && rest.is_char_boundary(n) =>
+            {
+                (Some(pos), n)
             }
+            _ => (None, c.len_utf8()),
+        };
+
+        if !f(pos, i, c) {
+            break;
         }
+
+        i += rest[..n].chars().count();
+        rest = &rest[n..];
     }
+}
+
+// split the input into the letters of the alphabet, calling `f` with
+// the position of each letter within the alphabet and the index of
+// the letter within the input. the first character that isn't part
+// of a letter is returned as an error
+pub(crate) fn tokenize(
+    alpha: &dyn Alphabet,
+    s: &str,
+    mut f: impl FnMut(usize, usize),
+) -> Result<()> {
+    let mut res = Ok(());
 
-    pub fn pos(&self, i: usize) -> Result<char> {
-        if i >= self.len() {
-            return Err(Error::new(&format!("no letter at position {}", i)));
+    scan(alpha, s, |pos, i, c| match pos {
+        Some(pos) => {
+            f(pos, i);
+            true
         }
+        None => {
+            res = Err(Error::new(ErrorKind::InvalidCharacter {
+                ch: c,
+                position: i,
+            }));
+            false
+        }
+    });
+
+    res
+}
 
-        Ok(self.by_pos[i])
+// restrict the alphabet to the number of letters specified by the
+// radix and verify that the alphabet is self-consistent over those
+// letters. a user-supplied alphabet that isn't would otherwise
+// silently produce ciphertext that can't be decrypted.
+pub(crate) fn limit(
+    alpha: Arc<dyn Alphabet>,
+    radix: usize,
+) -> Result<Arc<dyn Alphabet>> {
+    if alpha.len() < radix {
+        return Err(Error::new(ErrorKind::AlphabetTooSmall {
+            radix,
+            length: alpha.len(),
+        }));
+    }
+
+    let mut ltr = String::new();
+    for i in 0..radix {
+        ltr.clear();
+        if !alpha.write_letter(i, &mut ltr) {
+            return Err(Error::new(ErrorKind::InvalidPosition { position: i }));
+        }
+        match alpha.match_prefix(&ltr) {
+            Some((pos, n)) if pos == i && n == ltr.len() => (),
+            // the letter was read back as an earlier letter,
+            // the only one of the two that can ever be read
+            Some((pos, n)) if pos < i && n == ltr.len() => {
+                let mut chars = ltr.chars();
+                return Err(Error::new(match (chars.next(), chars.next()) {
+                    (Some(ch), None) => ErrorKind::DuplicateLetter { ch },
+                    _ => ErrorKind::DuplicateSymbol { symbol: ltr },
+                }));
+            }
+            _ => {
+                return Err(Error::new(ErrorKind::InconsistentAlphabet {
+                    position: i,
+                }))
+            }
+        }
+    }
+
+    if alpha.len() == radix {
+        Ok(alpha)
+    } else {
+        Ok(Arc::new(Limited { alpha, len: radix }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Alphabet;
+    use super::unicode::GeneralCategory;
+    use super::{Alphabet, CharAlphabet, IntoAlphabet, Preset, SymbolAlphabet};
+    use crate::error::ErrorKind;
     use crate::result::Result;
 
     #[test]
     fn limited_alphabet() -> Result<()> {
-        let alpha = Alphabet::new(None, Some(10))?;
+        let alpha = super::limit(None.into_alphabet()?, 10)?;
         assert!(alpha.len() == 10);
+        assert!(alpha.position_of('a').is_none());
         Ok(())
     }
 
     #[test]
     fn unlimited_alphabet() -> Result<()> {
-        let alpha = Alphabet::new(None, None)?;
+        let alpha = CharAlphabet::default();
         assert!(
             alpha.len() == super::DEFAULT_ALPHABET.len(),
             "expected {}, actual {}",
@@ -108,24 +882,311 @@ mod tests {
 
     #[test]
     fn alphabet_too_small() -> Result<()> {
-        let res = Alphabet::new(Some("123"), Some(10));
+        let res = super::limit("123".into_alphabet()?, 10);
         assert!(res.is_err());
         Ok(())
     }
 
+    #[test]
+    fn duplicate_letter() -> Result<()> {
+        let res = CharAlphabet::new("0123455789");
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateLetter { ch: '5' }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_letter_beyond_radix() -> Result<()> {
+        // only the letters within the radix need be distinct
+        let alpha = super::limit("01234567890".into_alphabet()?, 10)?;
+        assert!(alpha.position_of('0') == Some(0));
+
+        let res = super::limit("01234567890".into_alphabet()?, 11);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateLetter { ch: '0' }
+        ));
+
+        let ff1 =
+            crate::ff1::FF1::new(&[0; 16], None, 0, 0, 10, Some("01234567890"));
+        assert!(ff1.is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn inconsistent_alphabet() -> Result<()> {
+        struct Broken;
+        impl Alphabet for Broken {
+            fn len(&self) -> usize {
+                10
+            }
+            fn char_at(&self, pos: usize) -> Option<char> {
+                char::from_digit(pos as u32, 10)
+            }
+            fn position_of(&self, _: char) -> Option<usize> {
+                None
+            }
+        }
+
+        let res = super::limit(Broken.into_alphabet()?, 10);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InconsistentAlphabet { position: 0 }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn position_out_of_range() -> Result<()> {
+        // consistent over its letters, but claims an
+        // extra character at a position beyond its end
+        struct Stray;
+        impl Alphabet for Stray {
+            fn len(&self) -> usize {
+                10
+            }
+            fn char_at(&self, pos: usize) -> Option<char> {
+                char::from_digit(pos as u32, 10)
+            }
+            fn position_of(&self, c: char) -> Option<usize> {
+                match c {
+                    'x' => Some(42),
+                    _ => c.to_digit(10).map(|d| d as usize),
+                }
+            }
+        }
+
+        let ff1 = crate::ff1::FF1::new(&[0; 16], None, 0, 0, 10, Stray)?;
+        let res = ff1.encrypt("01234x6789", None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidCharacter {
+                ch: 'x',
+                position: 5
+            }
+        ));
+        assert!(ff1.validate("01234x6789").invalid_characters().len() == 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn presets() -> Result<()> {
+        let lens = [62, 10, 16, 16, 32, 32, 58, 64, 95, 36];
+        for (p, len) in Preset::ALL.iter().zip(lens) {
+            let alpha = p.into_alphabet()?;
+            assert!(alpha.len() == len, "{}: {}", p, alpha.len());
+            assert!(p.name().parse::<Preset>()? == *p);
+        }
+
+        let res = "base-58".parse::<Preset>();
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::UnknownPreset { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn preset_aliases() -> Result<()> {
+        let alpha = Preset::CrockfordBase32.into_alphabet()?;
+        assert!(alpha.position_of('1') == Some(1));
+        assert!(alpha.position_of('i') == Some(1));
+        assert!(alpha.position_of('L') == Some(1));
+        assert!(alpha.position_of('o') == Some(0));
+        assert!(alpha.position_of('z') == alpha.position_of('Z'));
+        assert!(alpha.position_of('U').is_none());
+
+        let alpha = Preset::AlphanumericCaseInsensitive.into_alphabet()?;
+        assert!(alpha.position_of('Q') == alpha.position_of('q'));
+
+        let alpha = Preset::HexLower.into_alphabet()?;
+        assert!(alpha.position_of('A').is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unicode_ranges() -> Result<()> {
+        // overlapping ranges given out of order
+        let alpha = CharAlphabet::from_ranges(&['k'..='z', 'a'..='m'])?;
+        assert!(alpha.len() == 26);
+        assert!(alpha.char_at(0) == Some('a'));
+        assert!(alpha.position_of('z') == Some(25));
+
+        // the range contains surrogates, which aren't chars
+        let alpha = CharAlphabet::from_ranges(&['\u{d7ff}'..='\u{e000}'])?;
+        assert!(alpha.len() == 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lookups() -> Result<()> {
+        let letters = [
+            // ascii
+            "9876543210zyxabc".to_string(),
+            // latin-1
+            "\u{ff}\u{e9}a\u{0}".to_string(),
+            // runs of consecutive letters, in and out of order
+            String::from_iter(
+                ('\u{4e00}'..='\u{4eff}')
+                    .chain('a'..='z')
+                    .chain(['\u{4f10}']),
+            ),
+        ];
+
+        for l in &letters {
+            let alphas =
+                [CharAlphabet::new(l)?, CharAlphabet::new(l)?.constant_time()];
+            for alpha in &alphas {
+                for (i, c) in l.chars().enumerate() {
+                    assert!(alpha.char_at(i) == Some(c));
+                    assert!(alpha.position_of(c) == Some(i));
+                }
+                assert!(alpha.char_at(alpha.len()).is_none());
+                for c in ['!', '\u{e8}', '\u{4f00}', '\u{10ffff}'] {
+                    assert!(alpha.position_of(c).is_none());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unicode_categories() -> Result<()> {
+        // the cyrillic block contains combining marks,
+        // symbols, and numbers in addition to letters
+        let alpha = CharAlphabet::from_categories(
+            &['\u{0400}'..='\u{04ff}'],
+            GeneralCategory::LETTERS,
+        )?;
+        assert!(alpha.len() == 248);
+        assert!(alpha.char_at(0) == Some('\u{0400}'));
+        assert!(alpha.position_of('\u{0483}').is_none());
+
+        // the greek block contains unassigned code points
+        let alpha = CharAlphabet::from_categories(
+            &['\u{0370}'..='\u{03ff}'],
+            &[GeneralCategory::UppercaseLetter],
+        )?;
+        assert!(alpha.position_of('\u{0378}').is_none());
+        assert!(alpha.position_of('\u{03a3}').is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn symbols() -> Result<()> {
+        let alpha = SymbolAlphabet::new(&["a", "b", "ch", "c", "\u{1f44d}"])?;
+        assert!(alpha.len() == 5);
+        assert!(alpha.match_prefix("chc") == Some((2, 2)));
+        assert!(alpha.match_prefix("cch") == Some((3, 1)));
+        assert!(alpha.match_prefix("\u{1f44d}") == Some((4, 4)));
+        assert!(alpha.match_prefix("d").is_none());
+        assert!(alpha.char_at(2).is_none());
+        assert!(alpha.char_at(3) == Some('c'));
+
+        let mut s = String::new();
+        assert!(alpha.write_letter(2, &mut s) && s == "ch");
+        assert!(!alpha.write_letter(5, &mut s));
+
+        Ok(())
+    }
+
+    #[test]
+    fn graphemes() -> Result<()> {
+        // thumbs up with a skin tone modifier, and
+        // a devanagari consonant with a vowel sign
+        let alpha = SymbolAlphabet::from_graphemes(
+            "\u{1f44d}\u{1f3fd}\u{1f44d}\u{915}\u{93f}\u{915}",
+        )?;
+        assert!(alpha.len() == 4);
+        assert!(alpha.match_prefix("\u{1f44d}\u{1f3fd}") == Some((0, 8)));
+        assert!(alpha.match_prefix("\u{1f44d}\u{915}") == Some((1, 4)));
+        assert!(alpha.match_prefix("\u{915}\u{93f}") == Some((2, 6)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_symbols() -> Result<()> {
+        let res = SymbolAlphabet::new(&["a", "ab", "bc"]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::AmbiguousSymbols { .. }
+        ));
+
+        let res = SymbolAlphabet::new(&["a", "b", "a"]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateSymbol { .. }
+        ));
+
+        let res = SymbolAlphabet::new(&["a", ""]);
+        assert!(matches!(res.err().unwrap().kind(), ErrorKind::EmptyLetter));
+
+        Ok(())
+    }
+
+    #[test]
+    fn prefix_symbols() -> Result<()> {
+        // "b" can't begin a letter, so "ab" is never misread
+        let alpha = SymbolAlphabet::new(&["ab", "a", "c"])?;
+        assert!(alpha.match_prefix("abc") == Some((0, 2)));
+
+        // "a" followed by "bc" could be read as "ab" followed by "c"
+        let res = SymbolAlphabet::new(&["c", "ab", "bc", "a"]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::AmbiguousSymbols { .. }
+        ));
+
+        // "a" followed by "a" would be read as "aa"
+        let res = SymbolAlphabet::new(&["aa", "a"]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::AmbiguousSymbols { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_symbols() -> Result<()> {
+        // an alphabet of the largest radix, many of whose letters are
+        // prefixes of others, is checked without comparing every pair
+        let letters: Vec<String> = (0..1usize << 16)
+            .map(|i| match i % 2 {
+                0 => format!("{:x}-", i / 2),
+                _ => format!("{:x}-x", i / 2),
+            })
+            .collect();
+        let alpha = SymbolAlphabet::new(&letters)?;
+        assert!(alpha.len() == 1 << 16);
+        assert!(alpha.match_prefix("7fff-x") == Some(((1 << 16) - 1, 6)));
+
+        Ok(())
+    }
+
     #[test]
     fn letter_not_found() -> Result<()> {
-        let alpha = Alphabet::new(None, None)?;
-        let res = alpha.ltr('!' as char);
-        assert!(res.is_err());
+        let alpha = CharAlphabet::default();
+        let res = alpha.position_of('!');
+        assert!(res.is_none());
         Ok(())
     }
 
     #[test]
     fn pos_not_found() -> Result<()> {
-        let alpha = Alphabet::new(None, None)?;
-        let res = alpha.pos(alpha.len() + 1);
-        assert!(res.is_err());
+        let alpha = CharAlphabet::default();
+        let res = alpha.char_at(alpha.len() + 1);
+        assert!(res.is_none());
         Ok(())
     }
 }
@@ -0,0 +1,456 @@
+//! Options controlling how input text is matched against an alphabet
+//!
+//! Text entered by people often differs from the letters of the
+//! alphabet in ways that don't change its meaning: it may be in a
+//! different case, in a different Unicode normalization form, or it
+//! may use digits from a script other than ASCII. [`InputOptions`]
+//! describes which of these differences should be ignored when the
+//! input is split into letters.
+//!
+//! ```rust
+//! use fpe::alphabet::input::{CaseMatching, InputOptions};
+//! use fpe::ff1::FF1;
+//!
+//! let ff1 = FF1::builder()
+//!     .key(&[0; 16])
+//!     .alphabet(fpe::alphabet::HEX_LOWER)
+//!     .input_options(InputOptions::new().case(CaseMatching::Insensitive))
+//!     .build()
+//!     .unwrap();
+//!
+//! assert!(
+//!     ff1.encrypt("DEADBEEF", None).unwrap()
+//!         == ff1.encrypt("deadbeef", None).unwrap()
+//! );
+//! ```
+
+use super::{Alphabet, SymbolAlphabet};
+use crate::error::{Error, ErrorKind};
+use crate::result::Result;
+
+use std::collections::HashSet;
+
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+/// The Unicode normalization form applied to the input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Normalization {
+    /// The input is used as given
+    #[default]
+    None,
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition
+    Nfkc,
+}
+
+/// How the case of the input is matched against the alphabet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseMatching {
+    /// Letters must match the case of the alphabet exactly
+    #[default]
+    Sensitive,
+    /// Letters match regardless of case. The output uses the case of
+    /// the letters in the alphabet.
+    Insensitive,
+    /// Letters match regardless of case. Each letter of the output
+    /// is upper case where the letter at the same position in the
+    /// input was upper case and lower case otherwise. Letters without
+    /// a case, such as digits, can't carry the case of the input, so
+    /// decryption restores the case of the original input only where
+    /// the ciphertext has a cased letter.
+    Preserve,
+}
+
+/// Options applied to the input before it is split into letters
+///
+/// The same options apply to the letters of the alphabet, so two
+/// letters that become indistinguishable, e.g. `a` and `A` when
+/// matching is case-insensitive, cause the context to fail to build.
+/// Positions reported in errors refer to the input after it has been
+/// normalized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InputOptions {
+    case: CaseMatching,
+    norm: Normalization,
+    digits: bool,
+}
+
+impl InputOptions {
+    /// Options that match the input exactly as given
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how the case of the input is matched
+    pub fn case(mut self, case: CaseMatching) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set the normalization form applied to the input
+    pub fn normalization(mut self, norm: Normalization) -> Self {
+        self.norm = norm;
+        self
+    }
+
+    /// Map full-width and Arabic-Indic digits to their ASCII equivalents
+    pub fn ascii_digits(mut self, enable: bool) -> Self {
+        self.digits = enable;
+        self
+    }
+
+    /// Whether the options leave the input unchanged
+    pub fn is_exact(&self) -> bool {
+        *self == Self::default()
+    }
+
+    // the input may be sensitive, so the output is allocated at its
+    // final size rather than grown, which would leave copies behind
+    fn normalize(&self, s: &str) -> String {
+        fn exact(it: impl Iterator<Item = char> + Clone) -> String {
+            let mut out =
+                String::with_capacity(it.clone().map(char::len_utf8).sum());
+            out.extend(it);
+            out
+        }
+
+        match self.norm {
+            Normalization::None => s.to_string(),
+            Normalization::Nfc => exact(s.nfc()),
+            Normalization::Nfkc => exact(s.nfkc()),
+        }
+    }
+
+    // the mapping of each character must produce exactly one
+    // character so that positions within the input are preserved
+    fn map_char(&self, c: char) -> char {
+        let c = if self.digits { ascii_digit(c) } else { c };
+
+        match self.case {
+            CaseMatching::Sensitive => c,
+            _ => {
+                let mut lower = c.to_lowercase();
+                match (lower.next(), lower.next()) {
+                    (Some(l), None) => l,
+                    _ => c,
+                }
+            }
+        }
+    }
+
+    fn transform(&self, s: &str) -> String {
+        self.normalize(s)
+            .chars()
+            .map(|c| self.map_char(c))
+            .collect()
+    }
+}
+
+// full-width digits and both forms of Arabic-Indic digits
+fn ascii_digit(c: char) -> char {
+    let base = match c {
+        '\u{ff10}'..='\u{ff19}' => 0xff10,
+        '\u{0660}'..='\u{0669}' => 0x0660,
+        '\u{06f0}'..='\u{06f9}' => 0x06f0,
+        _ => return c,
+    };
+
+    char::from_u32('0' as u32 + (c as u32 - base)).unwrap()
+}
+
+// the positions of the letters of an input and the case mask, which
+// are wiped when dropped since the input may be the plaintext
+pub(crate) type Letters = (Zeroizing<Vec<u16>>, Zeroizing<Vec<bool>>);
+
+// the alphabet as seen through the input options
+pub(crate) struct InputMap {
+    opts: InputOptions,
+    lookup: SymbolAlphabet,
+    // alternate forms of each letter for case preservation. a form is
+    // only present if it is read back as the same letter
+    upper: Vec<Option<String>>,
+    lower: Vec<Option<String>>,
+}
+
+impl InputMap {
+    pub fn new(alpha: &dyn Alphabet, opts: InputOptions) -> Result<Self> {
+        if alpha.is_constant_time() {
+            return Err(Error::new(ErrorKind::ConstantTimeInputOptions));
+        }
+
+        let mut letters = Vec::with_capacity(alpha.len());
+        let mut ltr = String::new();
+
+        for i in 0..alpha.len() {
+            ltr.clear();
+            if !alpha.write_letter(i, &mut ltr) {
+                return Err(Error::new(ErrorKind::InvalidPosition {
+                    position: i,
+                }));
+            }
+            letters.push(ltr.clone());
+        }
+
+        let mapped: Vec<String> =
+            letters.iter().map(|l| opts.transform(l)).collect();
+        let lookup = SymbolAlphabet::new(&mapped).map_err(|e| {
+            match e.kind() {
+                ErrorKind::DuplicateSymbol { .. } => {
+                    // report the first of the original letters that
+                    // became indistinguishable from an earlier one
+                    let mut seen = HashSet::with_capacity(mapped.len());
+                    let i = mapped
+                        .iter()
+                        .position(|m| !seen.insert(m))
+                        .unwrap_or(0);
+                    Error::new(ErrorKind::IndistinguishableLetters {
+                        letter: letters[i].clone(),
+                    })
+                }
+                _ => e,
+            }
+        })?;
+
+        let alt = |f: fn(&str) -> String| -> Vec<Option<String>> {
+            letters
+                .iter()
+                .zip(&mapped)
+                .map(|(l, m)| {
+                    let a = f(l);
+                    (opts.transform(&a) == *m).then_some(a)
+                })
+                .collect()
+        };
+        let (upper, lower) = match opts.case {
+            CaseMatching::Preserve => {
+                (alt(str::to_uppercase), alt(str::to_lowercase))
+            }
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        Ok(InputMap {
+            opts,
+            lookup,
+            upper,
+            lower,
+        })
+    }
+
+    pub fn options(&self) -> InputOptions {
+        self.opts
+    }
+
+    // apply the normalization form, only, to the input
+    pub fn normalize(&self, s: &str) -> String {
+        self.opts.normalize(s)
+    }
+
+    // the length, in bytes, of the longest alternate form of a letter
+    pub fn max_letter_len(&self) -> usize {
+        self.upper
+            .iter()
+            .chain(&self.lower)
+            .flatten()
+            .map(String::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    // split the input into letters as with alphabet::scan(), except
+    // that the characters passed to `f` are those of the (normalized)
+    // input rather than those resulting from the other options
+    pub fn scan(
+        &self,
+        s: &str,
+        f: impl FnMut(Option<usize>, usize, char) -> bool,
+    ) {
+        let norm = Zeroizing::new(self.opts.normalize(s));
+        self.scan_normalized(&norm, f);
+    }
+
+    // the copies of the input made here are wiped, since
+    // the input is the plaintext when encrypting
+    fn scan_normalized(
+        &self,
+        norm: &str,
+        mut f: impl FnMut(Option<usize>, usize, char) -> bool,
+    ) {
+        let mut chars = Zeroizing::new(Vec::with_capacity(norm.len()));
+        chars.extend(norm.chars());
+
+        // a mapped char may be encoded in more bytes than the original
+        let mut mapped = Zeroizing::new(String::with_capacity(chars.len() * 4));
+        mapped.extend(chars.iter().map(|c| self.opts.map_char(*c)));
+
+        super::scan(&self.lookup, &mapped, |pos, i, _| f(pos, i, chars[i]));
+    }
+
+    // split the input into letters, returning their positions within
+    // the alphabet and, when case is preserved, whether each was upper
+    // case. otherwise, the returned case mask is empty
+    pub fn read(&self, s: &str) -> Result<Letters> {
+        let preserve = self.opts.case == CaseMatching::Preserve;
+
+        // normalization may lengthen the input, so the buffers are
+        // sized from the normalized input to avoid reallocation
+        let norm = Zeroizing::new(self.opts.normalize(s));
+        let n = norm.chars().count();
+
+        let mut digits = Zeroizing::new(Vec::with_capacity(n));
+        let mut upper =
+            Zeroizing::new(Vec::with_capacity(if preserve { n } else { 0 }));
+        let mut res = Ok(());
+        self.scan_normalized(&norm, |pos, i, c| match pos {
+            // scan() never produces a position beyond the alphabet
+            Some(pos) if pos < self.lookup.len() => {
+                digits.push(pos as u16);
+                if preserve {
+                    // the case of a letter is that of its first char
+                    upper.push(c.is_uppercase());
+                }
+                true
+            }
+            _ => {
+                res = Err(Error::new(ErrorKind::InvalidCharacter {
+                    ch: c,
+                    position: i,
+                }));
+                false
+            }
+        });
+
+        res.map(|_| (digits, upper))
+    }
+
+    // append the letter at the given position, in upper case if
+    // requested and if the letter has an upper case form
+    pub fn write(&self, pos: usize, upper: bool, out: &mut String) -> bool {
+        let alt = match upper {
+            true => self.upper.get(pos),
+            false => self.lower.get(pos),
+        };
+
+        match alt {
+            Some(Some(l)) => {
+                out.push_str(l);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseMatching, InputMap, InputOptions, Normalization};
+    use crate::alphabet::CharAlphabet;
+    use crate::error::ErrorKind;
+    use crate::result::Result;
+
+    #[test]
+    fn case_insensitive() -> Result<()> {
+        let alpha = CharAlphabet::new("0123456789ABCDEF")?;
+        let map = InputMap::new(
+            &alpha,
+            InputOptions::new().case(CaseMatching::Insensitive),
+        )?;
+
+        let (d, upper) = map.read("fF0a")?;
+        assert!(*d == [15, 15, 0, 10]);
+        assert!(upper.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_preserve() -> Result<()> {
+        let alpha = CharAlphabet::new("0123456789abcdef")?;
+        let map = InputMap::new(
+            &alpha,
+            InputOptions::new().case(CaseMatching::Preserve),
+        )?;
+
+        let (d, upper) = map.read("aB1")?;
+        assert!(*d == [10, 11, 1]);
+        assert!(*upper == [false, true, false]);
+
+        let mut s = String::new();
+        assert!(map.write(12, true, &mut s));
+        assert!(map.write(12, false, &mut s));
+        // digits are their own upper case form
+        assert!(map.write(1, true, &mut s));
+        assert!(s == "Cc1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn indistinguishable_letters() {
+        let res = InputMap::new(
+            &CharAlphabet::default(),
+            InputOptions::new().case(CaseMatching::Insensitive),
+        );
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::IndistinguishableLetters { letter } if letter == "A"
+        ));
+    }
+
+    #[test]
+    fn constant_time() -> Result<()> {
+        let alpha = CharAlphabet::new("0123456789abcdef")?.constant_time();
+        let opts = InputOptions::new().case(CaseMatching::Insensitive);
+
+        let res = InputMap::new(&alpha, opts);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::ConstantTimeInputOptions
+        ));
+
+        // the check applies through the builders, too, but
+        // options that leave the input unchanged are allowed
+        let builder = crate::ff1::FF1::builder().key(&[0; 16]).alphabet(alpha);
+        assert!(builder.clone().input_options(opts).build().is_err());
+        assert!(builder.input_options(InputOptions::new()).build().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalization() -> Result<()> {
+        let alpha = CharAlphabet::new("aeiou\u{e9}\u{ea}")?;
+        let map = InputMap::new(
+            &alpha,
+            InputOptions::new().normalization(Normalization::Nfc),
+        )?;
+
+        // decomposed and precomposed forms are the same letter
+        assert!(*map.read("e\u{301}\u{ea}")?.0 == [5, 6]);
+        assert!(*map.read("\u{e9}e\u{302}")?.0 == [5, 6]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ascii_digits() -> Result<()> {
+        let alpha = CharAlphabet::new("0123456789")?;
+
+        let exact = InputMap::new(&alpha, InputOptions::new())?;
+        let res = exact.read("1\u{ff12}3");
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidCharacter {
+                ch: '\u{ff12}',
+                position: 1
+            }
+        ));
+
+        let map =
+            InputMap::new(&alpha, InputOptions::new().ascii_digits(true))?;
+        assert!(*map.read("\u{ff11}\u{0662}\u{06f3}4")?.0 == [1, 2, 3, 4]);
+
+        Ok(())
+    }
+}
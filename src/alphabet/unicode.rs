@@ -0,0 +1,154 @@
+//! Unicode character properties used to build alphabets
+//!
+//! The properties are taken from a fixed version of the Unicode
+//! Character Database, given by [`UNICODE_VERSION`], that is compiled
+//! into the library. The set of letters selected by a general category
+//! (and therefore the radix and the positions of letters in alphabets
+//! built from them) does not change with the version of Rust or of
+//! any other library in use.
+
+mod tables;
+
+/// The version of Unicode from which character properties are taken
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+/// The general category of a Unicode code point
+///
+/// Unassigned code points (category `Cn`) are not represented.
+/// Functions return `None` for them instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GeneralCategory {
+    /// Lu
+    UppercaseLetter,
+    /// Ll
+    LowercaseLetter,
+    /// Lt
+    TitlecaseLetter,
+    /// Lm
+    ModifierLetter,
+    /// Lo
+    OtherLetter,
+    /// Mn
+    NonspacingMark,
+    /// Mc
+    SpacingMark,
+    /// Me
+    EnclosingMark,
+    /// Nd
+    DecimalNumber,
+    /// Nl
+    LetterNumber,
+    /// No
+    OtherNumber,
+    /// Pc
+    ConnectorPunctuation,
+    /// Pd
+    DashPunctuation,
+    /// Ps
+    OpenPunctuation,
+    /// Pe
+    ClosePunctuation,
+    /// Pi
+    InitialPunctuation,
+    /// Pf
+    FinalPunctuation,
+    /// Po
+    OtherPunctuation,
+    /// Sm
+    MathSymbol,
+    /// Sc
+    CurrencySymbol,
+    /// Sk
+    ModifierSymbol,
+    /// So
+    OtherSymbol,
+    /// Zs
+    SpaceSeparator,
+    /// Zl
+    LineSeparator,
+    /// Zp
+    ParagraphSeparator,
+    /// Cc
+    Control,
+    /// Cf
+    Format,
+    /// Cs
+    Surrogate,
+    /// Co
+    PrivateUse,
+}
+
+impl GeneralCategory {
+    /// The letter categories: Lu, Ll, Lt, Lm, and Lo
+    pub const LETTERS: &'static [GeneralCategory] = &[
+        GeneralCategory::UppercaseLetter,
+        GeneralCategory::LowercaseLetter,
+        GeneralCategory::TitlecaseLetter,
+        GeneralCategory::ModifierLetter,
+        GeneralCategory::OtherLetter,
+    ];
+
+    /// The mark categories: Mn, Mc, and Me
+    pub const MARKS: &'static [GeneralCategory] = &[
+        GeneralCategory::NonspacingMark,
+        GeneralCategory::SpacingMark,
+        GeneralCategory::EnclosingMark,
+    ];
+
+    /// The number categories: Nd, Nl, and No
+    pub const NUMBERS: &'static [GeneralCategory] = &[
+        GeneralCategory::DecimalNumber,
+        GeneralCategory::LetterNumber,
+        GeneralCategory::OtherNumber,
+    ];
+}
+
+/// Returns the general category of a character, or None if the
+/// character is unassigned in [`UNICODE_VERSION`]
+pub fn general_category(c: char) -> Option<GeneralCategory> {
+    let cp = c as u32;
+    tables::GENERAL_CATEGORY
+        .binary_search_by(|(lo, hi, _)| {
+            if *hi < cp {
+                std::cmp::Ordering::Less
+            } else if *lo > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| tables::GENERAL_CATEGORY[i].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{general_category, GeneralCategory};
+
+    #[test]
+    fn table_is_sorted() {
+        let t = super::tables::GENERAL_CATEGORY;
+        assert!(t.iter().all(|(lo, hi, _)| lo <= hi));
+        assert!(t.windows(2).all(|w| w[0].1 < w[1].0));
+    }
+
+    #[test]
+    fn categories() {
+        assert!(
+            general_category('A') == Some(GeneralCategory::UppercaseLetter)
+        );
+        assert!(
+            general_category('z') == Some(GeneralCategory::LowercaseLetter)
+        );
+        assert!(general_category('7') == Some(GeneralCategory::DecimalNumber));
+        assert!(
+            general_category('\u{4e2d}') == Some(GeneralCategory::OtherLetter)
+        );
+        assert!(
+            general_category('\u{0301}')
+                == Some(GeneralCategory::NonspacingMark)
+        );
+        assert!(general_category('\u{0378}').is_none());
+        assert!(general_category('\u{10ffff}').is_none());
+    }
+}
@@ -0,0 +1,1193 @@
+// This file is generated from version 17.0.0 of the Unicode Character
+// Database (via the data in ICU4X's icu_properties crate, version 2.3).
+// Do not edit it by hand; regenerate it from the root of the repository:
+//
+//   cargo run --manifest-path tools/gen-unicode-tables/Cargo.toml \
+//       > src/alphabet/unicode/tables.rs
+//
+// Alphabets built from general categories depend on the exact contents
+// of this table, so updating it to a newer version of Unicode changes
+// the radix and letter positions of those alphabets. It may only be
+// regenerated as part of a release that is allowed to break them.
+
+// the general categories are referred to by their abbreviations
+// so that the table remains (somewhat) readable
+#![allow(non_upper_case_globals)]
+
+use super::GeneralCategory;
+
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
+const Cc: GeneralCategory = GeneralCategory::Control;
+const Cf: GeneralCategory = GeneralCategory::Format;
+const Co: GeneralCategory = GeneralCategory::PrivateUse;
+const Cs: GeneralCategory = GeneralCategory::Surrogate;
+const Ll: GeneralCategory = GeneralCategory::LowercaseLetter;
+const Lm: GeneralCategory = GeneralCategory::ModifierLetter;
+const Lo: GeneralCategory = GeneralCategory::OtherLetter;
+const Lt: GeneralCategory = GeneralCategory::TitlecaseLetter;
+const Lu: GeneralCategory = GeneralCategory::UppercaseLetter;
+const Mc: GeneralCategory = GeneralCategory::SpacingMark;
+const Me: GeneralCategory = GeneralCategory::EnclosingMark;
+const Mn: GeneralCategory = GeneralCategory::NonspacingMark;
+const Nd: GeneralCategory = GeneralCategory::DecimalNumber;
+const Nl: GeneralCategory = GeneralCategory::LetterNumber;
+const No: GeneralCategory = GeneralCategory::OtherNumber;
+const Pc: GeneralCategory = GeneralCategory::ConnectorPunctuation;
+const Pd: GeneralCategory = GeneralCategory::DashPunctuation;
+const Pe: GeneralCategory = GeneralCategory::ClosePunctuation;
+const Pf: GeneralCategory = GeneralCategory::FinalPunctuation;
+const Pi: GeneralCategory = GeneralCategory::InitialPunctuation;
+const Po: GeneralCategory = GeneralCategory::OtherPunctuation;
+const Ps: GeneralCategory = GeneralCategory::OpenPunctuation;
+const Sc: GeneralCategory = GeneralCategory::CurrencySymbol;
+const Sk: GeneralCategory = GeneralCategory::ModifierSymbol;
+const Sm: GeneralCategory = GeneralCategory::MathSymbol;
+const So: GeneralCategory = GeneralCategory::OtherSymbol;
+const Zl: GeneralCategory = GeneralCategory::LineSeparator;
+const Zp: GeneralCategory = GeneralCategory::ParagraphSeparator;
+const Zs: GeneralCategory = GeneralCategory::SpaceSeparator;
+
+// inclusive ranges of assigned code points and their general
+// categories, sorted by code point. unassigned code points (Cn)
+// are those that do not appear in the table.
+#[rustfmt::skip]
+pub(super) const GENERAL_CATEGORY: &[(u32, u32, GeneralCategory)] = &[
+    (0x00000, 0x0001f, Cc), (0x00020, 0x00020, Zs), (0x00021, 0x00023, Po),
+    (0x00024, 0x00024, Sc), (0x00025, 0x00027, Po), (0x00028, 0x00028, Ps),
+    (0x00029, 0x00029, Pe), (0x0002a, 0x0002a, Po), (0x0002b, 0x0002b, Sm),
+    (0x0002c, 0x0002c, Po), (0x0002d, 0x0002d, Pd), (0x0002e, 0x0002f, Po),
+    (0x00030, 0x00039, Nd), (0x0003a, 0x0003b, Po), (0x0003c, 0x0003e, Sm),
+    (0x0003f, 0x00040, Po), (0x00041, 0x0005a, Lu), (0x0005b, 0x0005b, Ps),
+    (0x0005c, 0x0005c, Po), (0x0005d, 0x0005d, Pe), (0x0005e, 0x0005e, Sk),
+    (0x0005f, 0x0005f, Pc), (0x00060, 0x00060, Sk), (0x00061, 0x0007a, Ll),
+    (0x0007b, 0x0007b, Ps), (0x0007c, 0x0007c, Sm), (0x0007d, 0x0007d, Pe),
+    (0x0007e, 0x0007e, Sm), (0x0007f, 0x0009f, Cc), (0x000a0, 0x000a0, Zs),
+    (0x000a1, 0x000a1, Po), (0x000a2, 0x000a5, Sc), (0x000a6, 0x000a6, So),
+    (0x000a7, 0x000a7, Po), (0x000a8, 0x000a8, Sk), (0x000a9, 0x000a9, So),
+    (0x000aa, 0x000aa, Lo), (0x000ab, 0x000ab, Pi), (0x000ac, 0x000ac, Sm),
+    (0x000ad, 0x000ad, Cf), (0x000ae, 0x000ae, So), (0x000af, 0x000af, Sk),
+    (0x000b0, 0x000b0, So), (0x000b1, 0x000b1, Sm), (0x000b2, 0x000b3, No),
+    (0x000b4, 0x000b4, Sk), (0x000b5, 0x000b5, Ll), (0x000b6, 0x000b7, Po),
+    (0x000b8, 0x000b8, Sk), (0x000b9, 0x000b9, No), (0x000ba, 0x000ba, Lo),
+    (0x000bb, 0x000bb, Pf), (0x000bc, 0x000be, No), (0x000bf, 0x000bf, Po),
+    (0x000c0, 0x000d6, Lu), (0x000d7, 0x000d7, Sm), (0x000d8, 0x000de, Lu),
+    (0x000df, 0x000f6, Ll), (0x000f7, 0x000f7, Sm), (0x000f8, 0x000ff, Ll),
+    (0x00100, 0x00100, Lu), (0x00101, 0x00101, Ll), (0x00102, 0x00102, Lu),
+    (0x00103, 0x00103, Ll), (0x00104, 0x00104, Lu), (0x00105, 0x00105, Ll),
+    (0x00106, 0x00106, Lu), (0x00107, 0x00107, Ll), (0x00108, 0x00108, Lu),
+    (0x00109, 0x00109, Ll), (0x0010a, 0x0010a, Lu), (0x0010b, 0x0010b, Ll),
+    (0x0010c, 0x0010c, Lu), (0x0010d, 0x0010d, Ll), (0x0010e, 0x0010e, Lu),
+    (0x0010f, 0x0010f, Ll), (0x00110, 0x00110, Lu), (0x00111, 0x00111, Ll),
+    (0x00112, 0x00112, Lu), (0x00113, 0x00113, Ll), (0x00114, 0x00114, Lu),
+    (0x00115, 0x00115, Ll), (0x00116, 0x00116, Lu), (0x00117, 0x00117, Ll),
+    (0x00118, 0x00118, Lu), (0x00119, 0x00119, Ll), (0x0011a, 0x0011a, Lu),
+    (0x0011b, 0x0011b, Ll), (0x0011c, 0x0011c, Lu), (0x0011d, 0x0011d, Ll),
+    (0x0011e, 0x0011e, Lu), (0x0011f, 0x0011f, Ll), (0x00120, 0x00120, Lu),
+    (0x00121, 0x00121, Ll), (0x00122, 0x00122, Lu), (0x00123, 0x00123, Ll),
+    (0x00124, 0x00124, Lu), (0x00125, 0x00125, Ll), (0x00126, 0x00126, Lu),
+    (0x00127, 0x00127, Ll), (0x00128, 0x00128, Lu), (0x00129, 0x00129, Ll),
+    (0x0012a, 0x0012a, Lu), (0x0012b, 0x0012b, Ll), (0x0012c, 0x0012c, Lu),
+    (0x0012d, 0x0012d, Ll), (0x0012e, 0x0012e, Lu), (0x0012f, 0x0012f, Ll),
+    (0x00130, 0x00130, Lu), (0x00131, 0x00131, Ll), (0x00132, 0x00132, Lu),
+    (0x00133, 0x00133, Ll), (0x00134, 0x00134, Lu), (0x00135, 0x00135, Ll),
+    (0x00136, 0x00136, Lu), (0x00137, 0x00138, Ll), (0x00139, 0x00139, Lu),
+    (0x0013a, 0x0013a, Ll), (0x0013b, 0x0013b, Lu), (0x0013c, 0x0013c, Ll),
+    (0x0013d, 0x0013d, Lu), (0x0013e, 0x0013e, Ll), (0x0013f, 0x0013f, Lu),
+    (0x00140, 0x00140, Ll), (0x00141, 0x00141, Lu), (0x00142, 0x00142, Ll),
+    (0x00143, 0x00143, Lu), (0x00144, 0x00144, Ll), (0x00145, 0x00145, Lu),
+    (0x00146, 0x00146, Ll), (0x00147, 0x00147, Lu), (0x00148, 0x00149, Ll),
+    (0x0014a, 0x0014a, Lu), (0x0014b, 0x0014b, Ll), (0x0014c, 0x0014c, Lu),
+    (0x0014d, 0x0014d, Ll), (0x0014e, 0x0014e, Lu), (0x0014f, 0x0014f, Ll),
+    (0x00150, 0x00150, Lu), (0x00151, 0x00151, Ll), (0x00152, 0x00152, Lu),
+    (0x00153, 0x00153, Ll), (0x00154, 0x00154, Lu), (0x00155, 0x00155, Ll),
+    (0x00156, 0x00156, Lu), (0x00157, 0x00157, Ll), (0x00158, 0x00158, Lu),
+    (0x00159, 0x00159, Ll), (0x0015a, 0x0015a, Lu), (0x0015b, 0x0015b, Ll),
+    (0x0015c, 0x0015c, Lu), (0x0015d, 0x0015d, Ll), (0x0015e, 0x0015e, Lu),
+    (0x0015f, 0x0015f, Ll), (0x00160, 0x00160, Lu), (0x00161, 0x00161, Ll),
+    (0x00162, 0x00162, Lu), (0x00163, 0x00163, Ll), (0x00164, 0x00164, Lu),
+    (0x00165, 0x00165, Ll), (0x00166, 0x00166, Lu), (0x00167, 0x00167, Ll),
+    (0x00168, 0x00168, Lu), (0x00169, 0x00169, Ll), (0x0016a, 0x0016a, Lu),
+    (0x0016b, 0x0016b, Ll), (0x0016c, 0x0016c, Lu), (0x0016d, 0x0016d, Ll),
+    (0x0016e, 0x0016e, Lu), (0x0016f, 0x0016f, Ll), (0x00170, 0x00170, Lu),
+    (0x00171, 0x00171, Ll), (0x00172, 0x00172, Lu), (0x00173, 0x00173, Ll),
+    (0x00174, 0x00174, Lu), (0x00175, 0x00175, Ll), (0x00176, 0x00176, Lu),
+    (0x00177, 0x00177, Ll), (0x00178, 0x00179, Lu), (0x0017a, 0x0017a, Ll),
+    (0x0017b, 0x0017b, Lu), (0x0017c, 0x0017c, Ll), (0x0017d, 0x0017d, Lu),
+    (0x0017e, 0x00180, Ll), (0x00181, 0x00182, Lu), (0x00183, 0x00183, Ll),
+    (0x00184, 0x00184, Lu), (0x00185, 0x00185, Ll), (0x00186, 0x00187, Lu),
+    (0x00188, 0x00188, Ll), (0x00189, 0x0018b, Lu), (0x0018c, 0x0018d, Ll),
+    (0x0018e, 0x00191, Lu), (0x00192, 0x00192, Ll), (0x00193, 0x00194, Lu),
+    (0x00195, 0x00195, Ll), (0x00196, 0x00198, Lu), (0x00199, 0x0019b, Ll),
+    (0x0019c, 0x0019d, Lu), (0x0019e, 0x0019e, Ll), (0x0019f, 0x001a0, Lu),
+    (0x001a1, 0x001a1, Ll), (0x001a2, 0x001a2, Lu), (0x001a3, 0x001a3, Ll),
+    (0x001a4, 0x001a4, Lu), (0x001a5, 0x001a5, Ll), (0x001a6, 0x001a7, Lu),
+    (0x001a8, 0x001a8, Ll), (0x001a9, 0x001a9, Lu), (0x001aa, 0x001ab, Ll),
+    (0x001ac, 0x001ac, Lu), (0x001ad, 0x001ad, Ll), (0x001ae, 0x001af, Lu),
+    (0x001b0, 0x001b0, Ll), (0x001b1, 0x001b3, Lu), (0x001b4, 0x001b4, Ll),
+    (0x001b5, 0x001b5, Lu), (0x001b6, 0x001b6, Ll), (0x001b7, 0x001b8, Lu),
+    (0x001b9, 0x001ba, Ll), (0x001bb, 0x001bb, Lo), (0x001bc, 0x001bc, Lu),
+    (0x001bd, 0x001bf, Ll), (0x001c0, 0x001c3, Lo), (0x001c4, 0x001c4, Lu),
+    (0x001c5, 0x001c5, Lt), (0x001c6, 0x001c6, Ll), (0x001c7, 0x001c7, Lu),
+    (0x001c8, 0x001c8, Lt), (0x001c9, 0x001c9, Ll), (0x001ca, 0x001ca, Lu),
+    (0x001cb, 0x001cb, Lt), (0x001cc, 0x001cc, Ll), (0x001cd, 0x001cd, Lu),
+    (0x001ce, 0x001ce, Ll), (0x001cf, 0x001cf, Lu), (0x001d0, 0x001d0, Ll),
+    (0x001d1, 0x001d1, Lu), (0x001d2, 0x001d2, Ll), (0x001d3, 0x001d3, Lu),
+    (0x001d4, 0x001d4, Ll), (0x001d5, 0x001d5, Lu), (0x001d6, 0x001d6, Ll),
+    (0x001d7, 0x001d7, Lu), (0x001d8, 0x001d8, Ll), (0x001d9, 0x001d9, Lu),
+    (0x001da, 0x001da, Ll), (0x001db, 0x001db, Lu), (0x001dc, 0x001dd, Ll),
+    (0x001de, 0x001de, Lu), (0x001df, 0x001df, Ll), (0x001e0, 0x001e0, Lu),
+    (0x001e1, 0x001e1, Ll), (0x001e2, 0x001e2, Lu), (0x001e3, 0x001e3, Ll),
+    (0x001e4, 0x001e4, Lu), (0x001e5, 0x001e5, Ll), (0x001e6, 0x001e6, Lu),
+    (0x001e7, 0x001e7, Ll), (0x001e8, 0x001e8, Lu), (0x001e9, 0x001e9, Ll),
+    (0x001ea, 0x001ea, Lu), (0x001eb, 0x001eb, Ll), (0x001ec, 0x001ec, Lu),
+    (0x001ed, 0x001ed, Ll), (0x001ee, 0x001ee, Lu), (0x001ef, 0x001f0, Ll),
+    (0x001f1, 0x001f1, Lu), (0x001f2, 0x001f2, Lt), (0x001f3, 0x001f3, Ll),
+    (0x001f4, 0x001f4, Lu), (0x001f5, 0x001f5, Ll), (0x001f6, 0x001f8, Lu),
+    (0x001f9, 0x001f9, Ll), (0x001fa, 0x001fa, Lu), (0x001fb, 0x001fb, Ll),
+    (0x001fc, 0x001fc, Lu), (0x001fd, 0x001fd, Ll), (0x001fe, 0x001fe, Lu),
+    (0x001ff, 0x001ff, Ll), (0x00200, 0x00200, Lu), (0x00201, 0x00201, Ll),
+    (0x00202, 0x00202, Lu), (0x00203, 0x00203, Ll), (0x00204, 0x00204, Lu),
+    (0x00205, 0x00205, Ll), (0x00206, 0x00206, Lu), (0x00207, 0x00207, Ll),
+    (0x00208, 0x00208, Lu), (0x00209, 0x00209, Ll), (0x0020a, 0x0020a, Lu),
+    (0x0020b, 0x0020b, Ll), (0x0020c, 0x0020c, Lu), (0x0020d, 0x0020d, Ll),
+    (0x0020e, 0x0020e, Lu), (0x0020f, 0x0020f, Ll), (0x00210, 0x00210, Lu),
+    (0x00211, 0x00211, Ll), (0x00212, 0x00212, Lu), (0x00213, 0x00213, Ll),
+    (0x00214, 0x00214, Lu), (0x00215, 0x00215, Ll), (0x00216, 0x00216, Lu),
+    (0x00217, 0x00217, Ll), (0x00218, 0x00218, Lu), (0x00219, 0x00219, Ll),
+    (0x0021a, 0x0021a, Lu), (0x0021b, 0x0021b, Ll), (0x0021c, 0x0021c, Lu),
+    (0x0021d, 0x0021d, Ll), (0x0021e, 0x0021e, Lu), (0x0021f, 0x0021f, Ll),
+    (0x00220, 0x00220, Lu), (0x00221, 0x00221, Ll), (0x00222, 0x00222, Lu),
+    (0x00223, 0x00223, Ll), (0x00224, 0x00224, Lu), (0x00225, 0x00225, Ll),
+    (0x00226, 0x00226, Lu), (0x00227, 0x00227, Ll), (0x00228, 0x00228, Lu),
+    (0x00229, 0x00229, Ll), (0x0022a, 0x0022a, Lu), (0x0022b, 0x0022b, Ll),
+    (0x0022c, 0x0022c, Lu), (0x0022d, 0x0022d, Ll), (0x0022e, 0x0022e, Lu),
+    (0x0022f, 0x0022f, Ll), (0x00230, 0x00230, Lu), (0x00231, 0x00231, Ll),
+    (0x00232, 0x00232, Lu), (0x00233, 0x00239, Ll), (0x0023a, 0x0023b, Lu),
+    (0x0023c, 0x0023c, Ll), (0x0023d, 0x0023e, Lu), (0x0023f, 0x00240, Ll),
+    (0x00241, 0x00241, Lu), (0x00242, 0x00242, Ll), (0x00243, 0x00246, Lu),
+    (0x00247, 0x00247, Ll), (0x00248, 0x00248, Lu), (0x00249, 0x00249, Ll),
+    (0x0024a, 0x0024a, Lu), (0x0024b, 0x0024b, Ll), (0x0024c, 0x0024c, Lu),
+    (0x0024d, 0x0024d, Ll), (0x0024e, 0x0024e, Lu), (0x0024f, 0x00293, Ll),
+    (0x00294, 0x00295, Lo), (0x00296, 0x002af, Ll), (0x002b0, 0x002c1, Lm),
+    (0x002c2, 0x002c5, Sk), (0x002c6, 0x002d1, Lm), (0x002d2, 0x002df, Sk),
+    (0x002e0, 0x002e4, Lm), (0x002e5, 0x002eb, Sk), (0x002ec, 0x002ec, Lm),
+    (0x002ed, 0x002ed, Sk), (0x002ee, 0x002ee, Lm), (0x002ef, 0x002ff, Sk),
+    (0x00300, 0x0036f, Mn), (0x00370, 0x00370, Lu), (0x00371, 0x00371, Ll),
+    (0x00372, 0x00372, Lu), (0x00373, 0x00373, Ll), (0x00374, 0x00374, Lm),
+    (0x00375, 0x00375, Sk), (0x00376, 0x00376, Lu), (0x00377, 0x00377, Ll),
+    (0x0037a, 0x0037a, Lm), (0x0037b, 0x0037d, Ll), (0x0037e, 0x0037e, Po),
+    (0x0037f, 0x0037f, Lu), (0x00384, 0x00385, Sk), (0x00386, 0x00386, Lu),
+    (0x00387, 0x00387, Po), (0x00388, 0x0038a, Lu), (0x0038c, 0x0038c, Lu),
+    (0x0038e, 0x0038f, Lu), (0x00390, 0x00390, Ll), (0x00391, 0x003a1, Lu),
+    (0x003a3, 0x003ab, Lu), (0x003ac, 0x003ce, Ll), (0x003cf, 0x003cf, Lu),
+    (0x003d0, 0x003d1, Ll), (0x003d2, 0x003d4, Lu), (0x003d5, 0x003d7, Ll),
+    (0x003d8, 0x003d8, Lu), (0x003d9, 0x003d9, Ll), (0x003da, 0x003da, Lu),
+    (0x003db, 0x003db, Ll), (0x003dc, 0x003dc, Lu), (0x003dd, 0x003dd, Ll),
+    (0x003de, 0x003de, Lu), (0x003df, 0x003df, Ll), (0x003e0, 0x003e0, Lu),
+    (0x003e1, 0x003e1, Ll), (0x003e2, 0x003e2, Lu), (0x003e3, 0x003e3, Ll),
+    (0x003e4, 0x003e4, Lu), (0x003e5, 0x003e5, Ll), (0x003e6, 0x003e6, Lu),
+    (0x003e7, 0x003e7, Ll), (0x003e8, 0x003e8, Lu), (0x003e9, 0x003e9, Ll),
+    (0x003ea, 0x003ea, Lu), (0x003eb, 0x003eb, Ll), (0x003ec, 0x003ec, Lu),
+    (0x003ed, 0x003ed, Ll), (0x003ee, 0x003ee, Lu), (0x003ef, 0x003f3, Ll),
+    (0x003f4, 0x003f4, Lu), (0x003f5, 0x003f5, Ll), (0x003f6, 0x003f6, Sm),
+    (0x003f7, 0x003f7, Lu), (0x003f8, 0x003f8, Ll), (0x003f9, 0x003fa, Lu),
+    (0x003fb, 0x003fc, Ll), (0x003fd, 0x0042f, Lu), (0x00430, 0x0045f, Ll),
+    (0x00460, 0x00460, Lu), (0x00461, 0x00461, Ll), (0x00462, 0x00462, Lu),
+    (0x00463, 0x00463, Ll), (0x00464, 0x00464, Lu), (0x00465, 0x00465, Ll),
+    (0x00466, 0x00466, Lu), (0x00467, 0x00467, Ll), (0x00468, 0x00468, Lu),
+    (0x00469, 0x00469, Ll), (0x0046a, 0x0046a, Lu), (0x0046b, 0x0046b, Ll),
+    (0x0046c, 0x0046c, Lu), (0x0046d, 0x0046d, Ll), (0x0046e, 0x0046e, Lu),
+    (0x0046f, 0x0046f, Ll), (0x00470, 0x00470, Lu), (0x00471, 0x00471, Ll),
+    (0x00472, 0x00472, Lu), (0x00473, 0x00473, Ll), (0x00474, 0x00474, Lu),
+    (0x00475, 0x00475, Ll), (0x00476, 0x00476, Lu), (0x00477, 0x00477, Ll),
+    (0x00478, 0x00478, Lu), (0x00479, 0x00479, Ll), (0x0047a, 0x0047a, Lu),
+    (0x0047b, 0x0047b, Ll), (0x0047c, 0x0047c, Lu), (0x0047d, 0x0047d, Ll),
+    (0x0047e, 0x0047e, Lu), (0x0047f, 0x0047f, Ll), (0x00480, 0x00480, Lu),
+    (0x00481, 0x00481, Ll), (0x00482, 0x00482, So), (0x00483, 0x00487, Mn),
+    (0x00488, 0x00489, Me), (0x0048a, 0x0048a, Lu), (0x0048b, 0x0048b, Ll),
+    (0x0048c, 0x0048c, Lu), (0x0048d, 0x0048d, Ll), (0x0048e, 0x0048e, Lu),
+    (0x0048f, 0x0048f, Ll), (0x00490, 0x00490, Lu), (0x00491, 0x00491, Ll),
+    (0x00492, 0x00492, Lu), (0x00493, 0x00493, Ll), (0x00494, 0x00494, Lu),
+    (0x00495, 0x00495, Ll), (0x00496, 0x00496, Lu), (0x00497, 0x00497, Ll),
+    (0x00498, 0x00498, Lu), (0x00499, 0x00499, Ll), (0x0049a, 0x0049a, Lu),
+    (0x0049b, 0x0049b, Ll), (0x0049c, 0x0049c, Lu), (0x0049d, 0x0049d, Ll),
+    (0x0049e, 0x0049e, Lu), (0x0049f, 0x0049f, Ll), (0x004a0, 0x004a0, Lu),
+    (0x004a1, 0x004a1, Ll), (0x004a2, 0x004a2, Lu), (0x004a3, 0x004a3, Ll),
+    (0x004a4, 0x004a4, Lu), (0x004a5, 0x004a5, Ll), (0x004a6, 0x004a6, Lu),
+    (0x004a7, 0x004a7, Ll), (0x004a8, 0x004a8, Lu), (0x004a9, 0x004a9, Ll),
+    (0x004aa, 0x004aa, Lu), (0x004ab, 0x004ab, Ll), (0x004ac, 0x004ac, Lu),
+    (0x004ad, 0x004ad, Ll), (0x004ae, 0x004ae, Lu), (0x004af, 0x004af, Ll),
+    (0x004b0, 0x004b0, Lu), (0x004b1, 0x004b1, Ll), (0x004b2, 0x004b2, Lu),
+    (0x004b3, 0x004b3, Ll), (0x004b4, 0x004b4, Lu), (0x004b5, 0x004b5, Ll),
+    (0x004b6, 0x004b6, Lu), (0x004b7, 0x004b7, Ll), (0x004b8, 0x004b8, Lu),
+    (0x004b9, 0x004b9, Ll), (0x004ba, 0x004ba, Lu), (0x004bb, 0x004bb, Ll),
+    (0x004bc, 0x004bc, Lu), (0x004bd, 0x004bd, Ll), (0x004be, 0x004be, Lu),
+    (0x004bf, 0x004bf, Ll), (0x004c0, 0x004c1, Lu), (0x004c2, 0x004c2, Ll),
+    (0x004c3, 0x004c3, Lu), (0x004c4, 0x004c4, Ll), (0x004c5, 0x004c5, Lu),
+    (0x004c6, 0x004c6, Ll), (0x004c7, 0x004c7, Lu), (0x004c8, 0x004c8, Ll),
+    (0x004c9, 0x004c9, Lu), (0x004ca, 0x004ca, Ll), (0x004cb, 0x004cb, Lu),
+    (0x004cc, 0x004cc, Ll), (0x004cd, 0x004cd, Lu), (0x004ce, 0x004cf, Ll),
+    (0x004d0, 0x004d0, Lu), (0x004d1, 0x004d1, Ll), (0x004d2, 0x004d2, Lu),
+    (0x004d3, 0x004d3, Ll), (0x004d4, 0x004d4, Lu), (0x004d5, 0x004d5, Ll),
+    (0x004d6, 0x004d6, Lu), (0x004d7, 0x004d7, Ll), (0x004d8, 0x004d8, Lu),
+    (0x004d9, 0x004d9, Ll), (0x004da, 0x004da, Lu), (0x004db, 0x004db, Ll),
+    (0x004dc, 0x004dc, Lu), (0x004dd, 0x004dd, Ll), (0x004de, 0x004de, Lu),
+    (0x004df, 0x004df, Ll), (0x004e0, 0x004e0, Lu), (0x004e1, 0x004e1, Ll),
+    (0x004e2, 0x004e2, Lu), (0x004e3, 0x004e3, Ll), (0x004e4, 0x004e4, Lu),
+    (0x004e5, 0x004e5, Ll), (0x004e6, 0x004e6, Lu), (0x004e7, 0x004e7, Ll),
+    (0x004e8, 0x004e8, Lu), (0x004e9, 0x004e9, Ll), (0x004ea, 0x004ea, Lu),
+    (0x004eb, 0x004eb, Ll), (0x004ec, 0x004ec, Lu), (0x004ed, 0x004ed, Ll),
+    (0x004ee, 0x004ee, Lu), (0x004ef, 0x004ef, Ll), (0x004f0, 0x004f0, Lu),
+    (0x004f1, 0x004f1, Ll), (0x004f2, 0x004f2, Lu), (0x004f3, 0x004f3, Ll),
+    (0x004f4, 0x004f4, Lu), (0x004f5, 0x004f5, Ll), (0x004f6, 0x004f6, Lu),
+    (0x004f7, 0x004f7, Ll), (0x004f8, 0x004f8, Lu), (0x004f9, 0x004f9, Ll),
+    (0x004fa, 0x004fa, Lu), (0x004fb, 0x004fb, Ll), (0x004fc, 0x004fc, Lu),
+    (0x004fd, 0x004fd, Ll), (0x004fe, 0x004fe, Lu), (0x004ff, 0x004ff, Ll),
+    (0x00500, 0x00500, Lu), (0x00501, 0x00501, Ll), (0x00502, 0x00502, Lu),
+    (0x00503, 0x00503, Ll), (0x00504, 0x00504, Lu), (0x00505, 0x00505, Ll),
+    (0x00506, 0x00506, Lu), (0x00507, 0x00507, Ll), (0x00508, 0x00508, Lu),
+    (0x00509, 0x00509, Ll), (0x0050a, 0x0050a, Lu), (0x0050b, 0x0050b, Ll),
+    (0x0050c, 0x0050c, Lu), (0x0050d, 0x0050d, Ll), (0x0050e, 0x0050e, Lu),
+    (0x0050f, 0x0050f, Ll), (0x00510, 0x00510, Lu), (0x00511, 0x00511, Ll),
+    (0x00512, 0x00512, Lu), (0x00513, 0x00513, Ll), (0x00514, 0x00514, Lu),
+    (0x00515, 0x00515, Ll), (0x00516, 0x00516, Lu), (0x00517, 0x00517, Ll),
+    (0x00518, 0x00518, Lu), (0x00519, 0x00519, Ll), (0x0051a, 0x0051a, Lu),
+    (0x0051b, 0x0051b, Ll), (0x0051c, 0x0051c, Lu), (0x0051d, 0x0051d, Ll),
+    (0x0051e, 0x0051e, Lu), (0x0051f, 0x0051f, Ll), (0x00520, 0x00520, Lu),
+    (0x00521, 0x00521, Ll), (0x00522, 0x00522, Lu), (0x00523, 0x00523, Ll),
+    (0x00524, 0x00524, Lu), (0x00525, 0x00525, Ll), (0x00526, 0x00526, Lu),
+    (0x00527, 0x00527, Ll), (0x00528, 0x00528, Lu), (0x00529, 0x00529, Ll),
+    (0x0052a, 0x0052a, Lu), (0x0052b, 0x0052b, Ll), (0x0052c, 0x0052c, Lu),
+    (0x0052d, 0x0052d, Ll), (0x0052e, 0x0052e, Lu), (0x0052f, 0x0052f, Ll),
+    (0x00531, 0x00556, Lu), (0x00559, 0x00559, Lm), (0x0055a, 0x0055f, Po),
+    (0x00560, 0x00588, Ll), (0x00589, 0x00589, Po), (0x0058a, 0x0058a, Pd),
+    (0x0058d, 0x0058e, So), (0x0058f, 0x0058f, Sc), (0x00591, 0x005bd, Mn),
+    (0x005be, 0x005be, Pd), (0x005bf, 0x005bf, Mn), (0x005c0, 0x005c0, Po),
+    (0x005c1, 0x005c2, Mn), (0x005c3, 0x005c3, Po), (0x005c4, 0x005c5, Mn),
+    (0x005c6, 0x005c6, Po), (0x005c7, 0x005c7, Mn), (0x005d0, 0x005ea, Lo),
+    (0x005ef, 0x005f2, Lo), (0x005f3, 0x005f4, Po), (0x00600, 0x00605, Cf),
+    (0x00606, 0x00608, Sm), (0x00609, 0x0060a, Po), (0x0060b, 0x0060b, Sc),
+    (0x0060c, 0x0060d, Po), (0x0060e, 0x0060f, So), (0x00610, 0x0061a, Mn),
+    (0x0061b, 0x0061b, Po), (0x0061c, 0x0061c, Cf), (0x0061d, 0x0061f, Po),
+    (0x00620, 0x0063f, Lo), (0x00640, 0x00640, Lm), (0x00641, 0x0064a, Lo),
+    (0x0064b, 0x0065f, Mn), (0x00660, 0x00669, Nd), (0x0066a, 0x0066d, Po),
+    (0x0066e, 0x0066f, Lo), (0x00670, 0x00670, Mn), (0x00671, 0x006d3, Lo),
+    (0x006d4, 0x006d4, Po), (0x006d5, 0x006d5, Lo), (0x006d6, 0x006dc, Mn),
+    (0x006dd, 0x006dd, Cf), (0x006de, 0x006de, So), (0x006df, 0x006e4, Mn),
+    (0x006e5, 0x006e6, Lm), (0x006e7, 0x006e8, Mn), (0x006e9, 0x006e9, So),
+    (0x006ea, 0x006ed, Mn), (0x006ee, 0x006ef, Lo), (0x006f0, 0x006f9, Nd),
+    (0x006fa, 0x006fc, Lo), (0x006fd, 0x006fe, So), (0x006ff, 0x006ff, Lo),
+    (0x00700, 0x0070d, Po), (0x0070f, 0x0070f, Cf), (0x00710, 0x00710, Lo),
+    (0x00711, 0x00711, Mn), (0x00712, 0x0072f, Lo), (0x00730, 0x0074a, Mn),
+    (0x0074d, 0x007a5, Lo), (0x007a6, 0x007b0, Mn), (0x007b1, 0x007b1, Lo),
+    (0x007c0, 0x007c9, Nd), (0x007ca, 0x007ea, Lo), (0x007eb, 0x007f3, Mn),
+    (0x007f4, 0x007f5, Lm), (0x007f6, 0x007f6, So), (0x007f7, 0x007f9, Po),
+    (0x007fa, 0x007fa, Lm), (0x007fd, 0x007fd, Mn), (0x007fe, 0x007ff, Sc),
+    (0x00800, 0x00815, Lo), (0x00816, 0x00819, Mn), (0x0081a, 0x0081a, Lm),
+    (0x0081b, 0x00823, Mn), (0x00824, 0x00824, Lm), (0x00825, 0x00827, Mn),
+    (0x00828, 0x00828, Lm), (0x00829, 0x0082d, Mn), (0x00830, 0x0083e, Po),
+    (0x00840, 0x00858, Lo), (0x00859, 0x0085b, Mn), (0x0085e, 0x0085e, Po),
+    (0x00860, 0x0086a, Lo), (0x00870, 0x00887, Lo), (0x00888, 0x00888, Sk),
+    (0x00889, 0x0088f, Lo), (0x00890, 0x00891, Cf), (0x00897, 0x0089f, Mn),
+    (0x008a0, 0x008c8, Lo), (0x008c9, 0x008c9, Lm), (0x008ca, 0x008e1, Mn),
+    (0x008e2, 0x008e2, Cf), (0x008e3, 0x00902, Mn), (0x00903, 0x00903, Mc),
+    (0x00904, 0x00939, Lo), (0x0093a, 0x0093a, Mn), (0x0093b, 0x0093b, Mc),
+    (0x0093c, 0x0093c, Mn), (0x0093d, 0x0093d, Lo), (0x0093e, 0x00940, Mc),
+    (0x00941, 0x00948, Mn), (0x00949, 0x0094c, Mc), (0x0094d, 0x0094d, Mn),
+    (0x0094e, 0x0094f, Mc), (0x00950, 0x00950, Lo), (0x00951, 0x00957, Mn),
+    (0x00958, 0x00961, Lo), (0x00962, 0x00963, Mn), (0x00964, 0x00965, Po),
+    (0x00966, 0x0096f, Nd), (0x00970, 0x00970, Po), (0x00971, 0x00971, Lm),
+    (0x00972, 0x00980, Lo), (0x00981, 0x00981, Mn), (0x00982, 0x00983, Mc),
+    (0x00985, 0x0098c, Lo), (0x0098f, 0x00990, Lo), (0x00993, 0x009a8, Lo),
+    (0x009aa, 0x009b0, Lo), (0x009b2, 0x009b2, Lo), (0x009b6, 0x009b9, Lo),
+    (0x009bc, 0x009bc, Mn), (0x009bd, 0x009bd, Lo), (0x009be, 0x009c0, Mc),
+    (0x009c1, 0x009c4, Mn), (0x009c7, 0x009c8, Mc), (0x009cb, 0x009cc, Mc),
+    (0x009cd, 0x009cd, Mn), (0x009ce, 0x009ce, Lo), (0x009d7, 0x009d7, Mc),
+    (0x009dc, 0x009dd, Lo), (0x009df, 0x009e1, Lo), (0x009e2, 0x009e3, Mn),
+    (0x009e6, 0x009ef, Nd), (0x009f0, 0x009f1, Lo), (0x009f2, 0x009f3, Sc),
+    (0x009f4, 0x009f9, No), (0x009fa, 0x009fa, So), (0x009fb, 0x009fb, Sc),
+    (0x009fc, 0x009fc, Lo), (0x009fd, 0x009fd, Po), (0x009fe, 0x009fe, Mn),
+    (0x00a01, 0x00a02, Mn), (0x00a03, 0x00a03, Mc), (0x00a05, 0x00a0a, Lo),
+    (0x00a0f, 0x00a10, Lo), (0x00a13, 0x00a28, Lo), (0x00a2a, 0x00a30, Lo),
+    (0x00a32, 0x00a33, Lo), (0x00a35, 0x00a36, Lo), (0x00a38, 0x00a39, Lo),
+    (0x00a3c, 0x00a3c, Mn), (0x00a3e, 0x00a40, Mc), (0x00a41, 0x00a42, Mn),
+    (0x00a47, 0x00a48, Mn), (0x00a4b, 0x00a4d, Mn), (0x00a51, 0x00a51, Mn),
+    (0x00a59, 0x00a5c, Lo), (0x00a5e, 0x00a5e, Lo), (0x00a66, 0x00a6f, Nd),
+    (0x00a70, 0x00a71, Mn), (0x00a72, 0x00a74, Lo), (0x00a75, 0x00a75, Mn),
+    (0x00a76, 0x00a76, Po), (0x00a81, 0x00a82, Mn), (0x00a83, 0x00a83, Mc),
+    (0x00a85, 0x00a8d, Lo), (0x00a8f, 0x00a91, Lo), (0x00a93, 0x00aa8, Lo),
+    (0x00aaa, 0x00ab0, Lo), (0x00ab2, 0x00ab3, Lo), (0x00ab5, 0x00ab9, Lo),
+    (0x00abc, 0x00abc, Mn), (0x00abd, 0x00abd, Lo), (0x00abe, 0x00ac0, Mc),
+    (0x00ac1, 0x00ac5, Mn), (0x00ac7, 0x00ac8, Mn), (0x00ac9, 0x00ac9, Mc),
+    (0x00acb, 0x00acc, Mc), (0x00acd, 0x00acd, Mn), (0x00ad0, 0x00ad0, Lo),
+    (0x00ae0, 0x00ae1, Lo), (0x00ae2, 0x00ae3, Mn), (0x00ae6, 0x00aef, Nd),
+    (0x00af0, 0x00af0, Po), (0x00af1, 0x00af1, Sc), (0x00af9, 0x00af9, Lo),
+    (0x00afa, 0x00aff, Mn), (0x00b01, 0x00b01, Mn), (0x00b02, 0x00b03, Mc),
+    (0x00b05, 0x00b0c, Lo), (0x00b0f, 0x00b10, Lo), (0x00b13, 0x00b28, Lo),
+    (0x00b2a, 0x00b30, Lo), (0x00b32, 0x00b33, Lo), (0x00b35, 0x00b39, Lo),
+    (0x00b3c, 0x00b3c, Mn), (0x00b3d, 0x00b3d, Lo), (0x00b3e, 0x00b3e, Mc),
+    (0x00b3f, 0x00b3f, Mn), (0x00b40, 0x00b40, Mc), (0x00b41, 0x00b44, Mn),
+    (0x00b47, 0x00b48, Mc), (0x00b4b, 0x00b4c, Mc), (0x00b4d, 0x00b4d, Mn),
+    (0x00b55, 0x00b56, Mn), (0x00b57, 0x00b57, Mc), (0x00b5c, 0x00b5d, Lo),
+    (0x00b5f, 0x00b61, Lo), (0x00b62, 0x00b63, Mn), (0x00b66, 0x00b6f, Nd),
+    (0x00b70, 0x00b70, So), (0x00b71, 0x00b71, Lo), (0x00b72, 0x00b77, No),
+    (0x00b82, 0x00b82, Mn), (0x00b83, 0x00b83, Lo), (0x00b85, 0x00b8a, Lo),
+    (0x00b8e, 0x00b90, Lo), (0x00b92, 0x00b95, Lo), (0x00b99, 0x00b9a, Lo),
+    (0x00b9c, 0x00b9c, Lo), (0x00b9e, 0x00b9f, Lo), (0x00ba3, 0x00ba4, Lo),
+    (0x00ba8, 0x00baa, Lo), (0x00bae, 0x00bb9, Lo), (0x00bbe, 0x00bbf, Mc),
+    (0x00bc0, 0x00bc0, Mn), (0x00bc1, 0x00bc2, Mc), (0x00bc6, 0x00bc8, Mc),
+    (0x00bca, 0x00bcc, Mc), (0x00bcd, 0x00bcd, Mn), (0x00bd0, 0x00bd0, Lo),
+    (0x00bd7, 0x00bd7, Mc), (0x00be6, 0x00bef, Nd), (0x00bf0, 0x00bf2, No),
+    (0x00bf3, 0x00bf8, So), (0x00bf9, 0x00bf9, Sc), (0x00bfa, 0x00bfa, So),
+    (0x00c00, 0x00c00, Mn), (0x00c01, 0x00c03, Mc), (0x00c04, 0x00c04, Mn),
+    (0x00c05, 0x00c0c, Lo), (0x00c0e, 0x00c10, Lo), (0x00c12, 0x00c28, Lo),
+    (0x00c2a, 0x00c39, Lo), (0x00c3c, 0x00c3c, Mn), (0x00c3d, 0x00c3d, Lo),
+    (0x00c3e, 0x00c40, Mn), (0x00c41, 0x00c44, Mc), (0x00c46, 0x00c48, Mn),
+    (0x00c4a, 0x00c4d, Mn), (0x00c55, 0x00c56, Mn), (0x00c58, 0x00c5a, Lo),
+    (0x00c5c, 0x00c5d, Lo), (0x00c60, 0x00c61, Lo), (0x00c62, 0x00c63, Mn),
+    (0x00c66, 0x00c6f, Nd), (0x00c77, 0x00c77, Po), (0x00c78, 0x00c7e, No),
+    (0x00c7f, 0x00c7f, So), (0x00c80, 0x00c80, Lo), (0x00c81, 0x00c81, Mn),
+    (0x00c82, 0x00c83, Mc), (0x00c84, 0x00c84, Po), (0x00c85, 0x00c8c, Lo),
+    (0x00c8e, 0x00c90, Lo), (0x00c92, 0x00ca8, Lo), (0x00caa, 0x00cb3, Lo),
+    (0x00cb5, 0x00cb9, Lo), (0x00cbc, 0x00cbc, Mn), (0x00cbd, 0x00cbd, Lo),
+    (0x00cbe, 0x00cbe, Mc), (0x00cbf, 0x00cbf, Mn), (0x00cc0, 0x00cc4, Mc),
+    (0x00cc6, 0x00cc6, Mn), (0x00cc7, 0x00cc8, Mc), (0x00cca, 0x00ccb, Mc),
+    (0x00ccc, 0x00ccd, Mn), (0x00cd5, 0x00cd6, Mc), (0x00cdc, 0x00cde, Lo),
+    (0x00ce0, 0x00ce1, Lo), (0x00ce2, 0x00ce3, Mn), (0x00ce6, 0x00cef, Nd),
+    (0x00cf1, 0x00cf2, Lo), (0x00cf3, 0x00cf3, Mc), (0x00d00, 0x00d01, Mn),
+    (0x00d02, 0x00d03, Mc), (0x00d04, 0x00d0c, Lo), (0x00d0e, 0x00d10, Lo),
+    (0x00d12, 0x00d3a, Lo), (0x00d3b, 0x00d3c, Mn), (0x00d3d, 0x00d3d, Lo),
+    (0x00d3e, 0x00d40, Mc), (0x00d41, 0x00d44, Mn), (0x00d46, 0x00d48, Mc),
+    (0x00d4a, 0x00d4c, Mc), (0x00d4d, 0x00d4d, Mn), (0x00d4e, 0x00d4e, Lo),
+    (0x00d4f, 0x00d4f, So), (0x00d54, 0x00d56, Lo), (0x00d57, 0x00d57, Mc),
+    (0x00d58, 0x00d5e, No), (0x00d5f, 0x00d61, Lo), (0x00d62, 0x00d63, Mn),
+    (0x00d66, 0x00d6f, Nd), (0x00d70, 0x00d78, No), (0x00d79, 0x00d79, So),
+    (0x00d7a, 0x00d7f, Lo), (0x00d81, 0x00d81, Mn), (0x00d82, 0x00d83, Mc),
+    (0x00d85, 0x00d96, Lo), (0x00d9a, 0x00db1, Lo), (0x00db3, 0x00dbb, Lo),
+    (0x00dbd, 0x00dbd, Lo), (0x00dc0, 0x00dc6, Lo), (0x00dca, 0x00dca, Mn),
+    (0x00dcf, 0x00dd1, Mc), (0x00dd2, 0x00dd4, Mn), (0x00dd6, 0x00dd6, Mn),
+    (0x00dd8, 0x00ddf, Mc), (0x00de6, 0x00def, Nd), (0x00df2, 0x00df3, Mc),
+    (0x00df4, 0x00df4, Po), (0x00e01, 0x00e30, Lo), (0x00e31, 0x00e31, Mn),
+    (0x00e32, 0x00e33, Lo), (0x00e34, 0x00e3a, Mn), (0x00e3f, 0x00e3f, Sc),
+    (0x00e40, 0x00e45, Lo), (0x00e46, 0x00e46, Lm), (0x00e47, 0x00e4e, Mn),
+    (0x00e4f, 0x00e4f, Po), (0x00e50, 0x00e59, Nd), (0x00e5a, 0x00e5b, Po),
+    (0x00e81, 0x00e82, Lo), (0x00e84, 0x00e84, Lo), (0x00e86, 0x00e8a, Lo),
+    (0x00e8c, 0x00ea3, Lo), (0x00ea5, 0x00ea5, Lo), (0x00ea7, 0x00eb0, Lo),
+    (0x00eb1, 0x00eb1, Mn), (0x00eb2, 0x00eb3, Lo), (0x00eb4, 0x00ebc, Mn),
+    (0x00ebd, 0x00ebd, Lo), (0x00ec0, 0x00ec4, Lo), (0x00ec6, 0x00ec6, Lm),
+    (0x00ec8, 0x00ece, Mn), (0x00ed0, 0x00ed9, Nd), (0x00edc, 0x00edf, Lo),
+    (0x00f00, 0x00f00, Lo), (0x00f01, 0x00f03, So), (0x00f04, 0x00f12, Po),
+    (0x00f13, 0x00f13, So), (0x00f14, 0x00f14, Po), (0x00f15, 0x00f17, So),
+    (0x00f18, 0x00f19, Mn), (0x00f1a, 0x00f1f, So), (0x00f20, 0x00f29, Nd),
+    (0x00f2a, 0x00f33, No), (0x00f34, 0x00f34, So), (0x00f35, 0x00f35, Mn),
+    (0x00f36, 0x00f36, So), (0x00f37, 0x00f37, Mn), (0x00f38, 0x00f38, So),
+    (0x00f39, 0x00f39, Mn), (0x00f3a, 0x00f3a, Ps), (0x00f3b, 0x00f3b, Pe),
+    (0x00f3c, 0x00f3c, Ps), (0x00f3d, 0x00f3d, Pe), (0x00f3e, 0x00f3f, Mc),
+    (0x00f40, 0x00f47, Lo), (0x00f49, 0x00f6c, Lo), (0x00f71, 0x00f7e, Mn),
+    (0x00f7f, 0x00f7f, Mc), (0x00f80, 0x00f84, Mn), (0x00f85, 0x00f85, Po),
+    (0x00f86, 0x00f87, Mn), (0x00f88, 0x00f8c, Lo), (0x00f8d, 0x00f97, Mn),
+    (0x00f99, 0x00fbc, Mn), (0x00fbe, 0x00fc5, So), (0x00fc6, 0x00fc6, Mn),
+    (0x00fc7, 0x00fcc, So), (0x00fce, 0x00fcf, So), (0x00fd0, 0x00fd4, Po),
+    (0x00fd5, 0x00fd8, So), (0x00fd9, 0x00fda, Po), (0x01000, 0x0102a, Lo),
+    (0x0102b, 0x0102c, Mc), (0x0102d, 0x01030, Mn), (0x01031, 0x01031, Mc),
+    (0x01032, 0x01037, Mn), (0x01038, 0x01038, Mc), (0x01039, 0x0103a, Mn),
+    (0x0103b, 0x0103c, Mc), (0x0103d, 0x0103e, Mn), (0x0103f, 0x0103f, Lo),
+    (0x01040, 0x01049, Nd), (0x0104a, 0x0104f, Po), (0x01050, 0x01055, Lo),
+    (0x01056, 0x01057, Mc), (0x01058, 0x01059, Mn), (0x0105a, 0x0105d, Lo),
+    (0x0105e, 0x01060, Mn), (0x01061, 0x01061, Lo), (0x01062, 0x01064, Mc),
+    (0x01065, 0x01066, Lo), (0x01067, 0x0106d, Mc), (0x0106e, 0x01070, Lo),
+    (0x01071, 0x01074, Mn), (0x01075, 0x01081, Lo), (0x01082, 0x01082, Mn),
+    (0x01083, 0x01084, Mc), (0x01085, 0x01086, Mn), (0x01087, 0x0108c, Mc),
+    (0x0108d, 0x0108d, Mn), (0x0108e, 0x0108e, Lo), (0x0108f, 0x0108f, Mc),
+    (0x01090, 0x01099, Nd), (0x0109a, 0x0109c, Mc), (0x0109d, 0x0109d, Mn),
+    (0x0109e, 0x0109f, So), (0x010a0, 0x010c5, Lu), (0x010c7, 0x010c7, Lu),
+    (0x010cd, 0x010cd, Lu), (0x010d0, 0x010fa, Ll), (0x010fb, 0x010fb, Po),
+    (0x010fc, 0x010fc, Lm), (0x010fd, 0x010ff, Ll), (0x01100, 0x01248, Lo),
+    (0x0124a, 0x0124d, Lo), (0x01250, 0x01256, Lo), (0x01258, 0x01258, Lo),
+    (0x0125a, 0x0125d, Lo), (0x01260, 0x01288, Lo), (0x0128a, 0x0128d, Lo),
+    (0x01290, 0x012b0, Lo), (0x012b2, 0x012b5, Lo), (0x012b8, 0x012be, Lo),
+    (0x012c0, 0x012c0, Lo), (0x012c2, 0x012c5, Lo), (0x012c8, 0x012d6, Lo),
+    (0x012d8, 0x01310, Lo), (0x01312, 0x01315, Lo), (0x01318, 0x0135a, Lo),
+    (0x0135d, 0x0135f, Mn), (0x01360, 0x01368, Po), (0x01369, 0x0137c, No),
+    (0x01380, 0x0138f, Lo), (0x01390, 0x01399, So), (0x013a0, 0x013f5, Lu),
+    (0x013f8, 0x013fd, Ll), (0x01400, 0x01400, Pd), (0x01401, 0x0166c, Lo),
+    (0x0166d, 0x0166d, So), (0x0166e, 0x0166e, Po), (0x0166f, 0x0167f, Lo),
+    (0x01680, 0x01680, Zs), (0x01681, 0x0169a, Lo), (0x0169b, 0x0169b, Ps),
+    (0x0169c, 0x0169c, Pe), (0x016a0, 0x016ea, Lo), (0x016eb, 0x016ed, Po),
+    (0x016ee, 0x016f0, Nl), (0x016f1, 0x016f8, Lo), (0x01700, 0x01711, Lo),
+    (0x01712, 0x01714, Mn), (0x01715, 0x01715, Mc), (0x0171f, 0x01731, Lo),
+    (0x01732, 0x01733, Mn), (0x01734, 0x01734, Mc), (0x01735, 0x01736, Po),
+    (0x01740, 0x01751, Lo), (0x01752, 0x01753, Mn), (0x01760, 0x0176c, Lo),
+    (0x0176e, 0x01770, Lo), (0x01772, 0x01773, Mn), (0x01780, 0x017b3, Lo),
+    (0x017b4, 0x017b5, Mn), (0x017b6, 0x017b6, Mc), (0x017b7, 0x017bd, Mn),
+    (0x017be, 0x017c5, Mc), (0x017c6, 0x017c6, Mn), (0x017c7, 0x017c8, Mc),
+    (0x017c9, 0x017d3, Mn), (0x017d4, 0x017d6, Po), (0x017d7, 0x017d7, Lm),
+    (0x017d8, 0x017da, Po), (0x017db, 0x017db, Sc), (0x017dc, 0x017dc, Lo),
+    (0x017dd, 0x017dd, Mn), (0x017e0, 0x017e9, Nd), (0x017f0, 0x017f9, No),
+    (0x01800, 0x01805, Po), (0x01806, 0x01806, Pd), (0x01807, 0x0180a, Po),
+    (0x0180b, 0x0180d, Mn), (0x0180e, 0x0180e, Cf), (0x0180f, 0x0180f, Mn),
+    (0x01810, 0x01819, Nd), (0x01820, 0x01842, Lo), (0x01843, 0x01843, Lm),
+    (0x01844, 0x01878, Lo), (0x01880, 0x01884, Lo), (0x01885, 0x01886, Mn),
+    (0x01887, 0x018a8, Lo), (0x018a9, 0x018a9, Mn), (0x018aa, 0x018aa, Lo),
+    (0x018b0, 0x018f5, Lo), (0x01900, 0x0191e, Lo), (0x01920, 0x01922, Mn),
+    (0x01923, 0x01926, Mc), (0x01927, 0x01928, Mn), (0x01929, 0x0192b, Mc),
+    (0x01930, 0x01931, Mc), (0x01932, 0x01932, Mn), (0x01933, 0x01938, Mc),
+    (0x01939, 0x0193b, Mn), (0x01940, 0x01940, So), (0x01944, 0x01945, Po),
+    (0x01946, 0x0194f, Nd), (0x01950, 0x0196d, Lo), (0x01970, 0x01974, Lo),
+    (0x01980, 0x019ab, Lo), (0x019b0, 0x019c9, Lo), (0x019d0, 0x019d9, Nd),
+    (0x019da, 0x019da, No), (0x019de, 0x019ff, So), (0x01a00, 0x01a16, Lo),
+    (0x01a17, 0x01a18, Mn), (0x01a19, 0x01a1a, Mc), (0x01a1b, 0x01a1b, Mn),
+    (0x01a1e, 0x01a1f, Po), (0x01a20, 0x01a54, Lo), (0x01a55, 0x01a55, Mc),
+    (0x01a56, 0x01a56, Mn), (0x01a57, 0x01a57, Mc), (0x01a58, 0x01a5e, Mn),
+    (0x01a60, 0x01a60, Mn), (0x01a61, 0x01a61, Mc), (0x01a62, 0x01a62, Mn),
+    (0x01a63, 0x01a64, Mc), (0x01a65, 0x01a6c, Mn), (0x01a6d, 0x01a72, Mc),
+    (0x01a73, 0x01a7c, Mn), (0x01a7f, 0x01a7f, Mn), (0x01a80, 0x01a89, Nd),
+    (0x01a90, 0x01a99, Nd), (0x01aa0, 0x01aa6, Po), (0x01aa7, 0x01aa7, Lm),
+    (0x01aa8, 0x01aad, Po), (0x01ab0, 0x01abd, Mn), (0x01abe, 0x01abe, Me),
+    (0x01abf, 0x01add, Mn), (0x01ae0, 0x01aeb, Mn), (0x01b00, 0x01b03, Mn),
+    (0x01b04, 0x01b04, Mc), (0x01b05, 0x01b33, Lo), (0x01b34, 0x01b34, Mn),
+    (0x01b35, 0x01b35, Mc), (0x01b36, 0x01b3a, Mn), (0x01b3b, 0x01b3b, Mc),
+    (0x01b3c, 0x01b3c, Mn), (0x01b3d, 0x01b41, Mc), (0x01b42, 0x01b42, Mn),
+    (0x01b43, 0x01b44, Mc), (0x01b45, 0x01b4c, Lo), (0x01b4e, 0x01b4f, Po),
+    (0x01b50, 0x01b59, Nd), (0x01b5a, 0x01b60, Po), (0x01b61, 0x01b6a, So),
+    (0x01b6b, 0x01b73, Mn), (0x01b74, 0x01b7c, So), (0x01b7d, 0x01b7f, Po),
+    (0x01b80, 0x01b81, Mn), (0x01b82, 0x01b82, Mc), (0x01b83, 0x01ba0, Lo),
+    (0x01ba1, 0x01ba1, Mc), (0x01ba2, 0x01ba5, Mn), (0x01ba6, 0x01ba7, Mc),
+    (0x01ba8, 0x01ba9, Mn), (0x01baa, 0x01baa, Mc), (0x01bab, 0x01bad, Mn),
+    (0x01bae, 0x01baf, Lo), (0x01bb0, 0x01bb9, Nd), (0x01bba, 0x01be5, Lo),
+    (0x01be6, 0x01be6, Mn), (0x01be7, 0x01be7, Mc), (0x01be8, 0x01be9, Mn),
+    (0x01bea, 0x01bec, Mc), (0x01bed, 0x01bed, Mn), (0x01bee, 0x01bee, Mc),
+    (0x01bef, 0x01bf1, Mn), (0x01bf2, 0x01bf3, Mc), (0x01bfc, 0x01bff, Po),
+    (0x01c00, 0x01c23, Lo), (0x01c24, 0x01c2b, Mc), (0x01c2c, 0x01c33, Mn),
+    (0x01c34, 0x01c35, Mc), (0x01c36, 0x01c37, Mn), (0x01c3b, 0x01c3f, Po),
+    (0x01c40, 0x01c49, Nd), (0x01c4d, 0x01c4f, Lo), (0x01c50, 0x01c59, Nd),
+    (0x01c5a, 0x01c77, Lo), (0x01c78, 0x01c7d, Lm), (0x01c7e, 0x01c7f, Po),
+    (0x01c80, 0x01c88, Ll), (0x01c89, 0x01c89, Lu), (0x01c8a, 0x01c8a, Ll),
+    (0x01c90, 0x01cba, Lu), (0x01cbd, 0x01cbf, Lu), (0x01cc0, 0x01cc7, Po),
+    (0x01cd0, 0x01cd2, Mn), (0x01cd3, 0x01cd3, Po), (0x01cd4, 0x01ce0, Mn),
+    (0x01ce1, 0x01ce1, Mc), (0x01ce2, 0x01ce8, Mn), (0x01ce9, 0x01cec, Lo),
+    (0x01ced, 0x01ced, Mn), (0x01cee, 0x01cf3, Lo), (0x01cf4, 0x01cf4, Mn),
+    (0x01cf5, 0x01cf6, Lo), (0x01cf7, 0x01cf7, Mc), (0x01cf8, 0x01cf9, Mn),
+    (0x01cfa, 0x01cfa, Lo), (0x01d00, 0x01d2b, Ll), (0x01d2c, 0x01d6a, Lm),
+    (0x01d6b, 0x01d77, Ll), (0x01d78, 0x01d78, Lm), (0x01d79, 0x01d9a, Ll),
+    (0x01d9b, 0x01dbf, Lm), (0x01dc0, 0x01dff, Mn), (0x01e00, 0x01e00, Lu),
+    (0x01e01, 0x01e01, Ll), (0x01e02, 0x01e02, Lu), (0x01e03, 0x01e03, Ll),
+    (0x01e04, 0x01e04, Lu), (0x01e05, 0x01e05, Ll), (0x01e06, 0x01e06, Lu),
+    (0x01e07, 0x01e07, Ll), (0x01e08, 0x01e08, Lu), (0x01e09, 0x01e09, Ll),
+    (0x01e0a, 0x01e0a, Lu), (0x01e0b, 0x01e0b, Ll), (0x01e0c, 0x01e0c, Lu),
+    (0x01e0d, 0x01e0d, Ll), (0x01e0e, 0x01e0e, Lu), (0x01e0f, 0x01e0f, Ll),
+    (0x01e10, 0x01e10, Lu), (0x01e11, 0x01e11, Ll), (0x01e12, 0x01e12, Lu),
+    (0x01e13, 0x01e13, Ll), (0x01e14, 0x01e14, Lu), (0x01e15, 0x01e15, Ll),
+    (0x01e16, 0x01e16, Lu), (0x01e17, 0x01e17, Ll), (0x01e18, 0x01e18, Lu),
+    (0x01e19, 0x01e19, Ll), (0x01e1a, 0x01e1a, Lu), (0x01e1b, 0x01e1b, Ll),
+    (0x01e1c, 0x01e1c, Lu), (0x01e1d, 0x01e1d, Ll), (0x01e1e, 0x01e1e, Lu),
+    (0x01e1f, 0x01e1f, Ll), (0x01e20, 0x01e20, Lu), (0x01e21, 0x01e21, Ll),
+    (0x01e22, 0x01e22, Lu), (0x01e23, 0x01e23, Ll), (0x01e24, 0x01e24, Lu),
+    (0x01e25, 0x01e25, Ll), (0x01e26, 0x01e26, Lu), (0x01e27, 0x01e27, Ll),
+    (0x01e28, 0x01e28, Lu), (0x01e29, 0x01e29, Ll), (0x01e2a, 0x01e2a, Lu),
+    (0x01e2b, 0x01e2b, Ll), (0x01e2c, 0x01e2c, Lu), (0x01e2d, 0x01e2d, Ll),
+    (0x01e2e, 0x01e2e, Lu), (0x01e2f, 0x01e2f, Ll), (0x01e30, 0x01e30, Lu),
+    (0x01e31, 0x01e31, Ll), (0x01e32, 0x01e32, Lu), (0x01e33, 0x01e33, Ll),
+    (0x01e34, 0x01e34, Lu), (0x01e35, 0x01e35, Ll), (0x01e36, 0x01e36, Lu),
+    (0x01e37, 0x01e37, Ll), (0x01e38, 0x01e38, Lu), (0x01e39, 0x01e39, Ll),
+    (0x01e3a, 0x01e3a, Lu), (0x01e3b, 0x01e3b, Ll), (0x01e3c, 0x01e3c, Lu),
+    (0x01e3d, 0x01e3d, Ll), (0x01e3e, 0x01e3e, Lu), (0x01e3f, 0x01e3f, Ll),
+    (0x01e40, 0x01e40, Lu), (0x01e41, 0x01e41, Ll), (0x01e42, 0x01e42, Lu),
+    (0x01e43, 0x01e43, Ll), (0x01e44, 0x01e44, Lu), (0x01e45, 0x01e45, Ll),
+    (0x01e46, 0x01e46, Lu), (0x01e47, 0x01e47, Ll), (0x01e48, 0x01e48, Lu),
+    (0x01e49, 0x01e49, Ll), (0x01e4a, 0x01e4a, Lu), (0x01e4b, 0x01e4b, Ll),
+    (0x01e4c, 0x01e4c, Lu), (0x01e4d, 0x01e4d, Ll), (0x01e4e, 0x01e4e, Lu),
+    (0x01e4f, 0x01e4f, Ll), (0x01e50, 0x01e50, Lu), (0x01e51, 0x01e51, Ll),
+    (0x01e52, 0x01e52, Lu), (0x01e53, 0x01e53, Ll), (0x01e54, 0x01e54, Lu),
+    (0x01e55, 0x01e55, Ll), (0x01e56, 0x01e56, Lu), (0x01e57, 0x01e57, Ll),
+    (0x01e58, 0x01e58, Lu), (0x01e59, 0x01e59, Ll), (0x01e5a, 0x01e5a, Lu),
+    (0x01e5b, 0x01e5b, Ll), (0x01e5c, 0x01e5c, Lu), (0x01e5d, 0x01e5d, Ll),
+    (0x01e5e, 0x01e5e, Lu), (0x01e5f, 0x01e5f, Ll), (0x01e60, 0x01e60, Lu),
+    (0x01e61, 0x01e61, Ll), (0x01e62, 0x01e62, Lu), (0x01e63, 0x01e63, Ll),
+    (0x01e64, 0x01e64, Lu), (0x01e65, 0x01e65, Ll), (0x01e66, 0x01e66, Lu),
+    (0x01e67, 0x01e67, Ll), (0x01e68, 0x01e68, Lu), (0x01e69, 0x01e69, Ll),
+    (0x01e6a, 0x01e6a, Lu), (0x01e6b, 0x01e6b, Ll), (0x01e6c, 0x01e6c, Lu),
+    (0x01e6d, 0x01e6d, Ll), (0x01e6e, 0x01e6e, Lu), (0x01e6f, 0x01e6f, Ll),
+    (0x01e70, 0x01e70, Lu), (0x01e71, 0x01e71, Ll), (0x01e72, 0x01e72, Lu),
+    (0x01e73, 0x01e73, Ll), (0x01e74, 0x01e74, Lu), (0x01e75, 0x01e75, Ll),
+    (0x01e76, 0x01e76, Lu), (0x01e77, 0x01e77, Ll), (0x01e78, 0x01e78, Lu),
+    (0x01e79, 0x01e79, Ll), (0x01e7a, 0x01e7a, Lu), (0x01e7b, 0x01e7b, Ll),
+    (0x01e7c, 0x01e7c, Lu), (0x01e7d, 0x01e7d, Ll), (0x01e7e, 0x01e7e, Lu),
+    (0x01e7f, 0x01e7f, Ll), (0x01e80, 0x01e80, Lu), (0x01e81, 0x01e81, Ll),
+    (0x01e82, 0x01e82, Lu), (0x01e83, 0x01e83, Ll), (0x01e84, 0x01e84, Lu),
+    (0x01e85, 0x01e85, Ll), (0x01e86, 0x01e86, Lu), (0x01e87, 0x01e87, Ll),
+    (0x01e88, 0x01e88, Lu), (0x01e89, 0x01e89, Ll), (0x01e8a, 0x01e8a, Lu),
+    (0x01e8b, 0x01e8b, Ll), (0x01e8c, 0x01e8c, Lu), (0x01e8d, 0x01e8d, Ll),
+    (0x01e8e, 0x01e8e, Lu), (0x01e8f, 0x01e8f, Ll), (0x01e90, 0x01e90, Lu),
+    (0x01e91, 0x01e91, Ll), (0x01e92, 0x01e92, Lu), (0x01e93, 0x01e93, Ll),
+    (0x01e94, 0x01e94, Lu), (0x01e95, 0x01e9d, Ll), (0x01e9e, 0x01e9e, Lu),
+    (0x01e9f, 0x01e9f, Ll), (0x01ea0, 0x01ea0, Lu), (0x01ea1, 0x01ea1, Ll),
+    (0x01ea2, 0x01ea2, Lu), (0x01ea3, 0x01ea3, Ll), (0x01ea4, 0x01ea4, Lu),
+    (0x01ea5, 0x01ea5, Ll), (0x01ea6, 0x01ea6, Lu), (0x01ea7, 0x01ea7, Ll),
+    (0x01ea8, 0x01ea8, Lu), (0x01ea9, 0x01ea9, Ll), (0x01eaa, 0x01eaa, Lu),
+    (0x01eab, 0x01eab, Ll), (0x01eac, 0x01eac, Lu), (0x01ead, 0x01ead, Ll),
+    (0x01eae, 0x01eae, Lu), (0x01eaf, 0x01eaf, Ll), (0x01eb0, 0x01eb0, Lu),
+    (0x01eb1, 0x01eb1, Ll), (0x01eb2, 0x01eb2, Lu), (0x01eb3, 0x01eb3, Ll),
+    (0x01eb4, 0x01eb4, Lu), (0x01eb5, 0x01eb5, Ll), (0x01eb6, 0x01eb6, Lu),
+    (0x01eb7, 0x01eb7, Ll), (0x01eb8, 0x01eb8, Lu), (0x01eb9, 0x01eb9, Ll),
+    (0x01eba, 0x01eba, Lu), (0x01ebb, 0x01ebb, Ll), (0x01ebc, 0x01ebc, Lu),
+    (0x01ebd, 0x01ebd, Ll), (0x01ebe, 0x01ebe, Lu), (0x01ebf, 0x01ebf, Ll),
+    (0x01ec0, 0x01ec0, Lu), (0x01ec1, 0x01ec1, Ll), (0x01ec2, 0x01ec2, Lu),
+    (0x01ec3, 0x01ec3, Ll), (0x01ec4, 0x01ec4, Lu), (0x01ec5, 0x01ec5, Ll),
+    (0x01ec6, 0x01ec6, Lu), (0x01ec7, 0x01ec7, Ll), (0x01ec8, 0x01ec8, Lu),
+    (0x01ec9, 0x01ec9, Ll), (0x01eca, 0x01eca, Lu), (0x01ecb, 0x01ecb, Ll),
+    (0x01ecc, 0x01ecc, Lu), (0x01ecd, 0x01ecd, Ll), (0x01ece, 0x01ece, Lu),
+    (0x01ecf, 0x01ecf, Ll), (0x01ed0, 0x01ed0, Lu), (0x01ed1, 0x01ed1, Ll),
+    (0x01ed2, 0x01ed2, Lu), (0x01ed3, 0x01ed3, Ll), (0x01ed4, 0x01ed4, Lu),
+    (0x01ed5, 0x01ed5, Ll), (0x01ed6, 0x01ed6, Lu), (0x01ed7, 0x01ed7, Ll),
+    (0x01ed8, 0x01ed8, Lu), (0x01ed9, 0x01ed9, Ll), (0x01eda, 0x01eda, Lu),
+    (0x01edb, 0x01edb, Ll), (0x01edc, 0x01edc, Lu), (0x01edd, 0x01edd, Ll),
+    (0x01ede, 0x01ede, Lu), (0x01edf, 0x01edf, Ll), (0x01ee0, 0x01ee0, Lu),
+    (0x01ee1, 0x01ee1, Ll), (0x01ee2, 0x01ee2, Lu), (0x01ee3, 0x01ee3, Ll),
+    (0x01ee4, 0x01ee4, Lu), (0x01ee5, 0x01ee5, Ll), (0x01ee6, 0x01ee6, Lu),
+    (0x01ee7, 0x01ee7, Ll), (0x01ee8, 0x01ee8, Lu), (0x01ee9, 0x01ee9, Ll),
+    (0x01eea, 0x01eea, Lu), (0x01eeb, 0x01eeb, Ll), (0x01eec, 0x01eec, Lu),
+    (0x01eed, 0x01eed, Ll), (0x01eee, 0x01eee, Lu), (0x01eef, 0x01eef, Ll),
+    (0x01ef0, 0x01ef0, Lu), (0x01ef1, 0x01ef1, Ll), (0x01ef2, 0x01ef2, Lu),
+    (0x01ef3, 0x01ef3, Ll), (0x01ef4, 0x01ef4, Lu), (0x01ef5, 0x01ef5, Ll),
+    (0x01ef6, 0x01ef6, Lu), (0x01ef7, 0x01ef7, Ll), (0x01ef8, 0x01ef8, Lu),
+    (0x01ef9, 0x01ef9, Ll), (0x01efa, 0x01efa, Lu), (0x01efb, 0x01efb, Ll),
+    (0x01efc, 0x01efc, Lu), (0x01efd, 0x01efd, Ll), (0x01efe, 0x01efe, Lu),
+    (0x01eff, 0x01f07, Ll), (0x01f08, 0x01f0f, Lu), (0x01f10, 0x01f15, Ll),
+    (0x01f18, 0x01f1d, Lu), (0x01f20, 0x01f27, Ll), (0x01f28, 0x01f2f, Lu),
+    (0x01f30, 0x01f37, Ll), (0x01f38, 0x01f3f, Lu), (0x01f40, 0x01f45, Ll),
+    (0x01f48, 0x01f4d, Lu), (0x01f50, 0x01f57, Ll), (0x01f59, 0x01f59, Lu),
+    (0x01f5b, 0x01f5b, Lu), (0x01f5d, 0x01f5d, Lu), (0x01f5f, 0x01f5f, Lu),
+    (0x01f60, 0x01f67, Ll), (0x01f68, 0x01f6f, Lu), (0x01f70, 0x01f7d, Ll),
+    (0x01f80, 0x01f87, Ll), (0x01f88, 0x01f8f, Lt), (0x01f90, 0x01f97, Ll),
+    (0x01f98, 0x01f9f, Lt), (0x01fa0, 0x01fa7, Ll), (0x01fa8, 0x01faf, Lt),
+    (0x01fb0, 0x01fb4, Ll), (0x01fb6, 0x01fb7, Ll), (0x01fb8, 0x01fbb, Lu),
+    (0x01fbc, 0x01fbc, Lt), (0x01fbd, 0x01fbd, Sk), (0x01fbe, 0x01fbe, Ll),
+    (0x01fbf, 0x01fc1, Sk), (0x01fc2, 0x01fc4, Ll), (0x01fc6, 0x01fc7, Ll),
+    (0x01fc8, 0x01fcb, Lu), (0x01fcc, 0x01fcc, Lt), (0x01fcd, 0x01fcf, Sk),
+    (0x01fd0, 0x01fd3, Ll), (0x01fd6, 0x01fd7, Ll), (0x01fd8, 0x01fdb, Lu),
+    (0x01fdd, 0x01fdf, Sk), (0x01fe0, 0x01fe7, Ll), (0x01fe8, 0x01fec, Lu),
+    (0x01fed, 0x01fef, Sk), (0x01ff2, 0x01ff4, Ll), (0x01ff6, 0x01ff7, Ll),
+    (0x01ff8, 0x01ffb, Lu), (0x01ffc, 0x01ffc, Lt), (0x01ffd, 0x01ffe, Sk),
+    (0x02000, 0x0200a, Zs), (0x0200b, 0x0200f, Cf), (0x02010, 0x02015, Pd),
+    (0x02016, 0x02017, Po), (0x02018, 0x02018, Pi), (0x02019, 0x02019, Pf),
+    (0x0201a, 0x0201a, Ps), (0x0201b, 0x0201c, Pi), (0x0201d, 0x0201d, Pf),
+    (0x0201e, 0x0201e, Ps), (0x0201f, 0x0201f, Pi), (0x02020, 0x02027, Po),
+    (0x02028, 0x02028, Zl), (0x02029, 0x02029, Zp), (0x0202a, 0x0202e, Cf),
+    (0x0202f, 0x0202f, Zs), (0x02030, 0x02038, Po), (0x02039, 0x02039, Pi),
+    (0x0203a, 0x0203a, Pf), (0x0203b, 0x0203e, Po), (0x0203f, 0x02040, Pc),
+    (0x02041, 0x02043, Po), (0x02044, 0x02044, Sm), (0x02045, 0x02045, Ps),
+    (0x02046, 0x02046, Pe), (0x02047, 0x02051, Po), (0x02052, 0x02052, Sm),
+    (0x02053, 0x02053, Po), (0x02054, 0x02054, Pc), (0x02055, 0x0205e, Po),
+    (0x0205f, 0x0205f, Zs), (0x02060, 0x02064, Cf), (0x02066, 0x0206f, Cf),
+    (0x02070, 0x02070, No), (0x02071, 0x02071, Lm), (0x02074, 0x02079, No),
+    (0x0207a, 0x0207c, Sm), (0x0207d, 0x0207d, Ps), (0x0207e, 0x0207e, Pe),
+    (0x0207f, 0x0207f, Lm), (0x02080, 0x02089, No), (0x0208a, 0x0208c, Sm),
+    (0x0208d, 0x0208d, Ps), (0x0208e, 0x0208e, Pe), (0x02090, 0x0209c, Lm),
+    (0x020a0, 0x020c1, Sc), (0x020d0, 0x020dc, Mn), (0x020dd, 0x020e0, Me),
+    (0x020e1, 0x020e1, Mn), (0x020e2, 0x020e4, Me), (0x020e5, 0x020f0, Mn),
+    (0x02100, 0x02101, So), (0x02102, 0x02102, Lu), (0x02103, 0x02106, So),
+    (0x02107, 0x02107, Lu), (0x02108, 0x02109, So), (0x0210a, 0x0210a, Ll),
+    (0x0210b, 0x0210d, Lu), (0x0210e, 0x0210f, Ll), (0x02110, 0x02112, Lu),
+    (0x02113, 0x02113, Ll), (0x02114, 0x02114, So), (0x02115, 0x02115, Lu),
+    (0x02116, 0x02117, So), (0x02118, 0x02118, Sm), (0x02119, 0x0211d, Lu),
+    (0x0211e, 0x02123, So), (0x02124, 0x02124, Lu), (0x02125, 0x02125, So),
+    (0x02126, 0x02126, Lu), (0x02127, 0x02127, So), (0x02128, 0x02128, Lu),
+    (0x02129, 0x02129, So), (0x0212a, 0x0212d, Lu), (0x0212e, 0x0212e, So),
+    (0x0212f, 0x0212f, Ll), (0x02130, 0x02133, Lu), (0x02134, 0x02134, Ll),
+    (0x02135, 0x02138, Lo), (0x02139, 0x02139, Ll), (0x0213a, 0x0213b, So),
+    (0x0213c, 0x0213d, Ll), (0x0213e, 0x0213f, Lu), (0x02140, 0x02144, Sm),
+    (0x02145, 0x02145, Lu), (0x02146, 0x02149, Ll), (0x0214a, 0x0214a, So),
+    (0x0214b, 0x0214b, Sm), (0x0214c, 0x0214d, So), (0x0214e, 0x0214e, Ll),
+    (0x0214f, 0x0214f, So), (0x02150, 0x0215f, No), (0x02160, 0x02182, Nl),
+    (0x02183, 0x02183, Lu), (0x02184, 0x02184, Ll), (0x02185, 0x02188, Nl),
+    (0x02189, 0x02189, No), (0x0218a, 0x0218b, So), (0x02190, 0x02194, Sm),
+    (0x02195, 0x02199, So), (0x0219a, 0x0219b, Sm), (0x0219c, 0x0219f, So),
+    (0x021a0, 0x021a0, Sm), (0x021a1, 0x021a2, So), (0x021a3, 0x021a3, Sm),
+    (0x021a4, 0x021a5, So), (0x021a6, 0x021a6, Sm), (0x021a7, 0x021ad, So),
+    (0x021ae, 0x021ae, Sm), (0x021af, 0x021cd, So), (0x021ce, 0x021cf, Sm),
+    (0x021d0, 0x021d1, So), (0x021d2, 0x021d2, Sm), (0x021d3, 0x021d3, So),
+    (0x021d4, 0x021d4, Sm), (0x021d5, 0x021f3, So), (0x021f4, 0x022ff, Sm),
+    (0x02300, 0x02307, So), (0x02308, 0x02308, Ps), (0x02309, 0x02309, Pe),
+    (0x0230a, 0x0230a, Ps), (0x0230b, 0x0230b, Pe), (0x0230c, 0x0231f, So),
+    (0x02320, 0x02321, Sm), (0x02322, 0x02328, So), (0x02329, 0x02329, Ps),
+    (0x0232a, 0x0232a, Pe), (0x0232b, 0x0237b, So), (0x0237c, 0x0237c, Sm),
+    (0x0237d, 0x0239a, So), (0x0239b, 0x023b3, Sm), (0x023b4, 0x023db, So),
+    (0x023dc, 0x023e1, Sm), (0x023e2, 0x02429, So), (0x02440, 0x0244a, So),
+    (0x02460, 0x0249b, No), (0x0249c, 0x024e9, So), (0x024ea, 0x024ff, No),
+    (0x02500, 0x025b6, So), (0x025b7, 0x025b7, Sm), (0x025b8, 0x025c0, So),
+    (0x025c1, 0x025c1, Sm), (0x025c2, 0x025f7, So), (0x025f8, 0x025ff, Sm),
+    (0x02600, 0x0266e, So), (0x0266f, 0x0266f, Sm), (0x02670, 0x02767, So),
+    (0x02768, 0x02768, Ps), (0x02769, 0x02769, Pe), (0x0276a, 0x0276a, Ps),
+    (0x0276b, 0x0276b, Pe), (0x0276c, 0x0276c, Ps), (0x0276d, 0x0276d, Pe),
+    (0x0276e, 0x0276e, Ps), (0x0276f, 0x0276f, Pe), (0x02770, 0x02770, Ps),
+    (0x02771, 0x02771, Pe), (0x02772, 0x02772, Ps), (0x02773, 0x02773, Pe),
+    (0x02774, 0x02774, Ps), (0x02775, 0x02775, Pe), (0x02776, 0x02793, No),
+    (0x02794, 0x027bf, So), (0x027c0, 0x027c4, Sm), (0x027c5, 0x027c5, Ps),
+    (0x027c6, 0x027c6, Pe), (0x027c7, 0x027e5, Sm), (0x027e6, 0x027e6, Ps),
+    (0x027e7, 0x027e7, Pe), (0x027e8, 0x027e8, Ps), (0x027e9, 0x027e9, Pe),
+    (0x027ea, 0x027ea, Ps), (0x027eb, 0x027eb, Pe), (0x027ec, 0x027ec, Ps),
+    (0x027ed, 0x027ed, Pe), (0x027ee, 0x027ee, Ps), (0x027ef, 0x027ef, Pe),
+    (0x027f0, 0x027ff, Sm), (0x02800, 0x028ff, So), (0x02900, 0x02982, Sm),
+    (0x02983, 0x02983, Ps), (0x02984, 0x02984, Pe), (0x02985, 0x02985, Ps),
+    (0x02986, 0x02986, Pe), (0x02987, 0x02987, Ps), (0x02988, 0x02988, Pe),
+    (0x02989, 0x02989, Ps), (0x0298a, 0x0298a, Pe), (0x0298b, 0x0298b, Ps),
+    (0x0298c, 0x0298c, Pe), (0x0298d, 0x0298d, Ps), (0x0298e, 0x0298e, Pe),
+    (0x0298f, 0x0298f, Ps), (0x02990, 0x02990, Pe), (0x02991, 0x02991, Ps),
+    (0x02992, 0x02992, Pe), (0x02993, 0x02993, Ps), (0x02994, 0x02994, Pe),
+    (0x02995, 0x02995, Ps), (0x02996, 0x02996, Pe), (0x02997, 0x02997, Ps),
+    (0x02998, 0x02998, Pe), (0x02999, 0x029d7, Sm), (0x029d8, 0x029d8, Ps),
+    (0x029d9, 0x029d9, Pe), (0x029da, 0x029da, Ps), (0x029db, 0x029db, Pe),
+    (0x029dc, 0x029fb, Sm), (0x029fc, 0x029fc, Ps), (0x029fd, 0x029fd, Pe),
+    (0x029fe, 0x02aff, Sm), (0x02b00, 0x02b2f, So), (0x02b30, 0x02b44, Sm),
+    (0x02b45, 0x02b46, So), (0x02b47, 0x02b4c, Sm), (0x02b4d, 0x02b73, So),
+    (0x02b76, 0x02bff, So), (0x02c00, 0x02c2f, Lu), (0x02c30, 0x02c5f, Ll),
+    (0x02c60, 0x02c60, Lu), (0x02c61, 0x02c61, Ll), (0x02c62, 0x02c64, Lu),
+    (0x02c65, 0x02c66, Ll), (0x02c67, 0x02c67, Lu), (0x02c68, 0x02c68, Ll),
+    (0x02c69, 0x02c69, Lu), (0x02c6a, 0x02c6a, Ll), (0x02c6b, 0x02c6b, Lu),
+    (0x02c6c, 0x02c6c, Ll), (0x02c6d, 0x02c70, Lu), (0x02c71, 0x02c71, Ll),
+    (0x02c72, 0x02c72, Lu), (0x02c73, 0x02c74, Ll), (0x02c75, 0x02c75, Lu),
+    (0x02c76, 0x02c7b, Ll), (0x02c7c, 0x02c7d, Lm), (0x02c7e, 0x02c80, Lu),
+    (0x02c81, 0x02c81, Ll), (0x02c82, 0x02c82, Lu), (0x02c83, 0x02c83, Ll),
+    (0x02c84, 0x02c84, Lu), (0x02c85, 0x02c85, Ll), (0x02c86, 0x02c86, Lu),
+    (0x02c87, 0x02c87, Ll), (0x02c88, 0x02c88, Lu), (0x02c89, 0x02c89, Ll),
+    (0x02c8a, 0x02c8a, Lu), (0x02c8b, 0x02c8b, Ll), (0x02c8c, 0x02c8c, Lu),
+    (0x02c8d, 0x02c8d, Ll), (0x02c8e, 0x02c8e, Lu), (0x02c8f, 0x02c8f, Ll),
+    (0x02c90, 0x02c90, Lu), (0x02c91, 0x02c91, Ll), (0x02c92, 0x02c92, Lu),
+    (0x02c93, 0x02c93, Ll), (0x02c94, 0x02c94, Lu), (0x02c95, 0x02c95, Ll),
+    (0x02c96, 0x02c96, Lu), (0x02c97, 0x02c97, Ll), (0x02c98, 0x02c98, Lu),
+    (0x02c99, 0x02c99, Ll), (0x02c9a, 0x02c9a, Lu), (0x02c9b, 0x02c9b, Ll),
+    (0x02c9c, 0x02c9c, Lu), (0x02c9d, 0x02c9d, Ll), (0x02c9e, 0x02c9e, Lu),
+    (0x02c9f, 0x02c9f, Ll), (0x02ca0, 0x02ca0, Lu), (0x02ca1, 0x02ca1, Ll),
+    (0x02ca2, 0x02ca2, Lu), (0x02ca3, 0x02ca3, Ll), (0x02ca4, 0x02ca4, Lu),
+    (0x02ca5, 0x02ca5, Ll), (0x02ca6, 0x02ca6, Lu), (0x02ca7, 0x02ca7, Ll),
+    (0x02ca8, 0x02ca8, Lu), (0x02ca9, 0x02ca9, Ll), (0x02caa, 0x02caa, Lu),
+    (0x02cab, 0x02cab, Ll), (0x02cac, 0x02cac, Lu), (0x02cad, 0x02cad, Ll),
+    (0x02cae, 0x02cae, Lu), (0x02caf, 0x02caf, Ll), (0x02cb0, 0x02cb0, Lu),
+    (0x02cb1, 0x02cb1, Ll), (0x02cb2, 0x02cb2, Lu), (0x02cb3, 0x02cb3, Ll),
+    (0x02cb4, 0x02cb4, Lu), (0x02cb5, 0x02cb5, Ll), (0x02cb6, 0x02cb6, Lu),
+    (0x02cb7, 0x02cb7, Ll), (0x02cb8, 0x02cb8, Lu), (0x02cb9, 0x02cb9, Ll),
+    (0x02cba, 0x02cba, Lu), (0x02cbb, 0x02cbb, Ll), (0x02cbc, 0x02cbc, Lu),
+    (0x02cbd, 0x02cbd, Ll), (0x02cbe, 0x02cbe, Lu), (0x02cbf, 0x02cbf, Ll),
+    (0x02cc0, 0x02cc0, Lu), (0x02cc1, 0x02cc1, Ll), (0x02cc2, 0x02cc2, Lu),
+    (0x02cc3, 0x02cc3, Ll), (0x02cc4, 0x02cc4, Lu), (0x02cc5, 0x02cc5, Ll),
+    (0x02cc6, 0x02cc6, Lu), (0x02cc7, 0x02cc7, Ll), (0x02cc8, 0x02cc8, Lu),
+    (0x02cc9, 0x02cc9, Ll), (0x02cca, 0x02cca, Lu), (0x02ccb, 0x02ccb, Ll),
+    (0x02ccc, 0x02ccc, Lu), (0x02ccd, 0x02ccd, Ll), (0x02cce, 0x02cce, Lu),
+    (0x02ccf, 0x02ccf, Ll), (0x02cd0, 0x02cd0, Lu), (0x02cd1, 0x02cd1, Ll),
+    (0x02cd2, 0x02cd2, Lu), (0x02cd3, 0x02cd3, Ll), (0x02cd4, 0x02cd4, Lu),
+    (0x02cd5, 0x02cd5, Ll), (0x02cd6, 0x02cd6, Lu), (0x02cd7, 0x02cd7, Ll),
+    (0x02cd8, 0x02cd8, Lu), (0x02cd9, 0x02cd9, Ll), (0x02cda, 0x02cda, Lu),
+    (0x02cdb, 0x02cdb, Ll), (0x02cdc, 0x02cdc, Lu), (0x02cdd, 0x02cdd, Ll),
+    (0x02cde, 0x02cde, Lu), (0x02cdf, 0x02cdf, Ll), (0x02ce0, 0x02ce0, Lu),
+    (0x02ce1, 0x02ce1, Ll), (0x02ce2, 0x02ce2, Lu), (0x02ce3, 0x02ce4, Ll),
+    (0x02ce5, 0x02cea, So), (0x02ceb, 0x02ceb, Lu), (0x02cec, 0x02cec, Ll),
+    (0x02ced, 0x02ced, Lu), (0x02cee, 0x02cee, Ll), (0x02cef, 0x02cf1, Mn),
+    (0x02cf2, 0x02cf2, Lu), (0x02cf3, 0x02cf3, Ll), (0x02cf9, 0x02cfc, Po),
+    (0x02cfd, 0x02cfd, No), (0x02cfe, 0x02cff, Po), (0x02d00, 0x02d25, Ll),
+    (0x02d27, 0x02d27, Ll), (0x02d2d, 0x02d2d, Ll), (0x02d30, 0x02d67, Lo),
+    (0x02d6f, 0x02d6f, Lm), (0x02d70, 0x02d70, Po), (0x02d7f, 0x02d7f, Mn),
+    (0x02d80, 0x02d96, Lo), (0x02da0, 0x02da6, Lo), (0x02da8, 0x02dae, Lo),
+    (0x02db0, 0x02db6, Lo), (0x02db8, 0x02dbe, Lo), (0x02dc0, 0x02dc6, Lo),
+    (0x02dc8, 0x02dce, Lo), (0x02dd0, 0x02dd6, Lo), (0x02dd8, 0x02dde, Lo),
+    (0x02de0, 0x02dff, Mn), (0x02e00, 0x02e01, Po), (0x02e02, 0x02e02, Pi),
+    (0x02e03, 0x02e03, Pf), (0x02e04, 0x02e04, Pi), (0x02e05, 0x02e05, Pf),
+    (0x02e06, 0x02e08, Po), (0x02e09, 0x02e09, Pi), (0x02e0a, 0x02e0a, Pf),
+    (0x02e0b, 0x02e0b, Po), (0x02e0c, 0x02e0c, Pi), (0x02e0d, 0x02e0d, Pf),
+    (0x02e0e, 0x02e16, Po), (0x02e17, 0x02e17, Pd), (0x02e18, 0x02e19, Po),
+    (0x02e1a, 0x02e1a, Pd), (0x02e1b, 0x02e1b, Po), (0x02e1c, 0x02e1c, Pi),
+    (0x02e1d, 0x02e1d, Pf), (0x02e1e, 0x02e1f, Po), (0x02e20, 0x02e20, Pi),
+    (0x02e21, 0x02e21, Pf), (0x02e22, 0x02e22, Ps), (0x02e23, 0x02e23, Pe),
+    (0x02e24, 0x02e24, Ps), (0x02e25, 0x02e25, Pe), (0x02e26, 0x02e26, Ps),
+    (0x02e27, 0x02e27, Pe), (0x02e28, 0x02e28, Ps), (0x02e29, 0x02e29, Pe),
+    (0x02e2a, 0x02e2e, Po), (0x02e2f, 0x02e2f, Lm), (0x02e30, 0x02e39, Po),
+    (0x02e3a, 0x02e3b, Pd), (0x02e3c, 0x02e3f, Po), (0x02e40, 0x02e40, Pd),
+    (0x02e41, 0x02e41, Po), (0x02e42, 0x02e42, Ps), (0x02e43, 0x02e4f, Po),
+    (0x02e50, 0x02e51, So), (0x02e52, 0x02e54, Po), (0x02e55, 0x02e55, Ps),
+    (0x02e56, 0x02e56, Pe), (0x02e57, 0x02e57, Ps), (0x02e58, 0x02e58, Pe),
+    (0x02e59, 0x02e59, Ps), (0x02e5a, 0x02e5a, Pe), (0x02e5b, 0x02e5b, Ps),
+    (0x02e5c, 0x02e5c, Pe), (0x02e5d, 0x02e5d, Pd), (0x02e80, 0x02e99, So),
+    (0x02e9b, 0x02ef3, So), (0x02f00, 0x02fd5, So), (0x02ff0, 0x02fff, So),
+    (0x03000, 0x03000, Zs), (0x03001, 0x03003, Po), (0x03004, 0x03004, So),
+    (0x03005, 0x03005, Lm), (0x03006, 0x03006, Lo), (0x03007, 0x03007, Nl),
+    (0x03008, 0x03008, Ps), (0x03009, 0x03009, Pe), (0x0300a, 0x0300a, Ps),
+    (0x0300b, 0x0300b, Pe), (0x0300c, 0x0300c, Ps), (0x0300d, 0x0300d, Pe),
+    (0x0300e, 0x0300e, Ps), (0x0300f, 0x0300f, Pe), (0x03010, 0x03010, Ps),
+    (0x03011, 0x03011, Pe), (0x03012, 0x03013, So), (0x03014, 0x03014, Ps),
+    (0x03015, 0x03015, Pe), (0x03016, 0x03016, Ps), (0x03017, 0x03017, Pe),
+    (0x03018, 0x03018, Ps), (0x03019, 0x03019, Pe), (0x0301a, 0x0301a, Ps),
+    (0x0301b, 0x0301b, Pe), (0x0301c, 0x0301c, Pd), (0x0301d, 0x0301d, Ps),
+    (0x0301e, 0x0301f, Pe), (0x03020, 0x03020, So), (0x03021, 0x03029, Nl),
+    (0x0302a, 0x0302d, Mn), (0x0302e, 0x0302f, Mc), (0x03030, 0x03030, Pd),
+    (0x03031, 0x03035, Lm), (0x03036, 0x03037, So), (0x03038, 0x0303a, Nl),
+    (0x0303b, 0x0303b, Lm), (0x0303c, 0x0303c, Lo), (0x0303d, 0x0303d, Po),
+    (0x0303e, 0x0303f, So), (0x03041, 0x03096, Lo), (0x03099, 0x0309a, Mn),
+    (0x0309b, 0x0309c, Sk), (0x0309d, 0x0309e, Lm), (0x0309f, 0x0309f, Lo),
+    (0x030a0, 0x030a0, Pd), (0x030a1, 0x030fa, Lo), (0x030fb, 0x030fb, Po),
+    (0x030fc, 0x030fe, Lm), (0x030ff, 0x030ff, Lo), (0x03105, 0x0312f, Lo),
+    (0x03131, 0x0318e, Lo), (0x03190, 0x03191, So), (0x03192, 0x03195, No),
+    (0x03196, 0x0319f, So), (0x031a0, 0x031bf, Lo), (0x031c0, 0x031e5, So),
+    (0x031ef, 0x031ef, So), (0x031f0, 0x031ff, Lo), (0x03200, 0x0321e, So),
+    (0x03220, 0x03229, No), (0x0322a, 0x03247, So), (0x03248, 0x0324f, No),
+    (0x03250, 0x03250, So), (0x03251, 0x0325f, No), (0x03260, 0x0327f, So),
+    (0x03280, 0x03289, No), (0x0328a, 0x032b0, So), (0x032b1, 0x032bf, No),
+    (0x032c0, 0x033ff, So), (0x03400, 0x04dbf, Lo), (0x04dc0, 0x04dff, So),
+    (0x04e00, 0x0a014, Lo), (0x0a015, 0x0a015, Lm), (0x0a016, 0x0a48c, Lo),
+    (0x0a490, 0x0a4c6, So), (0x0a4d0, 0x0a4f7, Lo), (0x0a4f8, 0x0a4fd, Lm),
+    (0x0a4fe, 0x0a4ff, Po), (0x0a500, 0x0a60b, Lo), (0x0a60c, 0x0a60c, Lm),
+    (0x0a60d, 0x0a60f, Po), (0x0a610, 0x0a61f, Lo), (0x0a620, 0x0a629, Nd),
+    (0x0a62a, 0x0a62b, Lo), (0x0a640, 0x0a640, Lu), (0x0a641, 0x0a641, Ll),
+    (0x0a642, 0x0a642, Lu), (0x0a643, 0x0a643, Ll), (0x0a644, 0x0a644, Lu),
+    (0x0a645, 0x0a645, Ll), (0x0a646, 0x0a646, Lu), (0x0a647, 0x0a647, Ll),
+    (0x0a648, 0x0a648, Lu), (0x0a649, 0x0a649, Ll), (0x0a64a, 0x0a64a, Lu),
+    (0x0a64b, 0x0a64b, Ll), (0x0a64c, 0x0a64c, Lu), (0x0a64d, 0x0a64d, Ll),
+    (0x0a64e, 0x0a64e, Lu), (0x0a64f, 0x0a64f, Ll), (0x0a650, 0x0a650, Lu),
+    (0x0a651, 0x0a651, Ll), (0x0a652, 0x0a652, Lu), (0x0a653, 0x0a653, Ll),
+    (0x0a654, 0x0a654, Lu), (0x0a655, 0x0a655, Ll), (0x0a656, 0x0a656, Lu),
+    (0x0a657, 0x0a657, Ll), (0x0a658, 0x0a658, Lu), (0x0a659, 0x0a659, Ll),
+    (0x0a65a, 0x0a65a, Lu), (0x0a65b, 0x0a65b, Ll), (0x0a65c, 0x0a65c, Lu),
+    (0x0a65d, 0x0a65d, Ll), (0x0a65e, 0x0a65e, Lu), (0x0a65f, 0x0a65f, Ll),
+    (0x0a660, 0x0a660, Lu), (0x0a661, 0x0a661, Ll), (0x0a662, 0x0a662, Lu),
+    (0x0a663, 0x0a663, Ll), (0x0a664, 0x0a664, Lu), (0x0a665, 0x0a665, Ll),
+    (0x0a666, 0x0a666, Lu), (0x0a667, 0x0a667, Ll), (0x0a668, 0x0a668, Lu),
+    (0x0a669, 0x0a669, Ll), (0x0a66a, 0x0a66a, Lu), (0x0a66b, 0x0a66b, Ll),
+    (0x0a66c, 0x0a66c, Lu), (0x0a66d, 0x0a66d, Ll), (0x0a66e, 0x0a66e, Lo),
+    (0x0a66f, 0x0a66f, Mn), (0x0a670, 0x0a672, Me), (0x0a673, 0x0a673, Po),
+    (0x0a674, 0x0a67d, Mn), (0x0a67e, 0x0a67e, Po), (0x0a67f, 0x0a67f, Lm),
+    (0x0a680, 0x0a680, Lu), (0x0a681, 0x0a681, Ll), (0x0a682, 0x0a682, Lu),
+    (0x0a683, 0x0a683, Ll), (0x0a684, 0x0a684, Lu), (0x0a685, 0x0a685, Ll),
+    (0x0a686, 0x0a686, Lu), (0x0a687, 0x0a687, Ll), (0x0a688, 0x0a688, Lu),
+    (0x0a689, 0x0a689, Ll), (0x0a68a, 0x0a68a, Lu), (0x0a68b, 0x0a68b, Ll),
+    (0x0a68c, 0x0a68c, Lu), (0x0a68d, 0x0a68d, Ll), (0x0a68e, 0x0a68e, Lu),
+    (0x0a68f, 0x0a68f, Ll), (0x0a690, 0x0a690, Lu), (0x0a691, 0x0a691, Ll),
+    (0x0a692, 0x0a692, Lu), (0x0a693, 0x0a693, Ll), (0x0a694, 0x0a694, Lu),
+    (0x0a695, 0x0a695, Ll), (0x0a696, 0x0a696, Lu), (0x0a697, 0x0a697, Ll),
+    (0x0a698, 0x0a698, Lu), (0x0a699, 0x0a699, Ll), (0x0a69a, 0x0a69a, Lu),
+    (0x0a69b, 0x0a69b, Ll), (0x0a69c, 0x0a69d, Lm), (0x0a69e, 0x0a69f, Mn),
+    (0x0a6a0, 0x0a6e5, Lo), (0x0a6e6, 0x0a6ef, Nl), (0x0a6f0, 0x0a6f1, Mn),
+    (0x0a6f2, 0x0a6f7, Po), (0x0a700, 0x0a716, Sk), (0x0a717, 0x0a71f, Lm),
+    (0x0a720, 0x0a721, Sk), (0x0a722, 0x0a722, Lu), (0x0a723, 0x0a723, Ll),
+    (0x0a724, 0x0a724, Lu), (0x0a725, 0x0a725, Ll), (0x0a726, 0x0a726, Lu),
+    (0x0a727, 0x0a727, Ll), (0x0a728, 0x0a728, Lu), (0x0a729, 0x0a729, Ll),
+    (0x0a72a, 0x0a72a, Lu), (0x0a72b, 0x0a72b, Ll), (0x0a72c, 0x0a72c, Lu),
+    (0x0a72d, 0x0a72d, Ll), (0x0a72e, 0x0a72e, Lu), (0x0a72f, 0x0a731, Ll),
+    (0x0a732, 0x0a732, Lu), (0x0a733, 0x0a733, Ll), (0x0a734, 0x0a734, Lu),
+    (0x0a735, 0x0a735, Ll), (0x0a736, 0x0a736, Lu), (0x0a737, 0x0a737, Ll),
+    (0x0a738, 0x0a738, Lu), (0x0a739, 0x0a739, Ll), (0x0a73a, 0x0a73a, Lu),
+    (0x0a73b, 0x0a73b, Ll), (0x0a73c, 0x0a73c, Lu), (0x0a73d, 0x0a73d, Ll),
+    (0x0a73e, 0x0a73e, Lu), (0x0a73f, 0x0a73f, Ll), (0x0a740, 0x0a740, Lu),
+    (0x0a741, 0x0a741, Ll), (0x0a742, 0x0a742, Lu), (0x0a743, 0x0a743, Ll),
+    (0x0a744, 0x0a744, Lu), (0x0a745, 0x0a745, Ll), (0x0a746, 0x0a746, Lu),
+    (0x0a747, 0x0a747, Ll), (0x0a748, 0x0a748, Lu), (0x0a749, 0x0a749, Ll),
+    (0x0a74a, 0x0a74a, Lu), (0x0a74b, 0x0a74b, Ll), (0x0a74c, 0x0a74c, Lu),
+    (0x0a74d, 0x0a74d, Ll), (0x0a74e, 0x0a74e, Lu), (0x0a74f, 0x0a74f, Ll),
+    (0x0a750, 0x0a750, Lu), (0x0a751, 0x0a751, Ll), (0x0a752, 0x0a752, Lu),
+    (0x0a753, 0x0a753, Ll), (0x0a754, 0x0a754, Lu), (0x0a755, 0x0a755, Ll),
+    (0x0a756, 0x0a756, Lu), (0x0a757, 0x0a757, Ll), (0x0a758, 0x0a758, Lu),
+    (0x0a759, 0x0a759, Ll), (0x0a75a, 0x0a75a, Lu), (0x0a75b, 0x0a75b, Ll),
+    (0x0a75c, 0x0a75c, Lu), (0x0a75d, 0x0a75d, Ll), (0x0a75e, 0x0a75e, Lu),
+    (0x0a75f, 0x0a75f, Ll), (0x0a760, 0x0a760, Lu), (0x0a761, 0x0a761, Ll),
+    (0x0a762, 0x0a762, Lu), (0x0a763, 0x0a763, Ll), (0x0a764, 0x0a764, Lu),
+    (0x0a765, 0x0a765, Ll), (0x0a766, 0x0a766, Lu), (0x0a767, 0x0a767, Ll),
+    (0x0a768, 0x0a768, Lu), (0x0a769, 0x0a769, Ll), (0x0a76a, 0x0a76a, Lu),
+    (0x0a76b, 0x0a76b, Ll), (0x0a76c, 0x0a76c, Lu), (0x0a76d, 0x0a76d, Ll),
+    (0x0a76e, 0x0a76e, Lu), (0x0a76f, 0x0a76f, Ll), (0x0a770, 0x0a770, Lm),
+    (0x0a771, 0x0a778, Ll), (0x0a779, 0x0a779, Lu), (0x0a77a, 0x0a77a, Ll),
+    (0x0a77b, 0x0a77b, Lu), (0x0a77c, 0x0a77c, Ll), (0x0a77d, 0x0a77e, Lu),
+    (0x0a77f, 0x0a77f, Ll), (0x0a780, 0x0a780, Lu), (0x0a781, 0x0a781, Ll),
+    (0x0a782, 0x0a782, Lu), (0x0a783, 0x0a783, Ll), (0x0a784, 0x0a784, Lu),
+    (0x0a785, 0x0a785, Ll), (0x0a786, 0x0a786, Lu), (0x0a787, 0x0a787, Ll),
+    (0x0a788, 0x0a788, Lm), (0x0a789, 0x0a78a, Sk), (0x0a78b, 0x0a78b, Lu),
+    (0x0a78c, 0x0a78c, Ll), (0x0a78d, 0x0a78d, Lu), (0x0a78e, 0x0a78e, Ll),
+    (0x0a78f, 0x0a78f, Lo), (0x0a790, 0x0a790, Lu), (0x0a791, 0x0a791, Ll),
+    (0x0a792, 0x0a792, Lu), (0x0a793, 0x0a795, Ll), (0x0a796, 0x0a796, Lu),
+    (0x0a797, 0x0a797, Ll), (0x0a798, 0x0a798, Lu), (0x0a799, 0x0a799, Ll),
+    (0x0a79a, 0x0a79a, Lu), (0x0a79b, 0x0a79b, Ll), (0x0a79c, 0x0a79c, Lu),
+    (0x0a79d, 0x0a79d, Ll), (0x0a79e, 0x0a79e, Lu), (0x0a79f, 0x0a79f, Ll),
+    (0x0a7a0, 0x0a7a0, Lu), (0x0a7a1, 0x0a7a1, Ll), (0x0a7a2, 0x0a7a2, Lu),
+    (0x0a7a3, 0x0a7a3, Ll), (0x0a7a4, 0x0a7a4, Lu), (0x0a7a5, 0x0a7a5, Ll),
+    (0x0a7a6, 0x0a7a6, Lu), (0x0a7a7, 0x0a7a7, Ll), (0x0a7a8, 0x0a7a8, Lu),
+    (0x0a7a9, 0x0a7a9, Ll), (0x0a7aa, 0x0a7ae, Lu), (0x0a7af, 0x0a7af, Ll),
+    (0x0a7b0, 0x0a7b4, Lu), (0x0a7b5, 0x0a7b5, Ll), (0x0a7b6, 0x0a7b6, Lu),
+    (0x0a7b7, 0x0a7b7, Ll), (0x0a7b8, 0x0a7b8, Lu), (0x0a7b9, 0x0a7b9, Ll),
+    (0x0a7ba, 0x0a7ba, Lu), (0x0a7bb, 0x0a7bb, Ll), (0x0a7bc, 0x0a7bc, Lu),
+    (0x0a7bd, 0x0a7bd, Ll), (0x0a7be, 0x0a7be, Lu), (0x0a7bf, 0x0a7bf, Ll),
+    (0x0a7c0, 0x0a7c0, Lu), (0x0a7c1, 0x0a7c1, Ll), (0x0a7c2, 0x0a7c2, Lu),
+    (0x0a7c3, 0x0a7c3, Ll), (0x0a7c4, 0x0a7c7, Lu), (0x0a7c8, 0x0a7c8, Ll),
+    (0x0a7c9, 0x0a7c9, Lu), (0x0a7ca, 0x0a7ca, Ll), (0x0a7cb, 0x0a7cc, Lu),
+    (0x0a7cd, 0x0a7cd, Ll), (0x0a7ce, 0x0a7ce, Lu), (0x0a7cf, 0x0a7cf, Ll),
+    (0x0a7d0, 0x0a7d0, Lu), (0x0a7d1, 0x0a7d1, Ll), (0x0a7d2, 0x0a7d2, Lu),
+    (0x0a7d3, 0x0a7d3, Ll), (0x0a7d4, 0x0a7d4, Lu), (0x0a7d5, 0x0a7d5, Ll),
+    (0x0a7d6, 0x0a7d6, Lu), (0x0a7d7, 0x0a7d7, Ll), (0x0a7d8, 0x0a7d8, Lu),
+    (0x0a7d9, 0x0a7d9, Ll), (0x0a7da, 0x0a7da, Lu), (0x0a7db, 0x0a7db, Ll),
+    (0x0a7dc, 0x0a7dc, Lu), (0x0a7f1, 0x0a7f4, Lm), (0x0a7f5, 0x0a7f5, Lu),
+    (0x0a7f6, 0x0a7f6, Ll), (0x0a7f7, 0x0a7f7, Lo), (0x0a7f8, 0x0a7f9, Lm),
+    (0x0a7fa, 0x0a7fa, Ll), (0x0a7fb, 0x0a801, Lo), (0x0a802, 0x0a802, Mn),
+    (0x0a803, 0x0a805, Lo), (0x0a806, 0x0a806, Mn), (0x0a807, 0x0a80a, Lo),
+    (0x0a80b, 0x0a80b, Mn), (0x0a80c, 0x0a822, Lo), (0x0a823, 0x0a824, Mc),
+    (0x0a825, 0x0a826, Mn), (0x0a827, 0x0a827, Mc), (0x0a828, 0x0a82b, So),
+    (0x0a82c, 0x0a82c, Mn), (0x0a830, 0x0a835, No), (0x0a836, 0x0a837, So),
+    (0x0a838, 0x0a838, Sc), (0x0a839, 0x0a839, So), (0x0a840, 0x0a873, Lo),
+    (0x0a874, 0x0a877, Po), (0x0a880, 0x0a881, Mc), (0x0a882, 0x0a8b3, Lo),
+    (0x0a8b4, 0x0a8c3, Mc), (0x0a8c4, 0x0a8c5, Mn), (0x0a8ce, 0x0a8cf, Po),
+    (0x0a8d0, 0x0a8d9, Nd), (0x0a8e0, 0x0a8f1, Mn), (0x0a8f2, 0x0a8f7, Lo),
+    (0x0a8f8, 0x0a8fa, Po), (0x0a8fb, 0x0a8fb, Lo), (0x0a8fc, 0x0a8fc, Po),
+    (0x0a8fd, 0x0a8fe, Lo), (0x0a8ff, 0x0a8ff, Mn), (0x0a900, 0x0a909, Nd),
+    (0x0a90a, 0x0a925, Lo), (0x0a926, 0x0a92d, Mn), (0x0a92e, 0x0a92f, Po),
+    (0x0a930, 0x0a946, Lo), (0x0a947, 0x0a951, Mn), (0x0a952, 0x0a953, Mc),
+    (0x0a95f, 0x0a95f, Po), (0x0a960, 0x0a97c, Lo), (0x0a980, 0x0a982, Mn),
+    (0x0a983, 0x0a983, Mc), (0x0a984, 0x0a9b2, Lo), (0x0a9b3, 0x0a9b3, Mn),
+    (0x0a9b4, 0x0a9b5, Mc), (0x0a9b6, 0x0a9b9, Mn), (0x0a9ba, 0x0a9bb, Mc),
+    (0x0a9bc, 0x0a9bd, Mn), (0x0a9be, 0x0a9c0, Mc), (0x0a9c1, 0x0a9cd, Po),
+    (0x0a9cf, 0x0a9cf, Lm), (0x0a9d0, 0x0a9d9, Nd), (0x0a9de, 0x0a9df, Po),
+    (0x0a9e0, 0x0a9e4, Lo), (0x0a9e5, 0x0a9e5, Mn), (0x0a9e6, 0x0a9e6, Lm),
+    (0x0a9e7, 0x0a9ef, Lo), (0x0a9f0, 0x0a9f9, Nd), (0x0a9fa, 0x0a9fe, Lo),
+    (0x0aa00, 0x0aa28, Lo), (0x0aa29, 0x0aa2e, Mn), (0x0aa2f, 0x0aa30, Mc),
+    (0x0aa31, 0x0aa32, Mn), (0x0aa33, 0x0aa34, Mc), (0x0aa35, 0x0aa36, Mn),
+    (0x0aa40, 0x0aa42, Lo), (0x0aa43, 0x0aa43, Mn), (0x0aa44, 0x0aa4b, Lo),
+    (0x0aa4c, 0x0aa4c, Mn), (0x0aa4d, 0x0aa4d, Mc), (0x0aa50, 0x0aa59, Nd),
+    (0x0aa5c, 0x0aa5f, Po), (0x0aa60, 0x0aa6f, Lo), (0x0aa70, 0x0aa70, Lm),
+    (0x0aa71, 0x0aa76, Lo), (0x0aa77, 0x0aa79, So), (0x0aa7a, 0x0aa7a, Lo),
+    (0x0aa7b, 0x0aa7b, Mc), (0x0aa7c, 0x0aa7c, Mn), (0x0aa7d, 0x0aa7d, Mc),
+    (0x0aa7e, 0x0aaaf, Lo), (0x0aab0, 0x0aab0, Mn), (0x0aab1, 0x0aab1, Lo),
+    (0x0aab2, 0x0aab4, Mn), (0x0aab5, 0x0aab6, Lo), (0x0aab7, 0x0aab8, Mn),
+    (0x0aab9, 0x0aabd, Lo), (0x0aabe, 0x0aabf, Mn), (0x0aac0, 0x0aac0, Lo),
+    (0x0aac1, 0x0aac1, Mn), (0x0aac2, 0x0aac2, Lo), (0x0aadb, 0x0aadc, Lo),
+    (0x0aadd, 0x0aadd, Lm), (0x0aade, 0x0aadf, Po), (0x0aae0, 0x0aaea, Lo),
+    (0x0aaeb, 0x0aaeb, Mc), (0x0aaec, 0x0aaed, Mn), (0x0aaee, 0x0aaef, Mc),
+    (0x0aaf0, 0x0aaf1, Po), (0x0aaf2, 0x0aaf2, Lo), (0x0aaf3, 0x0aaf4, Lm),
+    (0x0aaf5, 0x0aaf5, Mc), (0x0aaf6, 0x0aaf6, Mn), (0x0ab01, 0x0ab06, Lo),
+    (0x0ab09, 0x0ab0e, Lo), (0x0ab11, 0x0ab16, Lo), (0x0ab20, 0x0ab26, Lo),
+    (0x0ab28, 0x0ab2e, Lo), (0x0ab30, 0x0ab5a, Ll), (0x0ab5b, 0x0ab5b, Sk),
+    (0x0ab5c, 0x0ab5f, Lm), (0x0ab60, 0x0ab68, Ll), (0x0ab69, 0x0ab69, Lm),
+    (0x0ab6a, 0x0ab6b, Sk), (0x0ab70, 0x0abbf, Ll), (0x0abc0, 0x0abe2, Lo),
+    (0x0abe3, 0x0abe4, Mc), (0x0abe5, 0x0abe5, Mn), (0x0abe6, 0x0abe7, Mc),
+    (0x0abe8, 0x0abe8, Mn), (0x0abe9, 0x0abea, Mc), (0x0abeb, 0x0abeb, Po),
+    (0x0abec, 0x0abec, Mc), (0x0abed, 0x0abed, Mn), (0x0abf0, 0x0abf9, Nd),
+    (0x0ac00, 0x0d7a3, Lo), (0x0d7b0, 0x0d7c6, Lo), (0x0d7cb, 0x0d7fb, Lo),
+    (0x0d800, 0x0dfff, Cs), (0x0e000, 0x0f8ff, Co), (0x0f900, 0x0fa6d, Lo),
+    (0x0fa70, 0x0fad9, Lo), (0x0fb00, 0x0fb06, Ll), (0x0fb13, 0x0fb17, Ll),
+    (0x0fb1d, 0x0fb1d, Lo), (0x0fb1e, 0x0fb1e, Mn), (0x0fb1f, 0x0fb28, Lo),
+    (0x0fb29, 0x0fb29, Sm), (0x0fb2a, 0x0fb36, Lo), (0x0fb38, 0x0fb3c, Lo),
+    (0x0fb3e, 0x0fb3e, Lo), (0x0fb40, 0x0fb41, Lo), (0x0fb43, 0x0fb44, Lo),
+    (0x0fb46, 0x0fbb1, Lo), (0x0fbb2, 0x0fbc2, Sk), (0x0fbc3, 0x0fbd2, So),
+    (0x0fbd3, 0x0fd3d, Lo), (0x0fd3e, 0x0fd3e, Pe), (0x0fd3f, 0x0fd3f, Ps),
+    (0x0fd40, 0x0fd4f, So), (0x0fd50, 0x0fd8f, Lo), (0x0fd90, 0x0fd91, So),
+    (0x0fd92, 0x0fdc7, Lo), (0x0fdc8, 0x0fdcf, So), (0x0fdf0, 0x0fdfb, Lo),
+    (0x0fdfc, 0x0fdfc, Sc), (0x0fdfd, 0x0fdff, So), (0x0fe00, 0x0fe0f, Mn),
+    (0x0fe10, 0x0fe16, Po), (0x0fe17, 0x0fe17, Ps), (0x0fe18, 0x0fe18, Pe),
+    (0x0fe19, 0x0fe19, Po), (0x0fe20, 0x0fe2f, Mn), (0x0fe30, 0x0fe30, Po),
+    (0x0fe31, 0x0fe32, Pd), (0x0fe33, 0x0fe34, Pc), (0x0fe35, 0x0fe35, Ps),
+    (0x0fe36, 0x0fe36, Pe), (0x0fe37, 0x0fe37, Ps), (0x0fe38, 0x0fe38, Pe),
+    (0x0fe39, 0x0fe39, Ps), (0x0fe3a, 0x0fe3a, Pe), (0x0fe3b, 0x0fe3b, Ps),
+    (0x0fe3c, 0x0fe3c, Pe), (0x0fe3d, 0x0fe3d, Ps), (0x0fe3e, 0x0fe3e, Pe),
+    (0x0fe3f, 0x0fe3f, Ps), (0x0fe40, 0x0fe40, Pe), (0x0fe41, 0x0fe41, Ps),
+    (0x0fe42, 0x0fe42, Pe), (0x0fe43, 0x0fe43, Ps), (0x0fe44, 0x0fe44, Pe),
+    (0x0fe45, 0x0fe46, Po), (0x0fe47, 0x0fe47, Ps), (0x0fe48, 0x0fe48, Pe),
+    (0x0fe49, 0x0fe4c, Po), (0x0fe4d, 0x0fe4f, Pc), (0x0fe50, 0x0fe52, Po),
+    (0x0fe54, 0x0fe57, Po), (0x0fe58, 0x0fe58, Pd), (0x0fe59, 0x0fe59, Ps),
+    (0x0fe5a, 0x0fe5a, Pe), (0x0fe5b, 0x0fe5b, Ps), (0x0fe5c, 0x0fe5c, Pe),
+    (0x0fe5d, 0x0fe5d, Ps), (0x0fe5e, 0x0fe5e, Pe), (0x0fe5f, 0x0fe61, Po),
+    (0x0fe62, 0x0fe62, Sm), (0x0fe63, 0x0fe63, Pd), (0x0fe64, 0x0fe66, Sm),
+    (0x0fe68, 0x0fe68, Po), (0x0fe69, 0x0fe69, Sc), (0x0fe6a, 0x0fe6b, Po),
+    (0x0fe70, 0x0fe74, Lo), (0x0fe76, 0x0fefc, Lo), (0x0feff, 0x0feff, Cf),
+    (0x0ff01, 0x0ff03, Po), (0x0ff04, 0x0ff04, Sc), (0x0ff05, 0x0ff07, Po),
+    (0x0ff08, 0x0ff08, Ps), (0x0ff09, 0x0ff09, Pe), (0x0ff0a, 0x0ff0a, Po),
+    (0x0ff0b, 0x0ff0b, Sm), (0x0ff0c, 0x0ff0c, Po), (0x0ff0d, 0x0ff0d, Pd),
+    (0x0ff0e, 0x0ff0f, Po), (0x0ff10, 0x0ff19, Nd), (0x0ff1a, 0x0ff1b, Po),
+    (0x0ff1c, 0x0ff1e, Sm), (0x0ff1f, 0x0ff20, Po), (0x0ff21, 0x0ff3a, Lu),
+    (0x0ff3b, 0x0ff3b, Ps), (0x0ff3c, 0x0ff3c, Po), (0x0ff3d, 0x0ff3d, Pe),
+    (0x0ff3e, 0x0ff3e, Sk), (0x0ff3f, 0x0ff3f, Pc), (0x0ff40, 0x0ff40, Sk),
+    (0x0ff41, 0x0ff5a, Ll), (0x0ff5b, 0x0ff5b, Ps), (0x0ff5c, 0x0ff5c, Sm),
+    (0x0ff5d, 0x0ff5d, Pe), (0x0ff5e, 0x0ff5e, Sm), (0x0ff5f, 0x0ff5f, Ps),
+    (0x0ff60, 0x0ff60, Pe), (0x0ff61, 0x0ff61, Po), (0x0ff62, 0x0ff62, Ps),
+    (0x0ff63, 0x0ff63, Pe), (0x0ff64, 0x0ff65, Po), (0x0ff66, 0x0ff6f, Lo),
+    (0x0ff70, 0x0ff70, Lm), (0x0ff71, 0x0ff9d, Lo), (0x0ff9e, 0x0ff9f, Lm),
+    (0x0ffa0, 0x0ffbe, Lo), (0x0ffc2, 0x0ffc7, Lo), (0x0ffca, 0x0ffcf, Lo),
+    (0x0ffd2, 0x0ffd7, Lo), (0x0ffda, 0x0ffdc, Lo), (0x0ffe0, 0x0ffe1, Sc),
+    (0x0ffe2, 0x0ffe2, Sm), (0x0ffe3, 0x0ffe3, Sk), (0x0ffe4, 0x0ffe4, So),
+    (0x0ffe5, 0x0ffe6, Sc), (0x0ffe8, 0x0ffe8, So), (0x0ffe9, 0x0ffec, Sm),
+    (0x0ffed, 0x0ffee, So), (0x0fff9, 0x0fffb, Cf), (0x0fffc, 0x0fffd, So),
+    (0x10000, 0x1000b, Lo), (0x1000d, 0x10026, Lo), (0x10028, 0x1003a, Lo),
+    (0x1003c, 0x1003d, Lo), (0x1003f, 0x1004d, Lo), (0x10050, 0x1005d, Lo),
+    (0x10080, 0x100fa, Lo), (0x10100, 0x10102, Po), (0x10107, 0x10133, No),
+    (0x10137, 0x1013f, So), (0x10140, 0x10174, Nl), (0x10175, 0x10178, No),
+    (0x10179, 0x10189, So), (0x1018a, 0x1018b, No), (0x1018c, 0x1018e, So),
+    (0x10190, 0x1019c, So), (0x101a0, 0x101a0, So), (0x101d0, 0x101fc, So),
+    (0x101fd, 0x101fd, Mn), (0x10280, 0x1029c, Lo), (0x102a0, 0x102d0, Lo),
+    (0x102e0, 0x102e0, Mn), (0x102e1, 0x102fb, No), (0x10300, 0x1031f, Lo),
+    (0x10320, 0x10323, No), (0x1032d, 0x10340, Lo), (0x10341, 0x10341, Nl),
+    (0x10342, 0x10349, Lo), (0x1034a, 0x1034a, Nl), (0x10350, 0x10375, Lo),
+    (0x10376, 0x1037a, Mn), (0x10380, 0x1039d, Lo), (0x1039f, 0x1039f, Po),
+    (0x103a0, 0x103c3, Lo), (0x103c8, 0x103cf, Lo), (0x103d0, 0x103d0, Po),
+    (0x103d1, 0x103d5, Nl), (0x10400, 0x10427, Lu), (0x10428, 0x1044f, Ll),
+    (0x10450, 0x1049d, Lo), (0x104a0, 0x104a9, Nd), (0x104b0, 0x104d3, Lu),
+    (0x104d8, 0x104fb, Ll), (0x10500, 0x10527, Lo), (0x10530, 0x10563, Lo),
+    (0x1056f, 0x1056f, Po), (0x10570, 0x1057a, Lu), (0x1057c, 0x1058a, Lu),
+    (0x1058c, 0x10592, Lu), (0x10594, 0x10595, Lu), (0x10597, 0x105a1, Ll),
+    (0x105a3, 0x105b1, Ll), (0x105b3, 0x105b9, Ll), (0x105bb, 0x105bc, Ll),
+    (0x105c0, 0x105f3, Lo), (0x10600, 0x10736, Lo), (0x10740, 0x10755, Lo),
+    (0x10760, 0x10767, Lo), (0x10780, 0x10785, Lm), (0x10787, 0x107b0, Lm),
+    (0x107b2, 0x107ba, Lm), (0x10800, 0x10805, Lo), (0x10808, 0x10808, Lo),
+    (0x1080a, 0x10835, Lo), (0x10837, 0x10838, Lo), (0x1083c, 0x1083c, Lo),
+    (0x1083f, 0x10855, Lo), (0x10857, 0x10857, Po), (0x10858, 0x1085f, No),
+    (0x10860, 0x10876, Lo), (0x10877, 0x10878, So), (0x10879, 0x1087f, No),
+    (0x10880, 0x1089e, Lo), (0x108a7, 0x108af, No), (0x108e0, 0x108f2, Lo),
+    (0x108f4, 0x108f5, Lo), (0x108fb, 0x108ff, No), (0x10900, 0x10915, Lo),
+    (0x10916, 0x1091b, No), (0x1091f, 0x1091f, Po), (0x10920, 0x10939, Lo),
+    (0x1093f, 0x1093f, Po), (0x10940, 0x10959, Lo), (0x10980, 0x109b7, Lo),
+    (0x109bc, 0x109bd, No), (0x109be, 0x109bf, Lo), (0x109c0, 0x109cf, No),
+    (0x109d2, 0x109ff, No), (0x10a00, 0x10a00, Lo), (0x10a01, 0x10a03, Mn),
+    (0x10a05, 0x10a06, Mn), (0x10a0c, 0x10a0f, Mn), (0x10a10, 0x10a13, Lo),
+    (0x10a15, 0x10a17, Lo), (0x10a19, 0x10a35, Lo), (0x10a38, 0x10a3a, Mn),
+    (0x10a3f, 0x10a3f, Mn), (0x10a40, 0x10a48, No), (0x10a50, 0x10a58, Po),
+    (0x10a60, 0x10a7c, Lo), (0x10a7d, 0x10a7e, No), (0x10a7f, 0x10a7f, Po),
+    (0x10a80, 0x10a9c, Lo), (0x10a9d, 0x10a9f, No), (0x10ac0, 0x10ac7, Lo),
+    (0x10ac8, 0x10ac8, So), (0x10ac9, 0x10ae4, Lo), (0x10ae5, 0x10ae6, Mn),
+    (0x10aeb, 0x10aef, No), (0x10af0, 0x10af6, Po), (0x10b00, 0x10b35, Lo),
+    (0x10b39, 0x10b3f, Po), (0x10b40, 0x10b55, Lo), (0x10b58, 0x10b5f, No),
+    (0x10b60, 0x10b72, Lo), (0x10b78, 0x10b7f, No), (0x10b80, 0x10b91, Lo),
+    (0x10b99, 0x10b9c, Po), (0x10ba9, 0x10baf, No), (0x10c00, 0x10c48, Lo),
+    (0x10c80, 0x10cb2, Lu), (0x10cc0, 0x10cf2, Ll), (0x10cfa, 0x10cff, No),
+    (0x10d00, 0x10d23, Lo), (0x10d24, 0x10d27, Mn), (0x10d30, 0x10d39, Nd),
+    (0x10d40, 0x10d49, Nd), (0x10d4a, 0x10d4d, Lo), (0x10d4e, 0x10d4e, Lm),
+    (0x10d4f, 0x10d4f, Lo), (0x10d50, 0x10d65, Lu), (0x10d69, 0x10d6d, Mn),
+    (0x10d6e, 0x10d6e, Pd), (0x10d6f, 0x10d6f, Lm), (0x10d70, 0x10d85, Ll),
+    (0x10d8e, 0x10d8f, Sm), (0x10e60, 0x10e7e, No), (0x10e80, 0x10ea9, Lo),
+    (0x10eab, 0x10eac, Mn), (0x10ead, 0x10ead, Pd), (0x10eb0, 0x10eb1, Lo),
+    (0x10ec2, 0x10ec4, Lo), (0x10ec5, 0x10ec5, Lm), (0x10ec6, 0x10ec7, Lo),
+    (0x10ed0, 0x10ed0, Po), (0x10ed1, 0x10ed8, So), (0x10efa, 0x10eff, Mn),
+    (0x10f00, 0x10f1c, Lo), (0x10f1d, 0x10f26, No), (0x10f27, 0x10f27, Lo),
+    (0x10f30, 0x10f45, Lo), (0x10f46, 0x10f50, Mn), (0x10f51, 0x10f54, No),
+    (0x10f55, 0x10f59, Po), (0x10f70, 0x10f81, Lo), (0x10f82, 0x10f85, Mn),
+    (0x10f86, 0x10f89, Po), (0x10fb0, 0x10fc4, Lo), (0x10fc5, 0x10fcb, No),
+    (0x10fe0, 0x10ff6, Lo), (0x11000, 0x11000, Mc), (0x11001, 0x11001, Mn),
+    (0x11002, 0x11002, Mc), (0x11003, 0x11037, Lo), (0x11038, 0x11046, Mn),
+    (0x11047, 0x1104d, Po), (0x11052, 0x11065, No), (0x11066, 0x1106f, Nd),
+    (0x11070, 0x11070, Mn), (0x11071, 0x11072, Lo), (0x11073, 0x11074, Mn),
+    (0x11075, 0x11075, Lo), (0x1107f, 0x11081, Mn), (0x11082, 0x11082, Mc),
+    (0x11083, 0x110af, Lo), (0x110b0, 0x110b2, Mc), (0x110b3, 0x110b6, Mn),
+    (0x110b7, 0x110b8, Mc), (0x110b9, 0x110ba, Mn), (0x110bb, 0x110bc, Po),
+    (0x110bd, 0x110bd, Cf), (0x110be, 0x110c1, Po), (0x110c2, 0x110c2, Mn),
+    (0x110cd, 0x110cd, Cf), (0x110d0, 0x110e8, Lo), (0x110f0, 0x110f9, Nd),
+    (0x11100, 0x11102, Mn), (0x11103, 0x11126, Lo), (0x11127, 0x1112b, Mn),
+    (0x1112c, 0x1112c, Mc), (0x1112d, 0x11134, Mn), (0x11136, 0x1113f, Nd),
+    (0x11140, 0x11143, Po), (0x11144, 0x11144, Lo), (0x11145, 0x11146, Mc),
+    (0x11147, 0x11147, Lo), (0x11150, 0x11172, Lo), (0x11173, 0x11173, Mn),
+    (0x11174, 0x11175, Po), (0x11176, 0x11176, Lo), (0x11180, 0x11181, Mn),
+    (0x11182, 0x11182, Mc), (0x11183, 0x111b2, Lo), (0x111b3, 0x111b5, Mc),
+    (0x111b6, 0x111be, Mn), (0x111bf, 0x111c0, Mc), (0x111c1, 0x111c4, Lo),
+    (0x111c5, 0x111c8, Po), (0x111c9, 0x111cc, Mn), (0x111cd, 0x111cd, Po),
+    (0x111ce, 0x111ce, Mc), (0x111cf, 0x111cf, Mn), (0x111d0, 0x111d9, Nd),
+    (0x111da, 0x111da, Lo), (0x111db, 0x111db, Po), (0x111dc, 0x111dc, Lo),
+    (0x111dd, 0x111df, Po), (0x111e1, 0x111f4, No), (0x11200, 0x11211, Lo),
+    (0x11213, 0x1122b, Lo), (0x1122c, 0x1122e, Mc), (0x1122f, 0x11231, Mn),
+    (0x11232, 0x11233, Mc), (0x11234, 0x11234, Mn), (0x11235, 0x11235, Mc),
+    (0x11236, 0x11237, Mn), (0x11238, 0x1123d, Po), (0x1123e, 0x1123e, Mn),
+    (0x1123f, 0x11240, Lo), (0x11241, 0x11241, Mn), (0x11280, 0x11286, Lo),
+    (0x11288, 0x11288, Lo), (0x1128a, 0x1128d, Lo), (0x1128f, 0x1129d, Lo),
+    (0x1129f, 0x112a8, Lo), (0x112a9, 0x112a9, Po), (0x112b0, 0x112de, Lo),
+    (0x112df, 0x112df, Mn), (0x112e0, 0x112e2, Mc), (0x112e3, 0x112ea, Mn),
+    (0x112f0, 0x112f9, Nd), (0x11300, 0x11301, Mn), (0x11302, 0x11303, Mc),
+    (0x11305, 0x1130c, Lo), (0x1130f, 0x11310, Lo), (0x11313, 0x11328, Lo),
+    (0x1132a, 0x11330, Lo), (0x11332, 0x11333, Lo), (0x11335, 0x11339, Lo),
+    (0x1133b, 0x1133c, Mn), (0x1133d, 0x1133d, Lo), (0x1133e, 0x1133f, Mc),
+    (0x11340, 0x11340, Mn), (0x11341, 0x11344, Mc), (0x11347, 0x11348, Mc),
+    (0x1134b, 0x1134d, Mc), (0x11350, 0x11350, Lo), (0x11357, 0x11357, Mc),
+    (0x1135d, 0x11361, Lo), (0x11362, 0x11363, Mc), (0x11366, 0x1136c, Mn),
+    (0x11370, 0x11374, Mn), (0x11380, 0x11389, Lo), (0x1138b, 0x1138b, Lo),
+    (0x1138e, 0x1138e, Lo), (0x11390, 0x113b5, Lo), (0x113b7, 0x113b7, Lo),
+    (0x113b8, 0x113ba, Mc), (0x113bb, 0x113c0, Mn), (0x113c2, 0x113c2, Mc),
+    (0x113c5, 0x113c5, Mc), (0x113c7, 0x113ca, Mc), (0x113cc, 0x113cd, Mc),
+    (0x113ce, 0x113ce, Mn), (0x113cf, 0x113cf, Mc), (0x113d0, 0x113d0, Mn),
+    (0x113d1, 0x113d1, Lo), (0x113d2, 0x113d2, Mn), (0x113d3, 0x113d3, Lo),
+    (0x113d4, 0x113d5, Po), (0x113d7, 0x113d8, Po), (0x113e1, 0x113e2, Mn),
+    (0x11400, 0x11434, Lo), (0x11435, 0x11437, Mc), (0x11438, 0x1143f, Mn),
+    (0x11440, 0x11441, Mc), (0x11442, 0x11444, Mn), (0x11445, 0x11445, Mc),
+    (0x11446, 0x11446, Mn), (0x11447, 0x1144a, Lo), (0x1144b, 0x1144f, Po),
+    (0x11450, 0x11459, Nd), (0x1145a, 0x1145b, Po), (0x1145d, 0x1145d, Po),
+    (0x1145e, 0x1145e, Mn), (0x1145f, 0x11461, Lo), (0x11480, 0x114af, Lo),
+    (0x114b0, 0x114b2, Mc), (0x114b3, 0x114b8, Mn), (0x114b9, 0x114b9, Mc),
+    (0x114ba, 0x114ba, Mn), (0x114bb, 0x114be, Mc), (0x114bf, 0x114c0, Mn),
+    (0x114c1, 0x114c1, Mc), (0x114c2, 0x114c3, Mn), (0x114c4, 0x114c5, Lo),
+    (0x114c6, 0x114c6, Po), (0x114c7, 0x114c7, Lo), (0x114d0, 0x114d9, Nd),
+    (0x11580, 0x115ae, Lo), (0x115af, 0x115b1, Mc), (0x115b2, 0x115b5, Mn),
+    (0x115b8, 0x115bb, Mc), (0x115bc, 0x115bd, Mn), (0x115be, 0x115be, Mc),
+    (0x115bf, 0x115c0, Mn), (0x115c1, 0x115d7, Po), (0x115d8, 0x115db, Lo),
+    (0x115dc, 0x115dd, Mn), (0x11600, 0x1162f, Lo), (0x11630, 0x11632, Mc),
+    (0x11633, 0x1163a, Mn), (0x1163b, 0x1163c, Mc), (0x1163d, 0x1163d, Mn),
+    (0x1163e, 0x1163e, Mc), (0x1163f, 0x11640, Mn), (0x11641, 0x11643, Po),
+    (0x11644, 0x11644, Lo), (0x11650, 0x11659, Nd), (0x11660, 0x1166c, Po),
+    (0x11680, 0x116aa, Lo), (0x116ab, 0x116ab, Mn), (0x116ac, 0x116ac, Mc),
+    (0x116ad, 0x116ad, Mn), (0x116ae, 0x116af, Mc), (0x116b0, 0x116b5, Mn),
+    (0x116b6, 0x116b6, Mc), (0x116b7, 0x116b7, Mn), (0x116b8, 0x116b8, Lo),
+    (0x116b9, 0x116b9, Po), (0x116c0, 0x116c9, Nd), (0x116d0, 0x116e3, Nd),
+    (0x11700, 0x1171a, Lo), (0x1171d, 0x1171d, Mn), (0x1171e, 0x1171e, Mc),
+    (0x1171f, 0x1171f, Mn), (0x11720, 0x11721, Mc), (0x11722, 0x11725, Mn),
+    (0x11726, 0x11726, Mc), (0x11727, 0x1172b, Mn), (0x11730, 0x11739, Nd),
+    (0x1173a, 0x1173b, No), (0x1173c, 0x1173e, Po), (0x1173f, 0x1173f, So),
+    (0x11740, 0x11746, Lo), (0x11800, 0x1182b, Lo), (0x1182c, 0x1182e, Mc),
+    (0x1182f, 0x11837, Mn), (0x11838, 0x11838, Mc), (0x11839, 0x1183a, Mn),
+    (0x1183b, 0x1183b, Po), (0x118a0, 0x118bf, Lu), (0x118c0, 0x118df, Ll),
+    (0x118e0, 0x118e9, Nd), (0x118ea, 0x118f2, No), (0x118ff, 0x11906, Lo),
+    (0x11909, 0x11909, Lo), (0x1190c, 0x11913, Lo), (0x11915, 0x11916, Lo),
+    (0x11918, 0x1192f, Lo), (0x11930, 0x11935, Mc), (0x11937, 0x11938, Mc),
+    (0x1193b, 0x1193c, Mn), (0x1193d, 0x1193d, Mc), (0x1193e, 0x1193e, Mn),
+    (0x1193f, 0x1193f, Lo), (0x11940, 0x11940, Mc), (0x11941, 0x11941, Lo),
+    (0x11942, 0x11942, Mc), (0x11943, 0x11943, Mn), (0x11944, 0x11946, Po),
+    (0x11950, 0x11959, Nd), (0x119a0, 0x119a7, Lo), (0x119aa, 0x119d0, Lo),
+    (0x119d1, 0x119d3, Mc), (0x119d4, 0x119d7, Mn), (0x119da, 0x119db, Mn),
+    (0x119dc, 0x119df, Mc), (0x119e0, 0x119e0, Mn), (0x119e1, 0x119e1, Lo),
+    (0x119e2, 0x119e2, Po), (0x119e3, 0x119e3, Lo), (0x119e4, 0x119e4, Mc),
+    (0x11a00, 0x11a00, Lo), (0x11a01, 0x11a0a, Mn), (0x11a0b, 0x11a32, Lo),
+    (0x11a33, 0x11a38, Mn), (0x11a39, 0x11a39, Mc), (0x11a3a, 0x11a3a, Lo),
+    (0x11a3b, 0x11a3e, Mn), (0x11a3f, 0x11a46, Po), (0x11a47, 0x11a47, Mn),
+    (0x11a50, 0x11a50, Lo), (0x11a51, 0x11a56, Mn), (0x11a57, 0x11a58, Mc),
+    (0x11a59, 0x11a5b, Mn), (0x11a5c, 0x11a89, Lo), (0x11a8a, 0x11a96, Mn),
+    (0x11a97, 0x11a97, Mc), (0x11a98, 0x11a99, Mn), (0x11a9a, 0x11a9c, Po),
+    (0x11a9d, 0x11a9d, Lo), (0x11a9e, 0x11aa2, Po), (0x11ab0, 0x11af8, Lo),
+    (0x11b00, 0x11b09, Po), (0x11b60, 0x11b60, Mn), (0x11b61, 0x11b61, Mc),
+    (0x11b62, 0x11b64, Mn), (0x11b65, 0x11b65, Mc), (0x11b66, 0x11b66, Mn),
+    (0x11b67, 0x11b67, Mc), (0x11bc0, 0x11be0, Lo), (0x11be1, 0x11be1, Po),
+    (0x11bf0, 0x11bf9, Nd), (0x11c00, 0x11c08, Lo), (0x11c0a, 0x11c2e, Lo),
+    (0x11c2f, 0x11c2f, Mc), (0x11c30, 0x11c36, Mn), (0x11c38, 0x11c3d, Mn),
+    (0x11c3e, 0x11c3e, Mc), (0x11c3f, 0x11c3f, Mn), (0x11c40, 0x11c40, Lo),
+    (0x11c41, 0x11c45, Po), (0x11c50, 0x11c59, Nd), (0x11c5a, 0x11c6c, No),
+    (0x11c70, 0x11c71, Po), (0x11c72, 0x11c8f, Lo), (0x11c92, 0x11ca7, Mn),
+    (0x11ca9, 0x11ca9, Mc), (0x11caa, 0x11cb0, Mn), (0x11cb1, 0x11cb1, Mc),
+    (0x11cb2, 0x11cb3, Mn), (0x11cb4, 0x11cb4, Mc), (0x11cb5, 0x11cb6, Mn),
+    (0x11d00, 0x11d06, Lo), (0x11d08, 0x11d09, Lo), (0x11d0b, 0x11d30, Lo),
+    (0x11d31, 0x11d36, Mn), (0x11d3a, 0x11d3a, Mn), (0x11d3c, 0x11d3d, Mn),
+    (0x11d3f, 0x11d45, Mn), (0x11d46, 0x11d46, Lo), (0x11d47, 0x11d47, Mn),
+    (0x11d50, 0x11d59, Nd), (0x11d60, 0x11d65, Lo), (0x11d67, 0x11d68, Lo),
+    (0x11d6a, 0x11d89, Lo), (0x11d8a, 0x11d8e, Mc), (0x11d90, 0x11d91, Mn),
+    (0x11d93, 0x11d94, Mc), (0x11d95, 0x11d95, Mn), (0x11d96, 0x11d96, Mc),
+    (0x11d97, 0x11d97, Mn), (0x11d98, 0x11d98, Lo), (0x11da0, 0x11da9, Nd),
+    (0x11db0, 0x11dd8, Lo), (0x11dd9, 0x11dd9, Lm), (0x11dda, 0x11ddb, Lo),
+    (0x11de0, 0x11de9, Nd), (0x11ee0, 0x11ef2, Lo), (0x11ef3, 0x11ef4, Mn),
+    (0x11ef5, 0x11ef6, Mc), (0x11ef7, 0x11ef8, Po), (0x11f00, 0x11f01, Mn),
+    (0x11f02, 0x11f02, Lo), (0x11f03, 0x11f03, Mc), (0x11f04, 0x11f10, Lo),
+    (0x11f12, 0x11f33, Lo), (0x11f34, 0x11f35, Mc), (0x11f36, 0x11f3a, Mn),
+    (0x11f3e, 0x11f3f, Mc), (0x11f40, 0x11f40, Mn), (0x11f41, 0x11f41, Mc),
+    (0x11f42, 0x11f42, Mn), (0x11f43, 0x11f4f, Po), (0x11f50, 0x11f59, Nd),
+    (0x11f5a, 0x11f5a, Mn), (0x11fb0, 0x11fb0, Lo), (0x11fc0, 0x11fd4, No),
+    (0x11fd5, 0x11fdc, So), (0x11fdd, 0x11fe0, Sc), (0x11fe1, 0x11ff1, So),
+    (0x11fff, 0x11fff, Po), (0x12000, 0x12399, Lo), (0x12400, 0x1246e, Nl),
+    (0x12470, 0x12474, Po), (0x12480, 0x12543, Lo), (0x12f90, 0x12ff0, Lo),
+    (0x12ff1, 0x12ff2, Po), (0x13000, 0x1342f, Lo), (0x13430, 0x1343f, Cf),
+    (0x13440, 0x13440, Mn), (0x13441, 0x13446, Lo), (0x13447, 0x13455, Mn),
+    (0x13460, 0x143fa, Lo), (0x14400, 0x14646, Lo), (0x16100, 0x1611d, Lo),
+    (0x1611e, 0x16129, Mn), (0x1612a, 0x1612c, Mc), (0x1612d, 0x1612f, Mn),
+    (0x16130, 0x16139, Nd), (0x16800, 0x16a38, Lo), (0x16a40, 0x16a5e, Lo),
+    (0x16a60, 0x16a69, Nd), (0x16a6e, 0x16a6f, Po), (0x16a70, 0x16abe, Lo),
+    (0x16ac0, 0x16ac9, Nd), (0x16ad0, 0x16aed, Lo), (0x16af0, 0x16af4, Mn),
+    (0x16af5, 0x16af5, Po), (0x16b00, 0x16b2f, Lo), (0x16b30, 0x16b36, Mn),
+    (0x16b37, 0x16b3b, Po), (0x16b3c, 0x16b3f, So), (0x16b40, 0x16b43, Lm),
+    (0x16b44, 0x16b44, Po), (0x16b45, 0x16b45, So), (0x16b50, 0x16b59, Nd),
+    (0x16b5b, 0x16b61, No), (0x16b63, 0x16b77, Lo), (0x16b7d, 0x16b8f, Lo),
+    (0x16d40, 0x16d42, Lm), (0x16d43, 0x16d6a, Lo), (0x16d6b, 0x16d6c, Lm),
+    (0x16d6d, 0x16d6f, Po), (0x16d70, 0x16d79, Nd), (0x16e40, 0x16e5f, Lu),
+    (0x16e60, 0x16e7f, Ll), (0x16e80, 0x16e96, No), (0x16e97, 0x16e9a, Po),
+    (0x16ea0, 0x16eb8, Lu), (0x16ebb, 0x16ed3, Ll), (0x16f00, 0x16f4a, Lo),
+    (0x16f4f, 0x16f4f, Mn), (0x16f50, 0x16f50, Lo), (0x16f51, 0x16f87, Mc),
+    (0x16f8f, 0x16f92, Mn), (0x16f93, 0x16f9f, Lm), (0x16fe0, 0x16fe1, Lm),
+    (0x16fe2, 0x16fe2, Po), (0x16fe3, 0x16fe3, Lm), (0x16fe4, 0x16fe4, Mn),
+    (0x16ff0, 0x16ff1, Mc), (0x16ff2, 0x16ff3, Lm), (0x16ff4, 0x16ff6, Nl),
+    (0x17000, 0x18cd5, Lo), (0x18cff, 0x18d1e, Lo), (0x18d80, 0x18df2, Lo),
+    (0x1aff0, 0x1aff3, Lm), (0x1aff5, 0x1affb, Lm), (0x1affd, 0x1affe, Lm),
+    (0x1b000, 0x1b122, Lo), (0x1b132, 0x1b132, Lo), (0x1b150, 0x1b152, Lo),
+    (0x1b155, 0x1b155, Lo), (0x1b164, 0x1b167, Lo), (0x1b170, 0x1b2fb, Lo),
+    (0x1bc00, 0x1bc6a, Lo), (0x1bc70, 0x1bc7c, Lo), (0x1bc80, 0x1bc88, Lo),
+    (0x1bc90, 0x1bc99, Lo), (0x1bc9c, 0x1bc9c, So), (0x1bc9d, 0x1bc9e, Mn),
+    (0x1bc9f, 0x1bc9f, Po), (0x1bca0, 0x1bca3, Cf), (0x1cc00, 0x1ccef, So),
+    (0x1ccf0, 0x1ccf9, Nd), (0x1ccfa, 0x1ccfc, So), (0x1cd00, 0x1ceb3, So),
+    (0x1ceba, 0x1ced0, So), (0x1cee0, 0x1ceef, So), (0x1cef0, 0x1cef0, Sm),
+    (0x1cf00, 0x1cf2d, Mn), (0x1cf30, 0x1cf46, Mn), (0x1cf50, 0x1cfc3, So),
+    (0x1d000, 0x1d0f5, So), (0x1d100, 0x1d126, So), (0x1d129, 0x1d164, So),
+    (0x1d165, 0x1d166, Mc), (0x1d167, 0x1d169, Mn), (0x1d16a, 0x1d16c, So),
+    (0x1d16d, 0x1d172, Mc), (0x1d173, 0x1d17a, Cf), (0x1d17b, 0x1d182, Mn),
+    (0x1d183, 0x1d184, So), (0x1d185, 0x1d18b, Mn), (0x1d18c, 0x1d1a9, So),
+    (0x1d1aa, 0x1d1ad, Mn), (0x1d1ae, 0x1d1ea, So), (0x1d200, 0x1d241, So),
+    (0x1d242, 0x1d244, Mn), (0x1d245, 0x1d245, So), (0x1d2c0, 0x1d2d3, No),
+    (0x1d2e0, 0x1d2f3, No), (0x1d300, 0x1d356, So), (0x1d360, 0x1d378, No),
+    (0x1d400, 0x1d419, Lu), (0x1d41a, 0x1d433, Ll), (0x1d434, 0x1d44d, Lu),
+    (0x1d44e, 0x1d454, Ll), (0x1d456, 0x1d467, Ll), (0x1d468, 0x1d481, Lu),
+    (0x1d482, 0x1d49b, Ll), (0x1d49c, 0x1d49c, Lu), (0x1d49e, 0x1d49f, Lu),
+    (0x1d4a2, 0x1d4a2, Lu), (0x1d4a5, 0x1d4a6, Lu), (0x1d4a9, 0x1d4ac, Lu),
+    (0x1d4ae, 0x1d4b5, Lu), (0x1d4b6, 0x1d4b9, Ll), (0x1d4bb, 0x1d4bb, Ll),
+    (0x1d4bd, 0x1d4c3, Ll), (0x1d4c5, 0x1d4cf, Ll), (0x1d4d0, 0x1d4e9, Lu),
+    (0x1d4ea, 0x1d503, Ll), (0x1d504, 0x1d505, Lu), (0x1d507, 0x1d50a, Lu),
+    (0x1d50d, 0x1d514, Lu), (0x1d516, 0x1d51c, Lu), (0x1d51e, 0x1d537, Ll),
+    (0x1d538, 0x1d539, Lu), (0x1d53b, 0x1d53e, Lu), (0x1d540, 0x1d544, Lu),
+    (0x1d546, 0x1d546, Lu), (0x1d54a, 0x1d550, Lu), (0x1d552, 0x1d56b, Ll),
+    (0x1d56c, 0x1d585, Lu), (0x1d586, 0x1d59f, Ll), (0x1d5a0, 0x1d5b9, Lu),
+    (0x1d5ba, 0x1d5d3, Ll), (0x1d5d4, 0x1d5ed, Lu), (0x1d5ee, 0x1d607, Ll),
+    (0x1d608, 0x1d621, Lu), (0x1d622, 0x1d63b, Ll), (0x1d63c, 0x1d655, Lu),
+    (0x1d656, 0x1d66f, Ll), (0x1d670, 0x1d689, Lu), (0x1d68a, 0x1d6a5, Ll),
+    (0x1d6a8, 0x1d6c0, Lu), (0x1d6c1, 0x1d6c1, Sm), (0x1d6c2, 0x1d6da, Ll),
+    (0x1d6db, 0x1d6db, Sm), (0x1d6dc, 0x1d6e1, Ll), (0x1d6e2, 0x1d6fa, Lu),
+    (0x1d6fb, 0x1d6fb, Sm), (0x1d6fc, 0x1d714, Ll), (0x1d715, 0x1d715, Sm),
+    (0x1d716, 0x1d71b, Ll), (0x1d71c, 0x1d734, Lu), (0x1d735, 0x1d735, Sm),
+    (0x1d736, 0x1d74e, Ll), (0x1d74f, 0x1d74f, Sm), (0x1d750, 0x1d755, Ll),
+    (0x1d756, 0x1d76e, Lu), (0x1d76f, 0x1d76f, Sm), (0x1d770, 0x1d788, Ll),
+    (0x1d789, 0x1d789, Sm), (0x1d78a, 0x1d78f, Ll), (0x1d790, 0x1d7a8, Lu),
+    (0x1d7a9, 0x1d7a9, Sm), (0x1d7aa, 0x1d7c2, Ll), (0x1d7c3, 0x1d7c3, Sm),
+    (0x1d7c4, 0x1d7c9, Ll), (0x1d7ca, 0x1d7ca, Lu), (0x1d7cb, 0x1d7cb, Ll),
+    (0x1d7ce, 0x1d7ff, Nd), (0x1d800, 0x1d9ff, So), (0x1da00, 0x1da36, Mn),
+    (0x1da37, 0x1da3a, So), (0x1da3b, 0x1da6c, Mn), (0x1da6d, 0x1da74, So),
+    (0x1da75, 0x1da75, Mn), (0x1da76, 0x1da83, So), (0x1da84, 0x1da84, Mn),
+    (0x1da85, 0x1da86, So), (0x1da87, 0x1da8b, Po), (0x1da9b, 0x1da9f, Mn),
+    (0x1daa1, 0x1daaf, Mn), (0x1df00, 0x1df09, Ll), (0x1df0a, 0x1df0a, Lo),
+    (0x1df0b, 0x1df1e, Ll), (0x1df25, 0x1df2a, Ll), (0x1e000, 0x1e006, Mn),
+    (0x1e008, 0x1e018, Mn), (0x1e01b, 0x1e021, Mn), (0x1e023, 0x1e024, Mn),
+    (0x1e026, 0x1e02a, Mn), (0x1e030, 0x1e06d, Lm), (0x1e08f, 0x1e08f, Mn),
+    (0x1e100, 0x1e12c, Lo), (0x1e130, 0x1e136, Mn), (0x1e137, 0x1e13d, Lm),
+    (0x1e140, 0x1e149, Nd), (0x1e14e, 0x1e14e, Lo), (0x1e14f, 0x1e14f, So),
+    (0x1e290, 0x1e2ad, Lo), (0x1e2ae, 0x1e2ae, Mn), (0x1e2c0, 0x1e2eb, Lo),
+    (0x1e2ec, 0x1e2ef, Mn), (0x1e2f0, 0x1e2f9, Nd), (0x1e2ff, 0x1e2ff, Sc),
+    (0x1e4d0, 0x1e4ea, Lo), (0x1e4eb, 0x1e4eb, Lm), (0x1e4ec, 0x1e4ef, Mn),
+    (0x1e4f0, 0x1e4f9, Nd), (0x1e5d0, 0x1e5ed, Lo), (0x1e5ee, 0x1e5ef, Mn),
+    (0x1e5f0, 0x1e5f0, Lo), (0x1e5f1, 0x1e5fa, Nd), (0x1e5ff, 0x1e5ff, Po),
+    (0x1e6c0, 0x1e6de, Lo), (0x1e6e0, 0x1e6e2, Lo), (0x1e6e3, 0x1e6e3, Mn),
+    (0x1e6e4, 0x1e6e5, Lo), (0x1e6e6, 0x1e6e6, Mn), (0x1e6e7, 0x1e6ed, Lo),
+    (0x1e6ee, 0x1e6ef, Mn), (0x1e6f0, 0x1e6f4, Lo), (0x1e6f5, 0x1e6f5, Mn),
+    (0x1e6fe, 0x1e6fe, Lo), (0x1e6ff, 0x1e6ff, Lm), (0x1e7e0, 0x1e7e6, Lo),
+    (0x1e7e8, 0x1e7eb, Lo), (0x1e7ed, 0x1e7ee, Lo), (0x1e7f0, 0x1e7fe, Lo),
+    (0x1e800, 0x1e8c4, Lo), (0x1e8c7, 0x1e8cf, No), (0x1e8d0, 0x1e8d6, Mn),
+    (0x1e900, 0x1e921, Lu), (0x1e922, 0x1e943, Ll), (0x1e944, 0x1e94a, Mn),
+    (0x1e94b, 0x1e94b, Lm), (0x1e950, 0x1e959, Nd), (0x1e95e, 0x1e95f, Po),
+    (0x1ec71, 0x1ecab, No), (0x1ecac, 0x1ecac, So), (0x1ecad, 0x1ecaf, No),
+    (0x1ecb0, 0x1ecb0, Sc), (0x1ecb1, 0x1ecb4, No), (0x1ed01, 0x1ed2d, No),
+    (0x1ed2e, 0x1ed2e, So), (0x1ed2f, 0x1ed3d, No), (0x1ee00, 0x1ee03, Lo),
+    (0x1ee05, 0x1ee1f, Lo), (0x1ee21, 0x1ee22, Lo), (0x1ee24, 0x1ee24, Lo),
+    (0x1ee27, 0x1ee27, Lo), (0x1ee29, 0x1ee32, Lo), (0x1ee34, 0x1ee37, Lo),
+    (0x1ee39, 0x1ee39, Lo), (0x1ee3b, 0x1ee3b, Lo), (0x1ee42, 0x1ee42, Lo),
+    (0x1ee47, 0x1ee47, Lo), (0x1ee49, 0x1ee49, Lo), (0x1ee4b, 0x1ee4b, Lo),
+    (0x1ee4d, 0x1ee4f, Lo), (0x1ee51, 0x1ee52, Lo), (0x1ee54, 0x1ee54, Lo),
+    (0x1ee57, 0x1ee57, Lo), (0x1ee59, 0x1ee59, Lo), (0x1ee5b, 0x1ee5b, Lo),
+    (0x1ee5d, 0x1ee5d, Lo), (0x1ee5f, 0x1ee5f, Lo), (0x1ee61, 0x1ee62, Lo),
+    (0x1ee64, 0x1ee64, Lo), (0x1ee67, 0x1ee6a, Lo), (0x1ee6c, 0x1ee72, Lo),
+    (0x1ee74, 0x1ee77, Lo), (0x1ee79, 0x1ee7c, Lo), (0x1ee7e, 0x1ee7e, Lo),
+    (0x1ee80, 0x1ee89, Lo), (0x1ee8b, 0x1ee9b, Lo), (0x1eea1, 0x1eea3, Lo),
+    (0x1eea5, 0x1eea9, Lo), (0x1eeab, 0x1eebb, Lo), (0x1eef0, 0x1eef1, Sm),
+    (0x1f000, 0x1f02b, So), (0x1f030, 0x1f093, So), (0x1f0a0, 0x1f0ae, So),
+    (0x1f0b1, 0x1f0bf, So), (0x1f0c1, 0x1f0cf, So), (0x1f0d1, 0x1f0f5, So),
+    (0x1f100, 0x1f10c, No), (0x1f10d, 0x1f1ad, So), (0x1f1e6, 0x1f202, So),
+    (0x1f210, 0x1f23b, So), (0x1f240, 0x1f248, So), (0x1f250, 0x1f251, So),
+    (0x1f260, 0x1f265, So), (0x1f300, 0x1f3fa, So), (0x1f3fb, 0x1f3ff, Sk),
+    (0x1f400, 0x1f6d8, So), (0x1f6dc, 0x1f6ec, So), (0x1f6f0, 0x1f6fc, So),
+    (0x1f700, 0x1f7d9, So), (0x1f7e0, 0x1f7eb, So), (0x1f7f0, 0x1f7f0, So),
+    (0x1f800, 0x1f80b, So), (0x1f810, 0x1f847, So), (0x1f850, 0x1f859, So),
+    (0x1f860, 0x1f887, So), (0x1f890, 0x1f8ad, So), (0x1f8b0, 0x1f8bb, So),
+    (0x1f8c0, 0x1f8c1, So), (0x1f8d0, 0x1f8d8, Sm), (0x1f900, 0x1fa57, So),
+    (0x1fa60, 0x1fa6d, So), (0x1fa70, 0x1fa7c, So), (0x1fa80, 0x1fa8a, So),
+    (0x1fa8e, 0x1fac6, So), (0x1fac8, 0x1fac8, So), (0x1facd, 0x1fadc, So),
+    (0x1fadf, 0x1faea, So), (0x1faef, 0x1faf8, So), (0x1fb00, 0x1fb92, So),
+    (0x1fb94, 0x1fbef, So), (0x1fbf0, 0x1fbf9, Nd), (0x1fbfa, 0x1fbfa, So),
+    (0x20000, 0x2a6df, Lo), (0x2a700, 0x2b81d, Lo), (0x2b820, 0x2cead, Lo),
+    (0x2ceb0, 0x2ebe0, Lo), (0x2ebf0, 0x2ee5d, Lo), (0x2f800, 0x2fa1d, Lo),
+    (0x30000, 0x3134a, Lo), (0x31350, 0x33479, Lo), (0xe0001, 0xe0001, Cf),
+    (0xe0020, 0xe007f, Cf), (0xe0100, 0xe01ef, Mn), (0xf0000, 0xffffd, Co),
+    (0x100000, 0x10fffd, Co),
+];
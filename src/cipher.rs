@@ -0,0 +1,280 @@
+//! Functionality common to all format-preserving ciphers
+//!
+//! The [`FormatPreservingCipher`] trait is implemented by the context
+//! structures of each of the supported algorithms, allowing code to
+//! choose an algorithm at runtime, e.g.:
+//!
+//! ```rust
+//! use fpe::cipher::FormatPreservingCipher;
+//!
+//! fn context(
+//!     name: &str,
+//!     key: &[u8],
+//! ) -> fpe::result::Result<Box<dyn FormatPreservingCipher + Send + Sync>> {
+//!     Ok(match name {
+//!         "ff1" => Box::new(fpe::ff1::FF1::new(key, None, 0, 0, 10, None)?),
+//!         _ => Box::new(fpe::ff3_1::FF3_1::new(
+//!             key,
+//!             Some(&[0; 7]),
+//!             10,
+//!             None,
+//!         )?),
+//!     })
+//! }
+//!
+//! let c = context("ff3-1", &[0; 16]).unwrap();
+//! let ct = c.encrypt("0123456789", None).unwrap();
+//! assert!(c.decrypt(&ct, None).unwrap() == "0123456789");
+//! ```
+
+use crate::alphabet::Alphabet;
+use crate::error::{Error, ErrorKind};
+use crate::result::Result;
+
+use zeroize::Zeroizing;
+
+/// The format-preserving encryption algorithms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum Algorithm {
+    FF1,
+    FF3_1,
+}
+
+impl Algorithm {
+    /// The name of the algorithm, e.g. "ff3-1"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::FF1 => "ff1",
+            Algorithm::FF3_1 => "ff3-1",
+        }
+    }
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// prevents implementation of FormatPreservingCipher outside of the
+// crate so that methods can be added to it without breaking callers
+pub(crate) mod sealed {
+    pub trait Sealed {
+        // the implementation shared by the contexts, which gives
+        // access to e.g. the input options from within the crate
+        fn ffx(&self) -> &crate::ffx::FFX;
+    }
+}
+
+/// Operations supported by all format-preserving ciphers
+///
+/// The trait is object safe so that contexts for different
+/// algorithms may be used interchangeably via `Box<dyn ...>`.
+/// It is implemented by the contexts of this crate and is sealed,
+/// i.e. it can't be implemented for other types.
+pub trait FormatPreservingCipher: sealed::Sealed {
+    /// Encrypt a string
+    ///
+    /// If the tweak is not None, then the specified tweak will be used
+    /// instead of the default specified by the context.
+    fn encrypt(&self, pt: &str, twk: Option<&[u8]>) -> Result<String>;
+
+    /// Decrypt a string
+    ///
+    /// If the tweak is not None, then the specified tweak will be used
+    /// instead of the default specified by the context. The tweak used
+    /// must match that used during encryption.
+    fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String>;
+
+    /// The algorithm implemented by the context
+    fn algorithm(&self) -> Algorithm;
+
+    /// The number of characters in the alphabet used by the context
+    fn radix(&self) -> usize;
+
+    /// The alphabet used by the context, limited to `radix` letters
+    fn alphabet(&self) -> &dyn Alphabet;
+
+    /// The minimum number of characters that can be encrypted/decrypted
+    fn min_len(&self) -> usize;
+
+    /// The maximum number of characters that can be encrypted/decrypted
+    fn max_len(&self) -> usize;
+
+    /// The minimum length of the tweak, in bytes
+    fn min_tweak_len(&self) -> usize;
+
+    /// The maximum length of the tweak, in bytes, or None if the
+    /// length of the tweak is unbounded
+    fn max_tweak_len(&self) -> Option<usize>;
+
+    /// Check whether a string can be encrypted/decrypted
+    ///
+    /// Unlike encryption and decryption, which stop at the first
+    /// problem, this reports every character of the input that is
+    /// not part of the alphabet.
+    fn validate(&self, txt: &str) -> Validation;
+}
+
+/// A character of the input that is not part of the alphabet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCharacter {
+    /// The character as it appears in the input
+    pub ch: char,
+    /// The index of the character (not the byte) within the input
+    pub position: usize,
+}
+
+/// The result of validating an input string against a context
+///
+/// # Example
+/// ```rust
+/// use fpe::cipher::{FormatPreservingCipher, InvalidCharacter};
+///
+/// let ff1 = fpe::ff1::FF1::new(&[0; 16], None, 0, 0, 10, None).unwrap();
+/// let v = ff1.validate("0x234y");
+///
+/// assert!(!v.is_valid());
+/// assert!(
+///     v.invalid_characters()
+///         == [
+///             InvalidCharacter { ch: 'x', position: 1 },
+///             InvalidCharacter { ch: 'y', position: 5 },
+///         ]
+/// );
+/// assert!(v.length() == 6 && v.min_len() == 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validation {
+    invalid: Vec<InvalidCharacter>,
+    len: usize,
+    min: usize,
+    max: usize,
+}
+
+impl Validation {
+    pub(crate) fn new(
+        invalid: Vec<InvalidCharacter>,
+        len: usize,
+        min: usize,
+        max: usize,
+    ) -> Self {
+        Validation {
+            invalid,
+            len,
+            min,
+            max,
+        }
+    }
+
+    /// Whether the input can be encrypted/decrypted
+    pub fn is_valid(&self) -> bool {
+        self.invalid.is_empty() && self.is_valid_length()
+    }
+
+    /// Every character of the input that is not part of the alphabet,
+    /// in the order in which they appear
+    pub fn invalid_characters(&self) -> &[InvalidCharacter] {
+        &self.invalid
+    }
+
+    /// The length of the input, in letters. Each invalid character
+    /// is counted as a single letter.
+    pub fn length(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the length of the input is within the allowed range
+    pub fn is_valid_length(&self) -> bool {
+        (self.min..=self.max).contains(&self.len)
+    }
+
+    /// The minimum number of letters that can be encrypted/decrypted
+    pub fn min_len(&self) -> usize {
+        self.min
+    }
+
+    /// The maximum number of letters that can be encrypted/decrypted
+    pub fn max_len(&self) -> usize {
+        self.max
+    }
+
+    /// Convert the validation into the error that encryption or
+    /// decryption of the input would return, if any
+    pub fn into_result(self) -> Result<()> {
+        if let Some(c) = self.invalid.first() {
+            return Err(Error::new(ErrorKind::InvalidCharacter {
+                ch: c.ch,
+                position: c.position,
+            }));
+        }
+
+        if !self.is_valid_length() {
+            return Err(Error::new(ErrorKind::InvalidTextLength {
+                min: self.min,
+                max: self.max,
+                actual: self.len,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+/// Decrypt a string with one context and encrypt the result with another
+///
+/// This is intended for key rotation: the plaintext exists only within
+/// this function, and the buffers that hold it are wiped before the
+/// function returns. The contexts may use different algorithms, but
+/// the plaintext must be valid for both.
+///
+/// Note that the temporary values created by the arithmetic on large
+/// integers within the algorithms are out of reach and are not wiped.
+///
+/// # Example
+/// ```rust
+/// use fpe::cipher::reencrypt;
+/// use fpe::ff1::FF1;
+/// use fpe::ff3_1::FF3_1;
+///
+/// let old = FF1::new(&[1; 16], None, 0, 0, 10, None).unwrap();
+/// let new = FF3_1::new(&[2; 16], Some(&[0; 7]), 10, None).unwrap();
+///
+/// let ct = old.encrypt("123456789", None).unwrap();
+/// let ct = reencrypt(&ct, &old, None, &new, None).unwrap();
+/// assert!(new.decrypt(&ct, None).unwrap() == "123456789");
+/// ```
+pub fn reencrypt(
+    ct: &str,
+    from: &dyn FormatPreservingCipher,
+    from_twk: Option<&[u8]>,
+    to: &dyn FormatPreservingCipher,
+    to_twk: Option<&[u8]>,
+) -> Result<String> {
+    let pt = Zeroizing::new(from.decrypt(ct, from_twk)?);
+    to.encrypt(&pt, to_twk)
+}
+
+/// Re-encrypt each of a sequence of strings
+///
+/// Each string is re-encrypted as by [`reencrypt`] as the returned
+/// iterator is advanced, so arbitrarily large sequences may be
+/// processed without holding them in memory. A failure to re-encrypt
+/// one string does not prevent the others from being re-encrypted.
+/// Where the tweak differs from one string to the next, call
+/// [`reencrypt`] for each string instead.
+pub fn reencrypt_batch<'a, I>(
+    cts: I,
+    from: &'a dyn FormatPreservingCipher,
+    from_twk: Option<&'a [u8]>,
+    to: &'a dyn FormatPreservingCipher,
+    to_twk: Option<&'a [u8]>,
+) -> impl Iterator<Item = Result<String>> + 'a
+where
+    I: IntoIterator + 'a,
+    I::Item: AsRef<str>,
+{
+    cts.into_iter()
+        .map(move |ct| reencrypt(ct.as_ref(), from, from_twk, to, to_twk))
+}
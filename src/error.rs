@@ -0,0 +1,312 @@
+//! Errors returned by the FPE library
+//!
+//! Every error carries an [`ErrorKind`] that describes, in a machine
+//! readable way, what went wrong. The kind can be inspected via
+//! [`Error::kind`] so that callers don't need to resort to matching
+//! on the text of the error message.
+
+use std::fmt;
+
+/// The specific reason for an error
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The key length is not one of those supported by AES
+    InvalidKey { length: usize },
+    /// The radix is outside of the range supported by the algorithm
+    InvalidRadix { radix: usize },
+    /// The length of the input text is outside of the allowed range
+    InvalidTextLength {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+    /// The length of the tweak is outside of the allowed range.
+    /// A `max` of `None` indicates that the tweak length is unbounded
+    InvalidTweakLength {
+        min: usize,
+        max: Option<usize>,
+        actual: usize,
+    },
+    /// The minimum tweak length is greater than the maximum
+    InvalidTweakBounds { min: usize, max: usize },
+    /// The input contains a character that is not in the alphabet.
+    /// The position is the index of the character (not the byte)
+    /// within the input
+    InvalidCharacter { ch: char, position: usize },
+    /// The alphabet contains the same letter more than once
+    DuplicateLetter { ch: char },
+    /// The alphabet contains the same multi-character letter more than once
+    DuplicateSymbol { symbol: String },
+    /// The alphabet contains a letter that is an empty string
+    EmptyLetter,
+    /// The alphabet contains a letter (`symbol`) that begins with
+    /// another letter (`prefix`) in such a way that a sequence of
+    /// letters can't be reliably split into its individual letters
+    AmbiguousSymbols { prefix: String, symbol: String },
+    /// The letter can't be distinguished from another letter in the
+    /// alphabet once the input options have been applied to both
+    IndistinguishableLetters { letter: String },
+    /// Input options were given for an alphabet whose lookups take
+    /// constant time, which the options would not preserve
+    ConstantTimeInputOptions,
+    /// The alphabet contains fewer letters than required by the radix
+    AlphabetTooSmall { radix: usize, length: usize },
+    /// There is no letter at the given position in the alphabet
+    InvalidPosition { position: usize },
+    /// The alphabet's `char_at` and `position_of` functions do not
+    /// agree about the letter at the given position
+    InconsistentAlphabet { position: usize },
+    /// There is no alphabet preset with the given name
+    UnknownPreset { name: String },
+    /// A required parameter was not supplied to a builder
+    MissingParameter { name: &'static str },
+    /// The key version can't be represented by a letter of the alphabet
+    InvalidKeyVersion { version: usize },
+    /// The keyring already contains a context for the key version
+    DuplicateKeyVersion { version: usize },
+    /// The keyring does not contain a context for the key version
+    UnknownKeyVersion { version: usize },
+    /// The context does not use the same alphabet as the
+    /// other contexts in the keyring
+    IncompatibleAlphabet,
+    /// The context does not use the same input options as the
+    /// other contexts in the keyring
+    IncompatibleInputOptions,
+    /// The key provider has no key with the given id and version.
+    /// A version of `None` refers to the latest version of the key
+    UnknownKey { id: String, version: Option<u32> },
+    /// The key provider already has a key with the given id and version
+    DuplicateProviderKey { id: String, version: u32 },
+    /// The key provider failed to retrieve or store keys
+    KeyProvider { reason: String },
+    /// The requested amount of key material exceeds the
+    /// maximum that the key derivation function can produce
+    InvalidOutputLength { length: usize, max: usize },
+    /// The encoded key could not be decoded
+    InvalidKeyEncoding { reason: String },
+    /// The operating system's random number generator failed
+    RandomGeneration { reason: String },
+    /// The wrapped key is malformed or failed its integrity check,
+    /// e.g. because the key-encryption key is incorrect
+    InvalidWrappedKey,
+    /// The threshold for splitting a key must be at least 2 and no
+    /// more than the number of shares, which may be at most 255
+    InvalidShareThreshold { threshold: usize, shares: usize },
+    /// The share is malformed or inconsistent with the other shares
+    InvalidShare { reason: String },
+    /// Fewer shares than the threshold were supplied
+    InsufficientShares { threshold: usize, actual: usize },
+    /// The key recovered from the shares does not match
+    /// the key check value recorded in the shares
+    KeyCheckValueMismatch,
+    /// A known-answer self-test produced the wrong result
+    SelfTestFailed { test: String },
+    /// The parameters of the context are not permitted in FIPS mode
+    NotApproved { reason: String },
+    /// The test vectors are malformed or incomplete
+    InvalidTestVectors { reason: String },
+    /// The keystore already contains a key with the given name
+    DuplicateKey { name: String },
+    /// The keystore could not be read, written, or decrypted
+    Keystore { reason: String },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidKey { length } => {
+                write!(f, "invalid key length: {} bytes", length)
+            }
+            ErrorKind::InvalidRadix { radix } => {
+                write!(f, "invalid radix: {}", radix)
+            }
+            ErrorKind::InvalidTextLength { min, max, actual } => write!(
+                f,
+                "invalid text length: {} (must be between {} and {})",
+                actual, min, max
+            ),
+            ErrorKind::InvalidTweakLength { min, max, actual } => match max {
+                Some(max) => write!(
+                    f,
+                    "invalid tweak length: {} (must be between {} and {})",
+                    actual, min, max
+                ),
+                None => write!(
+                    f,
+                    "invalid tweak length: {} (must be at least {})",
+                    actual, min
+                ),
+            },
+            ErrorKind::InvalidTweakBounds { min, max } => write!(
+                f,
+                "minimum tweak length ({}) exceeds maximum ({})",
+                min, max
+            ),
+            ErrorKind::InvalidCharacter { ch, position } => write!(
+                f,
+                "'{}' at position {} not found in alphabet",
+                ch, position
+            ),
+            ErrorKind::DuplicateLetter { ch } => {
+                write!(f, "duplicate letter '{}' in alphabet", ch)
+            }
+            ErrorKind::DuplicateSymbol { symbol } => {
+                write!(f, "duplicate letter \"{}\" in alphabet", symbol)
+            }
+            ErrorKind::EmptyLetter => write!(f, "empty letter in alphabet"),
+            ErrorKind::AmbiguousSymbols { prefix, symbol } => write!(
+                f,
+                "letter \"{}\" is an ambiguous prefix of \"{}\" in alphabet",
+                prefix, symbol
+            ),
+            ErrorKind::IndistinguishableLetters { letter } => write!(
+                f,
+                "letter \"{}\" is indistinguishable from another letter",
+                letter
+            ),
+            ErrorKind::ConstantTimeInputOptions => write!(
+                f,
+                "input options can't be used with a constant-time alphabet"
+            ),
+            ErrorKind::AlphabetTooSmall { radix, length } => write!(
+                f,
+                "not enough letters in alphabet: {} required, {} available",
+                radix, length
+            ),
+            ErrorKind::InvalidPosition { position } => {
+                write!(f, "no letter at position {}", position)
+            }
+            ErrorKind::InconsistentAlphabet { position } => {
+                write!(f, "alphabet is inconsistent at position {}", position)
+            }
+            ErrorKind::UnknownPreset { name } => {
+                write!(f, "unknown alphabet preset: {}", name)
+            }
+            ErrorKind::MissingParameter { name } => {
+                write!(f, "missing required parameter: {}", name)
+            }
+            ErrorKind::InvalidKeyVersion { version } => {
+                write!(f, "invalid key version: {}", version)
+            }
+            ErrorKind::DuplicateKeyVersion { version } => {
+                write!(f, "duplicate key version: {}", version)
+            }
+            ErrorKind::UnknownKeyVersion { version } => {
+                write!(f, "unknown key version: {}", version)
+            }
+            ErrorKind::IncompatibleAlphabet => {
+                write!(f, "alphabet differs from that of the keyring")
+            }
+            ErrorKind::IncompatibleInputOptions => {
+                write!(f, "input options differ from those of the keyring")
+            }
+            ErrorKind::UnknownKey { id, version } => match version {
+                Some(v) => write!(f, "unknown key: {} version {}", id, v),
+                None => write!(f, "unknown key: {}", id),
+            },
+            ErrorKind::DuplicateProviderKey { id, version } => {
+                write!(f, "duplicate key: {} version {}", id, version)
+            }
+            ErrorKind::KeyProvider { reason } => {
+                write!(f, "key provider error: {}", reason)
+            }
+            ErrorKind::InvalidOutputLength { length, max } => write!(
+                f,
+                "invalid output length: {} bytes (must be at most {})",
+                length, max
+            ),
+            ErrorKind::InvalidKeyEncoding { reason } => {
+                write!(f, "invalid key encoding: {}", reason)
+            }
+            ErrorKind::RandomGeneration { reason } => {
+                write!(f, "random number generation failed: {}", reason)
+            }
+            ErrorKind::InvalidWrappedKey => {
+                write!(f, "invalid wrapped key")
+            }
+            ErrorKind::InvalidShareThreshold { threshold, shares } => write!(
+                f,
+                "invalid threshold: {} of {} shares",
+                threshold, shares
+            ),
+            ErrorKind::InvalidShare { reason } => {
+                write!(f, "invalid share: {}", reason)
+            }
+            ErrorKind::InsufficientShares { threshold, actual } => write!(
+                f,
+                "insufficient shares: {} of {} required",
+                actual, threshold
+            ),
+            ErrorKind::KeyCheckValueMismatch => {
+                write!(f, "recovered key does not match key check value")
+            }
+            ErrorKind::SelfTestFailed { test } => {
+                write!(f, "self-test failed: {}", test)
+            }
+            ErrorKind::NotApproved { reason } => {
+                write!(f, "not approved in fips mode: {}", reason)
+            }
+            ErrorKind::InvalidTestVectors { reason } => {
+                write!(f, "invalid test vectors: {}", reason)
+            }
+            ErrorKind::DuplicateKey { name } => {
+                write!(f, "duplicate key: {}", name)
+            }
+            ErrorKind::Keystore { reason } => {
+                write!(f, "keystore error: {}", reason)
+            }
+        }
+    }
+}
+
+/// Structure used by the library to convey errors
+#[derive(Debug, Clone)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind) -> Self {
+        Error { kind }
+    }
+
+    /// Returns the specific reason for the error
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error::new(kind)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn display() {
+        let e = Error::new(ErrorKind::InvalidCharacter {
+            ch: '!',
+            position: 3,
+        });
+        assert!(e.to_string() == "'!' at position 3 not found in alphabet");
+    }
+
+    #[test]
+    fn kind() {
+        let e: Error = ErrorKind::InvalidRadix { radix: 1 }.into();
+        assert!(*e.kind() == ErrorKind::InvalidRadix { radix: 1 });
+    }
+}
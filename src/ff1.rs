@@ -9,15 +9,32 @@
 //! parameters related to the algorithm. Once, this structure has
 //! been created, it can be used to encrypt and decrypt data
 
+use crate::alphabet::input::InputOptions;
+use crate::alphabet::{Alphabet, IntoAlphabet};
+use crate::cipher::{Algorithm, FormatPreservingCipher, Validation};
+use crate::error::{Error, ErrorKind};
 use crate::ffx;
+use crate::fips;
+use crate::kdf::{self, KeySize};
+use crate::key::{Fingerprint, KeyCheckValue, SecretKey};
+use crate::keywrap;
+use crate::memory::LockStatus;
+use crate::provider::{self, KeyProvider};
 use crate::result::Result;
+#[cfg(feature = "trace")]
+use crate::trace::Tracer;
+
+use std::sync::Arc;
 
 use byteorder::ByteOrder;
 use num_traits::Euclid;
+use zeroize::Zeroizing;
 
 /// The FF1 context structure
 pub struct FF1 {
     ffx: ffx::FFX,
+    kcv: KeyCheckValue,
+    fpr: Fingerprint,
 }
 
 impl FF1 {
@@ -32,45 +49,117 @@ impl FF1 {
     ///
     /// The radix must be less than or equal to the number of characters
     /// in the supplied alphabet (or the default alphabet) if no alphabet
-    /// is supplied to this function
+    /// is supplied to this function. The alphabet may be given as an
+    /// optional string or as any implementation of [`Alphabet`]
+    ///
+    /// This function is shorthand for the equivalent calls
+    /// to the [`Builder`] returned by [`FF1::builder`]
     pub fn new(
         key: &[u8],
         opt_t: Option<&[u8]>,
         mintwk: usize,
         maxtwk: usize,
         radix: usize,
-        opt_alpha: Option<&str>,
+        alpha: impl IntoAlphabet,
     ) -> Result<Self> {
-        Ok(FF1 {
-            ffx: ffx::FFX::new(
-                key,
-                opt_t,
-                // the maximum input length allowed by the
-                // algorithm specification is 2**32 - 1
-                (1 << 32) - 1,
-                mintwk,
-                maxtwk,
-                radix,
-                opt_alpha,
-            )?,
-        })
+        let mut b = FF1::builder()
+            .key(key)
+            .tweak_bounds(mintwk, maxtwk)
+            .radix(radix);
+        if let Some(t) = opt_t {
+            b = b.tweak(t);
+        }
+        b.alphabet(alpha).build()
+    }
+
+    /// Create a new FF1 context with a key derived from a master key
+    ///
+    /// The key is derived for the given label via [`kdf::derive_key`],
+    /// so contexts created from the same master key with different
+    /// labels use independent keys. The radix and alphabet are as for
+    /// [`FF1::new`]. Other parameters may be set via [`Builder::master_key`].
+    pub fn from_master_key(
+        master: &[u8],
+        label: &str,
+        size: KeySize,
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF1::builder()
+            .master_key(master, label, size)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
     }
 
-    // the code wants to work with individual characters or letters.
-    // this isn't possible with utf8, so the caller is expected to
-    // convert Strings to sequences of chars
-    fn cipher_chars(
+    /// Create a new FF1 context with a key from a key provider
+    ///
+    /// A version of `None` selects the latest version of the key. The
+    /// radix and alphabet are as for [`FF1::new`]. Other parameters may
+    /// be set via [`Builder::provider_key`].
+    pub fn from_provider(
+        provider: &dyn KeyProvider,
+        id: &str,
+        version: Option<u32>,
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF1::builder()
+            .provider_key(provider, id, version)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
+    }
+
+    /// Create a new FF1 context with a wrapped key
+    ///
+    /// The key is unwrapped with the key-encryption key as specified
+    /// by either RFC 3394 or RFC 5649; see [`keywrap`]. The radix and
+    /// alphabet are as for [`FF1::new`]. Other parameters may be set
+    /// via [`Builder::wrapped_key`].
+    pub fn from_wrapped_key(
+        wrapped: &[u8],
+        kek: &[u8],
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF1::builder()
+            .wrapped_key(wrapped, kek)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
+    }
+
+    /// Create a builder with which to configure an FF1 context
+    pub fn builder<'a>() -> Builder<'a> {
+        Builder::default()
+    }
+
+    // the code wants to work with individual letters, but a letter
+    // may consist of more than one char (or byte, in utf8). the
+    // caller is therefore expected to convert strings to sequences
+    // of digits, i.e. the positions of the letters in the alphabet
+    fn cipher_digits(
         &self,
-        inp: &[char],
+        inp: &[u16],
         opt_t: Option<&[u8]>,
         which: ffx::CipherType,
-    ) -> Result<Vec<char>> {
+    ) -> Result<Vec<u16>> {
         let ffx = &self.ffx;
         let radix = ffx.get_radix();
         let blksz = ffx.get_cipher_block_size();
 
         let t = ffx.get_tweak(&opt_t);
         ffx.validate_tweak_length(t.len())?;
+        // the length of the tweak is encoded in 32 bits (step 5), which
+        // the limits of the context don't guarantee when unbounded
+        if t.len() > u32::MAX as usize {
+            return Err(Error::new(ErrorKind::InvalidTweakLength {
+                min: ffx.get_min_tweak_length(),
+                max: Some(u32::MAX as usize),
+                actual: t.len(),
+            }));
+        }
 
         let n = inp.len();
         ffx.validate_text_length(n)?;
@@ -85,17 +174,17 @@ impl FF1 {
         // it turns out, those strings can be represented as numbers
         // for the duration of the algorithm and only converted back
         // to strings at the end. (step 2)
-        let mut na = ffx.chars_to_bignum(&inp[..u])?;
-        let mut nb = ffx.chars_to_bignum(&inp[u..])?;
+        let mut na = ffx.digits_to_bignum(&inp[..u])?;
+        let mut nb = ffx.digits_to_bignum(&inp[u..])?;
 
         // the input string gets broken in half, and `b` is the
         // number of bytes required to represent the latter half
         // as a number converted from the specified radix. (step 3)
         let b =
-            ((((radix as f64).log2() * (v as f64)).ceil() as usize) + 7) / 8;
+            (((radix as f64).log2() * (v as f64)).ceil() as usize).div_ceil(8);
         // d is the number of bytes extracted from the aes output
         // to be used as the number `y` in the algorithm (step 4)
-        let d = 4 * ((b + 3) / 4) + 4;
+        let d = 4 * b.div_ceil(4) + 4;
 
         // p serves as the input to one of the aes operations, the
         // output of which eventually becomes `y`. The algorithm
@@ -104,12 +193,14 @@ impl FF1 {
         // this `q` is also contained as part of `p` as the two are
         // supposed to be concatenated before being input to the aes
         // operation. `p` is the first 16 bytes, and `q` is the rest.
-        let mut p = Vec::<u8>::new();
-        p.resize(16 + ((t.len() + 1 + b + (blksz - 1)) / blksz) * blksz, 0);
+        let mut p = Zeroizing::new(vec![
+            0u8;
+            16 + (t.len() + 1 + b).div_ceil(blksz)
+                * blksz
+        ]);
 
         // `r` is the output from the aes operations
-        let mut r = Vec::<u8>::new();
-        r.resize(((d + (blksz - 1)) / blksz) * blksz, 0);
+        let mut r = Zeroizing::new(vec![0u8; d.div_ceil(blksz) * blksz]);
 
         // p is initialized once and remains unchanged after the values
         // to be put in p are specified by the algorithm (step 5)
@@ -174,7 +265,7 @@ impl FF1 {
                 // the num_bigint library doesn't provide left padding,
                 // but it does support little endian output which allows
                 // us to do right-padding and then reverse the bytes
-                let (_, mut v) = nb.to_bytes_le();
+                let mut v = Zeroizing::new(nb.to_bytes_le().1);
                 v.resize(b, 0);
                 v.reverse();
                 q[q_len - b..].copy_from_slice(&v);
@@ -215,17 +306,48 @@ impl FF1 {
             }
 
             // (step 6iv)
-            let y = num_bigint::BigInt::from_bytes_be(
+            let mut y = num_bigint::BigInt::from_bytes_be(
                 num_bigint::Sign::Plus,
                 &r[..d],
             );
 
             // (step 6vi, partial)
             match which {
-                ffx::CipherType::Encrypt => na += y,
-                ffx::CipherType::Decrypt => na -= y,
+                ffx::CipherType::Encrypt => na += &y,
+                ffx::CipherType::Decrypt => na -= &y,
             }
             na = na.rem_euclid(&mu);
+
+            #[cfg(feature = "trace")]
+            ffx.trace(|| {
+                let i = match which {
+                    ffx::CipherType::Encrypt => i as usize,
+                    ffx::CipherType::Decrypt => 9 - i as usize,
+                };
+                // (step 6v)
+                let m = if i % 2 == 0 { u } else { v };
+                let c = ffx.bignum_to_digits(&na, Some(m));
+                let o = ffx.bignum_to_digits(&nb, Some(n - m));
+                let (a, b) = match which {
+                    ffx::CipherType::Encrypt => (o, c),
+                    ffx::CipherType::Decrypt => (c, o),
+                };
+
+                crate::trace::Round {
+                    algorithm: Algorithm::FF1,
+                    i,
+                    p: p[..16].to_vec(),
+                    q: Some(p[16..].to_vec()),
+                    r: Some(r[..blksz].to_vec()),
+                    s: r[..d].to_vec(),
+                    y: y.to_string(),
+                    c: na.to_string(),
+                    a,
+                    b,
+                }
+            });
+
+            ffx::FFX::clear_bignum(&mut y);
             // (step 6v, partial)
             std::mem::swap(&mut mu, &mut mv);
 
@@ -239,26 +361,28 @@ impl FF1 {
         }
 
         // (step 7)
-        Ok([
-            ffx.bignum_to_chars(&na, Some(u))?,
-            ffx.bignum_to_chars(&nb, Some(v))?,
-        ]
-        .concat())
+        let a = Zeroizing::new(ffx.bignum_to_digits(&na, Some(u)));
+        let b = Zeroizing::new(ffx.bignum_to_digits(&nb, Some(v)));
+        ffx::FFX::clear_bignum(&mut na);
+        ffx::FFX::clear_bignum(&mut nb);
+
+        Ok([a.as_slice(), b.as_slice()].concat())
     }
 
     // common function to convert the input String to a sequence
-    // of chars before the cipher operation and back again after
+    // of digits before the cipher operation and back again after
     fn cipher_string(
         &self,
         inp_s: &str,
         opt_t: Option<&[u8]>,
         which: ffx::CipherType,
     ) -> Result<String> {
-        let mut inp_c = Vec::<char>::with_capacity(inp_s.chars().count());
-        inp_s.chars().for_each(|c| inp_c.push(c));
-
-        let out_c = self.cipher_chars(&inp_c, opt_t, which)?;
-        Ok(String::from_iter(out_c))
+        // one of the two sequences of digits is the plaintext,
+        // so both (and the case of its letters) are wiped when
+        // they're no longer needed
+        let (inp_d, upper) = self.ffx.text_to_digits(inp_s)?;
+        let out_d = Zeroizing::new(self.cipher_digits(&inp_d, opt_t, which)?);
+        self.ffx.digits_to_text(&out_d, &upper)
     }
 
     /// Encrypt a string
@@ -277,6 +401,279 @@ impl FF1 {
     pub fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
         self.cipher_string(ct, twk, ffx::CipherType::Decrypt)
     }
+
+    /// Check whether a string can be encrypted/decrypted
+    ///
+    /// Every character of the input that is not part of the alphabet
+    /// is reported, along with the length of the input and the range
+    /// of lengths allowed by the context.
+    pub fn validate(&self, txt: &str) -> Validation {
+        self.ffx.validate(txt)
+    }
+
+    /// The key check value of the key
+    ///
+    /// See [`KeyCheckValue`].
+    pub fn key_check_value(&self) -> KeyCheckValue {
+        self.kcv
+    }
+
+    /// The fingerprint of the key
+    ///
+    /// See [`Fingerprint`].
+    pub fn key_fingerprint(&self) -> Fingerprint {
+        self.fpr
+    }
+
+    /// Whether the expanded key is held in locked memory
+    ///
+    /// See the [`memory`](crate::memory) module. This is always
+    /// [`LockStatus::Disabled`] unless the `mlock` feature is enabled.
+    pub fn key_lock_status(&self) -> LockStatus {
+        self.ffx.get_key_lock_status()
+    }
+}
+
+/// Builder for FF1 contexts
+///
+/// The only required parameter is the key. If the radix is not
+/// specified, it is taken to be the number of characters in the
+/// alphabet, in which case an alphabet must be supplied. All
+/// parameters are validated when the context is built.
+///
+/// # Example
+/// ```rust
+/// let ff1 = fpe::ff1::FF1::builder()
+///     .key(&[
+///         0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+///         0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+///     ])
+///     .alphabet("0123456789")
+///     .build()
+///     .unwrap();
+///
+/// assert!(ff1.encrypt("0123456789", None).unwrap() == "2433477484");
+/// ```
+#[derive(Clone, Default)]
+pub struct Builder<'a> {
+    key: Option<Result<SecretKey>>,
+    twk: Option<&'a [u8]>,
+    mintwk: usize,
+    maxtwk: usize,
+    radix: Option<usize>,
+    alpha: Option<Result<Arc<dyn Alphabet>>>,
+    input: InputOptions,
+    #[cfg(feature = "trace")]
+    tracer: Option<Arc<dyn Tracer>>,
+}
+
+impl<'a> Builder<'a> {
+    /// Set the encryption key
+    ///
+    /// The key may be any of the lengths supported by AES. It may be
+    /// given as a slice of bytes or as a [`SecretKey`]. In either case,
+    /// the builder's copy of the key is wiped when the builder is dropped.
+    pub fn key(mut self, key: impl Into<SecretKey>) -> Self {
+        self.key = Some(Ok(key.into()));
+        self
+    }
+
+    /// Set the encryption key to one derived from a master key
+    ///
+    /// The key is derived for the given label and FF1 via
+    /// [`kdf::derive_key`], replacing any key set previously.
+    pub fn master_key(
+        mut self,
+        master: &[u8],
+        label: &str,
+        size: KeySize,
+    ) -> Self {
+        self.key = Some(kdf::derive_key(master, label, Algorithm::FF1, size));
+        self
+    }
+
+    /// Set the encryption key to one retrieved from a key provider
+    ///
+    /// A version of `None` selects the latest version of the key.
+    /// The key replaces any key set previously. Building the context
+    /// fails if the key is restricted to another algorithm.
+    pub fn provider_key(
+        mut self,
+        provider: &dyn KeyProvider,
+        id: &str,
+        version: Option<u32>,
+    ) -> Self {
+        self.key =
+            Some(provider::fetch_for(provider, id, version, Algorithm::FF1));
+        self
+    }
+
+    /// Set the encryption key to one unwrapped from a wrapped key
+    ///
+    /// The key may be wrapped as specified by either RFC 3394 or
+    /// RFC 5649. The key replaces any key set previously.
+    pub fn wrapped_key(mut self, wrapped: &[u8], kek: &[u8]) -> Self {
+        self.key = Some(keywrap::unwrap_key(kek, wrapped));
+        self
+    }
+
+    /// Set the default tweak
+    ///
+    /// If not set, an empty tweak is used by default.
+    pub fn tweak(mut self, twk: &'a [u8]) -> Self {
+        self.twk = Some(twk);
+        self
+    }
+
+    /// Set the minimum and maximum tweak lengths
+    ///
+    /// Both may be set to 0 (the default) to leave
+    /// the tweak length unbounded.
+    pub fn tweak_bounds(mut self, min: usize, max: usize) -> Self {
+        self.mintwk = min;
+        self.maxtwk = max;
+        self
+    }
+
+    /// Set the radix
+    pub fn radix(mut self, radix: usize) -> Self {
+        self.radix = Some(radix);
+        self
+    }
+
+    /// Set the alphabet
+    ///
+    /// The alphabet may be a string of letters or any implementation
+    /// of the [`Alphabet`] trait. Only the first `radix` letters of
+    /// the alphabet are used.
+    pub fn alphabet(mut self, alpha: impl IntoAlphabet) -> Self {
+        self.alpha = Some(alpha.into_alphabet());
+        self
+    }
+
+    /// Set the options used to match the input against the alphabet
+    ///
+    /// By default, the input must match the letters of the alphabet
+    /// exactly. See [`InputOptions`] for the alternatives.
+    pub fn input_options(mut self, opts: InputOptions) -> Self {
+        self.input = opts;
+        self
+    }
+
+    /// Set the tracer to receive the values computed in each round
+    ///
+    /// Only available with the `trace` feature. See the
+    /// [`trace`](crate::trace) module.
+    #[cfg(feature = "trace")]
+    pub fn tracer(mut self, tracer: Arc<dyn Tracer>) -> Self {
+        self.tracer = Some(tracer);
+        self
+    }
+
+    /// Validate the parameters and create the FF1 context
+    ///
+    /// When [FIPS mode](crate::fips) is enabled, the context must also
+    /// satisfy the requirements of that mode.
+    pub fn build(&self) -> Result<FF1> {
+        let ctx = self.build_unapproved()?;
+        fips::approve(&ctx, ctx.ffx.get_key_length())?;
+        Ok(ctx)
+    }
+
+    // create the context without the checks of fips mode, which
+    // the self-tests (required by fips mode) need to do
+    pub(crate) fn build_unapproved(&self) -> Result<FF1> {
+        let key = match &self.key {
+            Some(k) => k.clone()?,
+            None => {
+                return Err(Error::new(ErrorKind::MissingParameter {
+                    name: "key",
+                }))
+            }
+        };
+        let alpha = match &self.alpha {
+            Some(a) => a.clone()?,
+            None => None::<&str>.into_alphabet()?,
+        };
+        let radix = match (self.radix, &self.alpha) {
+            (Some(r), _) => r,
+            (None, Some(_)) => alpha.len(),
+            (None, None) => {
+                return Err(Error::new(ErrorKind::MissingParameter {
+                    name: "radix",
+                }))
+            }
+        };
+
+        let mut ffx = ffx::FFX::new(
+            key.expose_secret(),
+            self.twk,
+            // the maximum input length allowed by the
+            // algorithm specification is 2**32 - 1
+            (1 << 32) - 1,
+            self.mintwk,
+            self.maxtwk,
+            radix,
+            alpha,
+        )?;
+        ffx.set_input_options(self.input)?;
+        #[cfg(feature = "trace")]
+        ffx.set_tracer(self.tracer.clone());
+
+        Ok(FF1 {
+            ffx,
+            kcv: key.check_value()?,
+            fpr: key.fingerprint(),
+        })
+    }
+}
+
+impl crate::cipher::sealed::Sealed for FF1 {
+    fn ffx(&self) -> &ffx::FFX {
+        &self.ffx
+    }
+}
+
+impl FormatPreservingCipher for FF1 {
+    fn encrypt(&self, pt: &str, twk: Option<&[u8]>) -> Result<String> {
+        FF1::encrypt(self, pt, twk)
+    }
+
+    fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
+        FF1::decrypt(self, ct, twk)
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::FF1
+    }
+
+    fn radix(&self) -> usize {
+        self.ffx.get_radix()
+    }
+
+    fn alphabet(&self) -> &dyn Alphabet {
+        self.ffx.get_alphabet()
+    }
+
+    fn min_len(&self) -> usize {
+        self.ffx.get_min_text_length()
+    }
+
+    fn max_len(&self) -> usize {
+        self.ffx.get_max_text_length()
+    }
+
+    fn min_tweak_len(&self) -> usize {
+        self.ffx.get_min_tweak_length()
+    }
+
+    fn max_tweak_len(&self) -> Option<usize> {
+        self.ffx.get_max_tweak_length()
+    }
+
+    fn validate(&self, txt: &str) -> Validation {
+        FF1::validate(self, txt)
+    }
 }
 
 fn cipher(
@@ -288,7 +685,7 @@ fn cipher(
     op: fn(&FF1, &str, Option<&[u8]>) -> Result<String>,
 ) -> Result<String> {
     let ff1 = FF1::new(key, None, 0, 0, radix, alpha)?;
-    return op(&ff1, txt, twk);
+    op(&ff1, txt, twk)
 }
 
 pub fn encrypt(
@@ -298,7 +695,7 @@ pub fn encrypt(
     radix: usize,
     alpha: Option<&str>,
 ) -> Result<String> {
-    return cipher(key, twk, pt, radix, alpha, FF1::encrypt);
+    cipher(key, twk, pt, radix, alpha, FF1::encrypt)
 }
 
 pub fn decrypt(
@@ -308,7 +705,7 @@ pub fn decrypt(
     radix: usize,
     alpha: Option<&str>,
 ) -> Result<String> {
-    return cipher(key, twk, ct, radix, alpha, FF1::decrypt);
+    cipher(key, twk, ct, radix, alpha, FF1::decrypt)
 }
 
 #[cfg(test)]
@@ -30,14 +30,31 @@
 //! let out = ff3_1.decrypt(&ct, None).unwrap();
 //! assert!(out == pt);
 
+use crate::alphabet::input::InputOptions;
+use crate::alphabet::{Alphabet, IntoAlphabet};
+use crate::cipher::{Algorithm, FormatPreservingCipher, Validation};
+use crate::error::{Error, ErrorKind};
 use crate::ffx;
+use crate::fips;
+use crate::kdf::{self, KeySize};
+use crate::key::{Fingerprint, KeyCheckValue, SecretKey};
+use crate::keywrap;
+use crate::memory::LockStatus;
+use crate::provider::{self, KeyProvider};
 use crate::result::Result;
+#[cfg(feature = "trace")]
+use crate::trace::Tracer;
+
+use std::sync::Arc;
 
 use num_traits::Euclid;
+use zeroize::{Zeroize, Zeroizing};
 
 /// The FF3_1 context structure
 pub struct FF3_1 {
     ffx: ffx::FFX,
+    kcv: KeyCheckValue,
+    fpr: Fingerprint,
 }
 
 impl FF3_1 {
@@ -53,45 +70,97 @@ impl FF3_1 {
     ///
     /// The radix must be less than or equal to the number of characters
     /// in the supplied alphabet (or the default alphabet) if no alphabet
-    /// is supplied to this function
+    /// is supplied to this function. The alphabet may be given as an
+    /// optional string or as any implementation of [`Alphabet`]
+    ///
+    /// This function is shorthand for the equivalent calls
+    /// to the [`Builder`] returned by [`FF3_1::builder`]
     pub fn new(
         key: &[u8],
         opt_twk: Option<&[u8]>,
         radix: usize,
-        opt_alpha: Option<&str>,
+        alpha: impl IntoAlphabet,
     ) -> Result<Self> {
-        // key is reversed for ff3-1
-        let mut k = key.to_vec();
-        k.reverse();
+        let mut b = FF3_1::builder().key(key).radix(radix);
+        if let Some(t) = opt_twk {
+            b = b.tweak(t);
+        }
+        b.alphabet(alpha).build()
+    }
 
-        Ok(FF3_1 {
-            ffx: ffx::FFX::new(
-                &k,
-                opt_twk,
-                // maxlen for ff3-1:
-                //   = 2 * log_radix(2**96)
-                //   = 2 * log2(2**96) / log2(radix)
-                //   = 2 * 96 / log2(radix)
-                //   = 192 / log2(radix)
-                (192f64 / (radix as f64).log2()).floor() as usize,
-                // tweak size is fixed for ff3-1
-                7,
-                7,
-                radix,
-                opt_alpha,
-            )?,
-        })
+    /// Create a new FF3-1 context with a key derived from a master key
+    ///
+    /// The key is derived for the given label via [`kdf::derive_key`],
+    /// so contexts created from the same master key with different
+    /// labels use independent keys. The radix and alphabet are as for
+    /// [`FF3_1::new`]. Other parameters may be set via [`Builder::master_key`].
+    pub fn from_master_key(
+        master: &[u8],
+        label: &str,
+        size: KeySize,
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF3_1::builder()
+            .master_key(master, label, size)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
+    }
+
+    /// Create a new FF3-1 context with a key from a key provider
+    ///
+    /// A version of `None` selects the latest version of the key. The
+    /// radix and alphabet are as for [`FF3_1::new`]. Other parameters may
+    /// be set via [`Builder::provider_key`].
+    pub fn from_provider(
+        provider: &dyn KeyProvider,
+        id: &str,
+        version: Option<u32>,
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF3_1::builder()
+            .provider_key(provider, id, version)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
+    }
+
+    /// Create a new FF3-1 context with a wrapped key
+    ///
+    /// The key is unwrapped with the key-encryption key as specified
+    /// by either RFC 3394 or RFC 5649; see [`keywrap`]. The radix and
+    /// alphabet are as for [`FF3_1::new`]. Other parameters may be set
+    /// via [`Builder::wrapped_key`].
+    pub fn from_wrapped_key(
+        wrapped: &[u8],
+        kek: &[u8],
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<Self> {
+        FF3_1::builder()
+            .wrapped_key(wrapped, kek)
+            .radix(radix)
+            .alphabet(alpha)
+            .build()
     }
 
-    // the code wants to work with individual characters or letters.
-    // this isn't possible with utf8, so the caller is expected to
-    // convert Strings to sequences of chars
-    fn cipher_chars(
+    /// Create a builder with which to configure an FF3-1 context
+    pub fn builder<'a>() -> Builder<'a> {
+        Builder::default()
+    }
+
+    // the code wants to work with individual letters, but a letter
+    // may consist of more than one char (or byte, in utf8). the
+    // caller is therefore expected to convert strings to sequences
+    // of digits, i.e. the positions of the letters in the alphabet
+    fn cipher_digits(
         &self,
-        inp: &[char],
+        inp: &[u16],
         opt_twk: Option<&[u8]>,
         which: ffx::CipherType,
-    ) -> Result<Vec<char>> {
+    ) -> Result<Vec<u16>> {
         let ffx = &self.ffx;
         let radix = ffx.get_radix();
 
@@ -103,8 +172,8 @@ impl FF3_1 {
         let u = n - v;
 
         // (step 2)
-        let mut a = inp[..u].to_vec();
-        let mut b = inp[u..].to_vec();
+        let mut a = Zeroizing::new(inp[..u].to_vec());
+        let mut b = Zeroizing::new(inp[u..].to_vec());
 
         let t = ffx.get_tweak(&opt_twk);
         ffx.validate_tweak_length(t.len())?;
@@ -140,8 +209,8 @@ impl FF3_1 {
         // without the need for reversal, the strings can be converted
         // to their numerical representations for the duration of the
         // algorithm
-        let mut na = ffx.chars_to_bignum(&a)?;
-        let mut nb = ffx.chars_to_bignum(&b)?;
+        let mut na = ffx.digits_to_bignum(&a)?;
+        let mut nb = ffx.digits_to_bignum(&b)?;
 
         // during decryption, the algorithm runs in "reverse".
         // swap these values so that during decryption we start
@@ -158,7 +227,7 @@ impl FF3_1 {
             let mut p: [[u8; 16]; 2] = [[0; 16]; 2];
 
             // (step 4i, 4ii)
-            p[0][..4].copy_from_slice(&tw[((i + 1) as u8 % 2) as usize]);
+            p[0][..4].copy_from_slice(&tw[((i + 1) % 2) as usize]);
             match which {
                 ffx::CipherType::Encrypt => p[0][3] ^= i,
                 ffx::CipherType::Decrypt => p[0][3] ^= 7 - i,
@@ -167,7 +236,7 @@ impl FF3_1 {
             // the num_bigint library doesn't provide left padding,
             // but it does support little endian output which allows
             // us to do right-padding and then reverse the bytes
-            let (_, mut v) = nb.to_bytes_le();
+            let mut v = Zeroizing::new(nb.to_bytes_le().1);
             v.resize(12, 0);
             v.reverse();
             p[0][4..16].copy_from_slice(&v);
@@ -184,17 +253,55 @@ impl FF3_1 {
             p[1].reverse();
 
             // (step 4iv)
-            let y = num_bigint::BigInt::from_bytes_be(
+            let mut y = num_bigint::BigInt::from_bytes_be(
                 num_bigint::Sign::Plus,
                 &p[1],
             );
 
             // (step 4v)
             match which {
-                ffx::CipherType::Encrypt => na += y,
-                ffx::CipherType::Decrypt => na -= y,
+                ffx::CipherType::Encrypt => na += &y,
+                ffx::CipherType::Decrypt => na -= &y,
             }
             na = na.rem_euclid(&mu);
+
+            #[cfg(feature = "trace")]
+            ffx.trace(|| {
+                let i = match which {
+                    ffx::CipherType::Encrypt => i as usize,
+                    ffx::CipherType::Decrypt => 7 - i as usize,
+                };
+                // p[0] was reversed for the cipher operation.
+                // the strings are held in reverse, too
+                let mut pb = p[0];
+                pb.reverse();
+                // v is shadowed by the conversion of b, above
+                let m = if i % 2 == 0 { u } else { n - u };
+                let mut c = ffx.bignum_to_digits(&na, Some(m));
+                let mut o = ffx.bignum_to_digits(&nb, Some(n - m));
+                c.reverse();
+                o.reverse();
+                let (a, b) = match which {
+                    ffx::CipherType::Encrypt => (o, c),
+                    ffx::CipherType::Decrypt => (c, o),
+                };
+
+                crate::trace::Round {
+                    algorithm: Algorithm::FF3_1,
+                    i,
+                    p: pb.to_vec(),
+                    q: None,
+                    r: None,
+                    s: p[1].to_vec(),
+                    y: y.to_string(),
+                    c: na.to_string(),
+                    a,
+                    b,
+                }
+            });
+
+            p.zeroize();
+            ffx::FFX::clear_bignum(&mut y);
             // (step 4i, partial)
             std::mem::swap(&mut mu, &mut mv);
 
@@ -208,30 +315,33 @@ impl FF3_1 {
         }
 
         // convert A and B back from their numerical representations
-        b = ffx.bignum_to_chars(&nb, Some(v))?;
-        a = ffx.bignum_to_chars(&na, Some(u))?;
+        *b = ffx.bignum_to_digits(&nb, Some(v));
+        *a = ffx.bignum_to_digits(&na, Some(u));
+        ffx::FFX::clear_bignum(&mut na);
+        ffx::FFX::clear_bignum(&mut nb);
 
         // restore the ordering of the strings
         b.reverse();
         a.reverse();
 
         // (step 5)
-        Ok([a, b].concat())
+        Ok([a.as_slice(), b.as_slice()].concat())
     }
 
     // common function to convert the input String to a sequence
-    // of chars before the cipher operation and back again after
+    // of digits before the cipher operation and back again after
     fn cipher_string(
         &self,
         inp_s: &str,
         opt_t: Option<&[u8]>,
         which: ffx::CipherType,
     ) -> Result<String> {
-        let mut inp_c = Vec::<char>::new();
-        inp_s.chars().for_each(|c| inp_c.push(c));
-
-        let out_c = self.cipher_chars(&inp_c, opt_t, which)?;
-        Ok(String::from_iter(out_c))
+        // one of the two sequences of digits is the plaintext,
+        // so both (and the case of its letters) are wiped when
+        // they're no longer needed
+        let (inp_d, upper) = self.ffx.text_to_digits(inp_s)?;
+        let out_d = Zeroizing::new(self.cipher_digits(&inp_d, opt_t, which)?);
+        self.ffx.digits_to_text(&out_d, &upper)
     }
 
     /// Encrypt a string
@@ -250,6 +360,266 @@ impl FF3_1 {
     pub fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
         self.cipher_string(ct, twk, ffx::CipherType::Decrypt)
     }
+
+    /// Check whether a string can be encrypted/decrypted
+    ///
+    /// Every character of the input that is not part of the alphabet
+    /// is reported, along with the length of the input and the range
+    /// of lengths allowed by the context.
+    pub fn validate(&self, txt: &str) -> Validation {
+        self.ffx.validate(txt)
+    }
+
+    /// The key check value of the key
+    ///
+    /// See [`KeyCheckValue`]. This is computed from the key as
+    /// supplied, not the reversed key used internally by FF3-1.
+    pub fn key_check_value(&self) -> KeyCheckValue {
+        self.kcv
+    }
+
+    /// The fingerprint of the key
+    ///
+    /// See [`Fingerprint`].
+    pub fn key_fingerprint(&self) -> Fingerprint {
+        self.fpr
+    }
+
+    /// Whether the expanded key is held in locked memory
+    ///
+    /// See the [`memory`](crate::memory) module. This is always
+    /// [`LockStatus::Disabled`] unless the `mlock` feature is enabled.
+    pub fn key_lock_status(&self) -> LockStatus {
+        self.ffx.get_key_lock_status()
+    }
+}
+
+/// Builder for FF3-1 contexts
+///
+/// The only required parameter is the key. If the radix is not
+/// specified, it is taken to be the number of characters in the
+/// alphabet, in which case an alphabet must be supplied. All
+/// parameters are validated when the context is built.
+#[derive(Clone, Default)]
+pub struct Builder<'a> {
+    key: Option<Result<SecretKey>>,
+    twk: Option<&'a [u8]>,
+    radix: Option<usize>,
+    alpha: Option<Result<Arc<dyn Alphabet>>>,
+    input: InputOptions,
+    #[cfg(feature = "trace")]
+    tracer: Option<Arc<dyn Tracer>>,
+}
+
+impl<'a> Builder<'a> {
+    /// Set the encryption key
+    ///
+    /// The key may be any of the lengths supported by AES. It may be
+    /// given as a slice of bytes or as a [`SecretKey`]. In either case,
+    /// the builder's copy of the key is wiped when the builder is dropped.
+    pub fn key(mut self, key: impl Into<SecretKey>) -> Self {
+        self.key = Some(Ok(key.into()));
+        self
+    }
+
+    /// Set the encryption key to one derived from a master key
+    ///
+    /// The key is derived for the given label and FF3-1 via
+    /// [`kdf::derive_key`], replacing any key set previously.
+    pub fn master_key(
+        mut self,
+        master: &[u8],
+        label: &str,
+        size: KeySize,
+    ) -> Self {
+        self.key = Some(kdf::derive_key(master, label, Algorithm::FF3_1, size));
+        self
+    }
+
+    /// Set the encryption key to one retrieved from a key provider
+    ///
+    /// A version of `None` selects the latest version of the key.
+    /// The key replaces any key set previously. Building the context
+    /// fails if the key is restricted to another algorithm.
+    pub fn provider_key(
+        mut self,
+        provider: &dyn KeyProvider,
+        id: &str,
+        version: Option<u32>,
+    ) -> Self {
+        self.key =
+            Some(provider::fetch_for(provider, id, version, Algorithm::FF3_1));
+        self
+    }
+
+    /// Set the encryption key to one unwrapped from a wrapped key
+    ///
+    /// The key may be wrapped as specified by either RFC 3394 or
+    /// RFC 5649. The key replaces any key set previously.
+    pub fn wrapped_key(mut self, wrapped: &[u8], kek: &[u8]) -> Self {
+        self.key = Some(keywrap::unwrap_key(kek, wrapped));
+        self
+    }
+
+    /// Set the default tweak
+    ///
+    /// The tweak must be 7 bytes long. If not set, a tweak must be
+    /// supplied during each encrypt and decrypt operation.
+    pub fn tweak(mut self, twk: &'a [u8]) -> Self {
+        self.twk = Some(twk);
+        self
+    }
+
+    /// Set the radix
+    pub fn radix(mut self, radix: usize) -> Self {
+        self.radix = Some(radix);
+        self
+    }
+
+    /// Set the alphabet
+    ///
+    /// The alphabet may be a string of letters or any implementation
+    /// of the [`Alphabet`] trait. Only the first `radix` letters of
+    /// the alphabet are used.
+    pub fn alphabet(mut self, alpha: impl IntoAlphabet) -> Self {
+        self.alpha = Some(alpha.into_alphabet());
+        self
+    }
+
+    /// Set the options used to match the input against the alphabet
+    ///
+    /// By default, the input must match the letters of the alphabet
+    /// exactly. See [`InputOptions`] for the alternatives.
+    pub fn input_options(mut self, opts: InputOptions) -> Self {
+        self.input = opts;
+        self
+    }
+
+    /// Set the tracer to receive the values computed in each round
+    ///
+    /// Only available with the `trace` feature. See the
+    /// [`trace`](crate::trace) module.
+    #[cfg(feature = "trace")]
+    pub fn tracer(mut self, tracer: Arc<dyn Tracer>) -> Self {
+        self.tracer = Some(tracer);
+        self
+    }
+
+    /// Validate the parameters and create the FF3-1 context
+    ///
+    /// When [FIPS mode](crate::fips) is enabled, the context must also
+    /// satisfy the requirements of that mode.
+    pub fn build(&self) -> Result<FF3_1> {
+        let ctx = self.build_unapproved()?;
+        fips::approve(&ctx, ctx.ffx.get_key_length())?;
+        Ok(ctx)
+    }
+
+    // create the context without the checks of fips mode, which
+    // the self-tests (required by fips mode) need to do
+    pub(crate) fn build_unapproved(&self) -> Result<FF3_1> {
+        let key = match &self.key {
+            Some(k) => k.clone()?,
+            None => {
+                return Err(Error::new(ErrorKind::MissingParameter {
+                    name: "key",
+                }))
+            }
+        };
+        let alpha = match &self.alpha {
+            Some(a) => a.clone()?,
+            None => None::<&str>.into_alphabet()?,
+        };
+        let radix = match (self.radix, &self.alpha) {
+            (Some(r), _) => r,
+            (None, Some(_)) => alpha.len(),
+            (None, None) => {
+                return Err(Error::new(ErrorKind::MissingParameter {
+                    name: "radix",
+                }))
+            }
+        };
+
+        // key is reversed for ff3-1. the reversed
+        // copy is wiped once the cipher is initialized
+        let mut k = Zeroizing::new(key.expose_secret().to_vec());
+        k.reverse();
+
+        let mut ffx = ffx::FFX::new(
+            &k,
+            self.twk,
+            // maxlen for ff3-1:
+            //   = 2 * floor(log_radix(2**96))
+            //   = 2 * floor(log2(2**96) / log2(radix))
+            //   = 2 * floor(96 / log2(radix))
+            //
+            // sp 800-38g rev. 1 takes the floor before doubling,
+            // which is sometimes one less than floor(192 / log2(radix))
+            fips::ff3_1_max_len(radix),
+            // tweak size is fixed for ff3-1
+            7,
+            7,
+            radix,
+            alpha,
+        )?;
+        ffx.set_input_options(self.input)?;
+        #[cfg(feature = "trace")]
+        ffx.set_tracer(self.tracer.clone());
+
+        Ok(FF3_1 {
+            ffx,
+            kcv: key.check_value()?,
+            fpr: key.fingerprint(),
+        })
+    }
+}
+
+impl crate::cipher::sealed::Sealed for FF3_1 {
+    fn ffx(&self) -> &ffx::FFX {
+        &self.ffx
+    }
+}
+
+impl FormatPreservingCipher for FF3_1 {
+    fn encrypt(&self, pt: &str, twk: Option<&[u8]>) -> Result<String> {
+        FF3_1::encrypt(self, pt, twk)
+    }
+
+    fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
+        FF3_1::decrypt(self, ct, twk)
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        Algorithm::FF3_1
+    }
+
+    fn radix(&self) -> usize {
+        self.ffx.get_radix()
+    }
+
+    fn alphabet(&self) -> &dyn Alphabet {
+        self.ffx.get_alphabet()
+    }
+
+    fn min_len(&self) -> usize {
+        self.ffx.get_min_text_length()
+    }
+
+    fn max_len(&self) -> usize {
+        self.ffx.get_max_text_length()
+    }
+
+    fn min_tweak_len(&self) -> usize {
+        self.ffx.get_min_tweak_length()
+    }
+
+    fn max_tweak_len(&self) -> Option<usize> {
+        self.ffx.get_max_tweak_length()
+    }
+
+    fn validate(&self, txt: &str) -> Validation {
+        FF3_1::validate(self, txt)
+    }
 }
 
 fn cipher(
@@ -261,7 +631,7 @@ fn cipher(
     op: fn(&FF3_1, &str, Option<&[u8]>) -> Result<String>,
 ) -> Result<String> {
     let ff3_1 = FF3_1::new(key, None, radix, alpha)?;
-    return op(&ff3_1, txt, twk);
+    op(&ff3_1, txt, twk)
 }
 
 pub fn encrypt(
@@ -271,7 +641,7 @@ pub fn encrypt(
     radix: usize,
     alpha: Option<&str>,
 ) -> Result<String> {
-    return cipher(key, twk, pt, radix, alpha, FF3_1::encrypt);
+    cipher(key, twk, pt, radix, alpha, FF3_1::encrypt)
 }
 
 pub fn decrypt(
@@ -281,7 +651,7 @@ pub fn decrypt(
     radix: usize,
     alpha: Option<&str>,
 ) -> Result<String> {
-    return cipher(key, twk, ct, radix, alpha, FF3_1::decrypt);
+    cipher(key, twk, ct, radix, alpha, FF3_1::decrypt)
 }
 
 #[cfg(test)]
@@ -1,7 +1,22 @@
 use crate::aes;
-use crate::alphabet;
-use crate::error::Error;
+use crate::alphabet::input::{InputMap, InputOptions, Letters};
+use crate::alphabet::{self, Alphabet, IntoAlphabet};
+use crate::cipher::{InvalidCharacter, Validation};
+use crate::error::{Error, ErrorKind};
+use crate::memory::LockStatus;
 use crate::result::Result;
+#[cfg(feature = "trace")]
+use crate::trace::{Round, Tracer};
+
+use num_traits::{ToPrimitive, Zero};
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use zeroize::Zeroizing;
+
+// the largest radix allowed by the specification for both algorithms
+const MAX_RADIX: usize = 1 << 16;
 
 pub enum CipherType {
     Encrypt,
@@ -18,11 +33,17 @@ struct FFXSizeLimits {
     txt: SizeLimits,
 }
 
+#[allow(clippy::upper_case_acronyms)]
 pub struct FFX {
     cipher: aes::Cipher,
-    twk: Vec<u8>,
+    twk: Zeroizing<Vec<u8>>,
     len: FFXSizeLimits,
-    alpha: alphabet::Alphabet,
+    alpha: Arc<dyn Alphabet>,
+    input: Option<InputMap>,
+    // the length, in bytes, of the longest letter that may be output
+    max_letter: usize,
+    #[cfg(feature = "trace")]
+    tracer: Option<Arc<dyn Tracer>>,
 }
 
 impl FFX {
@@ -33,13 +54,23 @@ impl FFX {
         mintwk: usize,
         maxtwk: usize,
         radix: usize,
-        opt_alpha: Option<&str>,
+        alpha: impl IntoAlphabet,
     ) -> Result<Self> {
-        if radix < 2 {
-            return Err(Error::new("invalid radix"));
+        if !(2..=MAX_RADIX).contains(&radix) {
+            return Err(Error::new(ErrorKind::InvalidRadix { radix }));
         }
 
-        let alpha = alphabet::Alphabet::new(opt_alpha, Some(radix))?;
+        let alpha = alphabet::limit(alpha.into_alphabet()?, radix)?;
+
+        let mut ltr = String::new();
+        let max_letter = (0..radix)
+            .map(|i| {
+                ltr.clear();
+                alpha.write_letter(i, &mut ltr);
+                ltr.len()
+            })
+            .max()
+            .unwrap_or(0);
 
         // the minimum required length for both ff1 and ff3-1 is given
         // by the inequality: radix**minlen >= 1_000_000
@@ -50,31 +81,25 @@ impl FFX {
         //         = ceil(6 / log_10(radix))
         let mintxt = (6f64 / (radix as f64).log10()).ceil() as usize;
         if mintxt < 2 || mintxt > maxtxt {
-            return Err(Error::new("unsupported radix/maximum text length"));
+            return Err(Error::new(ErrorKind::InvalidRadix { radix }));
         }
 
         if mintwk > maxtwk {
-            return Err(Error::new(
-                "minimum tweak length must be less than maximum",
-            ));
+            return Err(Error::new(ErrorKind::InvalidTweakBounds {
+                min: mintwk,
+                max: maxtwk,
+            }));
         }
 
-        let twk: Vec<u8>;
-        match opt_twk {
-            None => twk = Vec::new(),
-            Some(t) => {
-                if t.len() < mintwk || (maxtwk > 0 && t.len() > maxtwk) {
-                    return Err(Error::new("invalid tweak length"));
-                }
-
-                twk = t.to_vec();
-            }
-        }
+        let twk = Zeroizing::new(match opt_twk {
+            None => Vec::new(),
+            Some(t) => t.to_vec(),
+        });
 
-        Ok(FFX {
+        let ffx = FFX {
             cipher: aes::Cipher::new(key)?,
 
-            twk: twk,
+            twk,
 
             len: FFXSizeLimits {
                 twk: SizeLimits {
@@ -87,8 +112,18 @@ impl FFX {
                 },
             },
 
-            alpha: alpha,
-        })
+            alpha,
+            input: None,
+            max_letter,
+            #[cfg(feature = "trace")]
+            tracer: None,
+        };
+
+        if opt_twk.is_some() {
+            ffx.validate_tweak_length(ffx.twk.len())?;
+        }
+
+        Ok(ffx)
     }
 
     pub fn get_tweak<'a>(&'a self, opt_twk: &Option<&'a [u8]>) -> &'a [u8] {
@@ -102,13 +137,56 @@ impl FFX {
         self.alpha.len()
     }
 
+    pub fn get_alphabet(&self) -> &dyn Alphabet {
+        self.alpha.as_ref()
+    }
+
+    pub fn get_input_options(&self) -> InputOptions {
+        self.input
+            .as_ref()
+            .map(InputMap::options)
+            .unwrap_or_default()
+    }
+
+    pub fn get_min_text_length(&self) -> usize {
+        self.len.txt.min
+    }
+
+    pub fn get_max_text_length(&self) -> usize {
+        self.len.txt.max
+    }
+
+    pub fn get_min_tweak_length(&self) -> usize {
+        self.len.twk.min
+    }
+
+    // a maximum tweak length of 0 means that it is unbounded
+    pub fn get_max_tweak_length(&self) -> Option<usize> {
+        match self.len.twk.max {
+            0 => None,
+            max => Some(max),
+        }
+    }
+
     pub fn get_cipher_block_size(&self) -> usize {
         self.cipher.block_size()
     }
 
+    pub fn get_key_length(&self) -> usize {
+        self.cipher.key_len()
+    }
+
+    pub fn get_key_lock_status(&self) -> LockStatus {
+        self.cipher.lock_status()
+    }
+
     pub fn validate_text_length(&self, n: usize) -> Result<()> {
         if n < self.len.txt.min || n > self.len.txt.max {
-            return Err(Error::new("invalid text length"));
+            return Err(Error::new(ErrorKind::InvalidTextLength {
+                min: self.len.txt.min,
+                max: self.len.txt.max,
+                actual: n,
+            }));
         }
 
         Ok(())
@@ -118,19 +196,29 @@ impl FFX {
         if n < self.len.twk.min
             || (self.len.twk.max > 0 && n > self.len.twk.max)
         {
-            return Err(Error::new("invalid tweak length"));
+            return Err(Error::new(ErrorKind::InvalidTweakLength {
+                min: self.len.twk.min,
+                max: self.get_max_tweak_length(),
+                actual: n,
+            }));
         }
 
         Ok(())
     }
 
+    // aes-cbc-mac with a zero iv. the output is the last block
     pub fn prf(&self, s: &[u8], d: &mut [u8]) -> Result<()> {
-        let mut c = self.cipher.clone();
-        let blksz = c.block_size();
+        let blksz = self.cipher.block_size();
+        // the chaining value, i.e. the previous output block
+        let mut x = Zeroizing::new([0u8; 16]);
 
         for i in 0..(s.len() / blksz) {
             let j = i * blksz;
-            c.encrypt_block(&s[j..(j + blksz)], d);
+            for (x, s) in x.iter_mut().zip(&s[j..(j + blksz)]) {
+                *x ^= s;
+            }
+            self.cipher.encrypt_block(x.as_slice(), d);
+            x.copy_from_slice(&d[..blksz]);
         }
 
         Ok(())
@@ -140,55 +228,205 @@ impl FFX {
         self.prf(&s[0..16], d)
     }
 
-    pub fn chars_to_bignum(
-        &self,
-        chars: &[char],
-    ) -> Result<num_bigint::BigInt> {
-        let radix = self.alpha.len();
-        let mut digits = Vec::<u8>::with_capacity(chars.len());
+    #[cfg(feature = "trace")]
+    pub fn set_tracer(&mut self, tracer: Option<Arc<dyn Tracer>>) {
+        self.tracer = tracer;
+    }
+
+    // the round is only constructed if there is a tracer to receive it
+    #[cfg(feature = "trace")]
+    pub fn trace(&self, round: impl FnOnce() -> Round) {
+        if let Some(t) = &self.tracer {
+            t.round(&round());
+        }
+    }
 
-        for c in chars {
-            digits.push(self.alpha.ltr(*c)? as u8);
+    pub fn set_input_options(&mut self, opts: InputOptions) -> Result<()> {
+        self.input = match opts.is_exact() {
+            true => None,
+            false => Some(InputMap::new(self.alpha.as_ref(), opts)?),
+        };
+
+        if let Some(input) = &self.input {
+            self.max_letter = self.max_letter.max(input.max_letter_len());
         }
 
-        Ok(num_bigint::BigInt::from_radix_be(
-            num_bigint::Sign::Plus,
-            &digits,
-            radix as u32,
-        )
-        .unwrap())
+        Ok(())
     }
 
-    pub fn bignum_to_chars(
+    // splits the input into the letters of the alphabet, converting
+    // each to its position within the alphabet. the position of an
+    // invalid character is reported as its index (in characters, not
+    // bytes) within the input. when the input options preserve case,
+    // the case of each letter is also returned; otherwise, the
+    // returned case mask is empty
+    pub fn text_to_digits(&self, s: &str) -> Result<Letters> {
+        if let Some(input) = &self.input {
+            return input.read(s);
+        }
+
+        let mut digits = Zeroizing::new(Vec::<u16>::with_capacity(s.len()));
+        alphabet::tokenize(self.alpha.as_ref(), s, |d, _| {
+            digits.push(d as u16)
+        })?;
+
+        Ok((digits, Zeroizing::new(Vec::new())))
+    }
+
+    // checks the entire input, collecting every invalid character
+    pub fn validate(&self, s: &str) -> Validation {
+        let mut invalid = Vec::new();
+        let mut len = 0;
+
+        self.scan(s, |pos, i, c| {
+            if pos.is_none() {
+                invalid.push(InvalidCharacter { ch: c, position: i });
+            }
+            len += 1;
+            true
+        });
+
+        Validation::new(invalid, len, self.len.txt.min, self.len.txt.max)
+    }
+
+    // splits the input into letters as with alphabet::scan(), matching
+    // the input as specified by the input options. the indices passed
+    // to `f` refer to the input after it has been normalized
+    pub fn scan(
         &self,
-        n: &num_bigint::BigInt,
-        opt_len: Option<usize>,
-    ) -> Result<Vec<char>> {
-        let (_, digits) = n.to_radix_le(self.alpha.len() as u32);
-        let mut chars = Vec::<char>::with_capacity(digits.len());
+        s: &str,
+        f: impl FnMut(Option<usize>, usize, char) -> bool,
+    ) {
+        match &self.input {
+            Some(input) => input.scan(s, f),
+            None => alphabet::scan(self.alpha.as_ref(), s, f),
+        }
+    }
 
-        for d in digits {
-            chars.push(self.alpha.pos(d as usize)?);
+    // the input in the normalization form given by the input options
+    pub fn normalize<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        match &self.input {
+            Some(input) => Cow::Owned(input.normalize(s)),
+            None => Cow::Borrowed(s),
         }
+    }
 
-        match opt_len {
-            None => (),
-            Some(len) => {
-                if chars.len() < len {
-                    chars.resize(len, self.alpha.pos(0)?);
-                }
+    // converts each digit back to its letter in the alphabet, applying
+    // the case, if any, from the corresponding position in the mask.
+    // the output is allocated at its largest possible size so that
+    // growing it doesn't leave copies of the plaintext behind
+    pub fn digits_to_text(
+        &self,
+        digits: &[u16],
+        upper: &[bool],
+    ) -> Result<String> {
+        let mut s = String::with_capacity(digits.len() * self.max_letter);
+
+        for (i, d) in digits.iter().enumerate() {
+            let pos = *d as usize;
+            let written = match (&self.input, upper.get(i)) {
+                (Some(input), Some(u)) => input.write(pos, *u, &mut s),
+                _ => false,
+            };
+
+            if !written && !self.alpha.write_letter(pos, &mut s) {
+                return Err(Error::new(ErrorKind::InvalidPosition {
+                    position: pos,
+                }));
             }
         }
 
-        chars.reverse();
-        Ok(chars)
+        Ok(s)
+    }
+
+    // overwrites the value of the number with zeros. the memory used
+    // by temporary values during arithmetic is out of reach, so this
+    // only limits the number of copies of sensitive values left behind
+    pub fn clear_bignum(n: &mut num_bigint::BigInt) {
+        let len = n.iter_u32_digits().len();
+        n.assign_from_slice(num_bigint::Sign::Plus, &vec![0; len]);
+    }
+
+    pub fn digits_to_bignum(
+        &self,
+        digits: &[u16],
+    ) -> Result<num_bigint::BigInt> {
+        let radix = self.alpha.len() as u32;
+
+        if let Some(d) = digits.iter().find(|d| u32::from(**d) >= radix) {
+            return Err(Error::new(ErrorKind::InvalidPosition {
+                position: *d as usize,
+            }));
+        }
+
+        // the num_bigint library only supports conversion from
+        // radices up to 256. anything larger is done "by hand"
+        if radix <= 256 {
+            let digits: Zeroizing<Vec<u8>> =
+                Zeroizing::new(digits.iter().map(|d| *d as u8).collect());
+            num_bigint::BigInt::from_radix_be(
+                num_bigint::Sign::Plus,
+                &digits,
+                radix,
+            )
+            .ok_or_else(|| {
+                Error::new(ErrorKind::InvalidRadix {
+                    radix: radix as usize,
+                })
+            })
+        } else {
+            Ok(digits
+                .iter()
+                .fold(num_bigint::BigInt::zero(), |n, d| n * radix + *d))
+        }
+    }
+
+    // converts the number to a sequence of digits (most significant
+    // first), left padding with zeros to the specified length
+    pub fn bignum_to_digits(
+        &self,
+        n: &num_bigint::BigInt,
+        opt_len: Option<usize>,
+    ) -> Vec<u16> {
+        let radix = self.alpha.len() as u32;
+
+        // as with conversion to a bignum, radices larger than
+        // 256 must be handled outside of the num_bigint library.
+        // the digits are least significant first
+        let le: Zeroizing<Vec<u16>> = if radix <= 256 {
+            let (_, digits) = n.to_radix_le(radix);
+            let digits = Zeroizing::new(digits);
+            Zeroizing::new(digits.iter().map(|d| u16::from(*d)).collect())
+        } else {
+            // each digit accounts for more than 8 bits of the number
+            let mut m = n.clone();
+            let mut digits = Zeroizing::new(Vec::<u16>::with_capacity(
+                m.bits().div_ceil(8) as usize,
+            ));
+            while !m.is_zero() {
+                digits.push((&m % radix).to_u16().unwrap());
+                m /= radix;
+            }
+            FFX::clear_bignum(&mut m);
+            digits
+        };
+
+        // the result is allocated at its final size so
+        // that padding it doesn't leave a copy behind
+        let len = std::cmp::max(le.len(), opt_len.unwrap_or(0));
+        let mut digits = Vec::with_capacity(len);
+        digits.resize(len - le.len(), 0);
+        digits.extend(le.iter().rev());
+        digits
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::FFX;
+    use crate::error::ErrorKind;
     use crate::result::Result;
+    use num_traits::Zero;
 
     use std::str::FromStr;
 
@@ -222,12 +460,85 @@ mod tests {
         let s = n.to_str_radix(10);
         assert!(s == n_str);
 
-        let c = ffx.bignum_to_chars(&n, None)?;
-        assert!(String::from_iter(c.clone()) == n_str);
+        let d = ffx.bignum_to_digits(&n, None);
+        assert!(ffx.digits_to_text(&d, &[])? == n_str);
+
+        let r = ffx.digits_to_bignum(&ffx.text_to_digits(n_str)?.0)?;
+        assert!(n == r);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_radix_conversion() -> Result<()> {
+        let alpha = String::from_iter(
+            (0x4e00..0x4e00 + 300).map(|c| char::from_u32(c).unwrap()),
+        );
+        let ffx =
+            FFX::new(&[0; 16], None, 1024, 0, 0, 300, Some(alpha.as_str()))?;
+
+        let n_str = "9037450980398204379409345039453045723049";
+        let n = num_bigint::BigInt::from_str(n_str).unwrap();
+
+        let d = ffx.bignum_to_digits(&n, Some(20));
+        assert!(d.len() == 20);
 
-        let r = ffx.chars_to_bignum(&c)?;
+        let s = ffx.digits_to_text(&d, &[])?;
+        let r = ffx.digits_to_bignum(&ffx.text_to_digits(&s)?.0)?;
         assert!(n == r);
 
         Ok(())
     }
+
+    #[test]
+    fn test_digit_out_of_range() -> Result<()> {
+        let ffx = FFX::new(&[0; 16], None, 1024, 0, 0, 10, None)?;
+
+        let res = ffx.digits_to_bignum(&[1, 2, 42]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidPosition { position: 42 }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_capacity() -> Result<()> {
+        let alpha = "\u{3b1}\u{3b2}\u{3b3}\u{3b4}\u{3b5}\u{3b6}0123";
+        let ffx = FFX::new(&[0; 16], None, 1024, 0, 0, 10, Some(alpha))?;
+
+        // the output never needs to grow, even though
+        // most of the letters are multiple bytes long
+        let s = ffx.digits_to_text(&[0, 1, 2, 3], &[])?;
+        assert!(s.len() == 8 && s.capacity() == 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_bignum() {
+        let mut n = num_bigint::BigInt::from_str(
+            "9037450980398204379409345039453045723049",
+        )
+        .unwrap();
+        FFX::clear_bignum(&mut n);
+        assert!(n.is_zero());
+    }
+
+    #[test]
+    fn test_invalid_character_position() -> Result<()> {
+        let ffx = FFX::new(&[0; 16], None, 1024, 0, 0, 10, None)?;
+
+        let res = ffx.text_to_digits("0123x56789");
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidCharacter {
+                ch: 'x',
+                position: 4
+            }
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,187 @@
+//! Known-answer self-tests and FIPS mode
+//!
+//! [`self_test`] verifies the implementations of FF1 and FF3-1 against
+//! the NIST sample vectors for FF1 and the ACVP vectors for FF3-1, in
+//! both directions. The vectors are those in the `src/fips/vectors` directory
+//! of the crate, which the tests of the [`acvp`](crate::acvp) module
+//! also evaluate. The tests run once per process; the result is cached
+//! and returned by every subsequent call.
+//!
+//! FIPS mode is a process-wide switch that, once [enabled](enable),
+//! causes the creation of contexts to fail unless the self-tests have
+//! passed and the parameters of the context satisfy the requirements
+//! of NIST SP 800-38G Rev. 1:
+//!
+//! - the key is 128, 192, or 256 bits long
+//! - the radix is between 2 and 2^16
+//! - the domain, i.e. radix^minlen, contains at least one million values
+//! - for FF1, the text is at most 2^32 - 1 letters long and the maximum
+//!   tweak length, if set, is less than 2^32 bytes. FF1 rejects longer
+//!   tweaks regardless, since it encodes their lengths in 32 bits
+//! - for FF3-1, the text is at most 2 * floor(log_radix(2^96)) letters
+//!   long and the tweak is 56 bits long
+//!
+//! FIPS mode can't be disabled once enabled.
+//!
+//! # Example
+//! ```rust
+//! fpe::fips::self_test().unwrap();
+//! ```
+
+use crate::acvp::VectorSet;
+use crate::cipher::{Algorithm, FormatPreservingCipher};
+use crate::error::{Error, ErrorKind};
+use crate::result::Result;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SELF_TEST: OnceLock<Result<()>> = OnceLock::new();
+
+/// Enable FIPS mode for the remainder of the process
+///
+/// The self-tests are run (if they have not already been) and their
+/// result returned. FIPS mode is enabled regardless of the result, in
+/// which case no contexts can be created.
+pub fn enable() -> Result<()> {
+    ENABLED.store(true, Ordering::SeqCst);
+    self_test()
+}
+
+/// Whether FIPS mode is enabled
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+/// Run the known-answer self-tests
+///
+/// The tests are only run by the first call; the result is cached
+/// and returned by that and all later calls.
+pub fn self_test() -> Result<()> {
+    SELF_TEST.get_or_init(run_self_tests).clone()
+}
+
+// the maximum length of the text for ff3-1 per sp 800-38g rev. 1
+pub(crate) fn ff3_1_max_len(radix: usize) -> usize {
+    2 * (96f64 / (radix as f64).log2()).floor() as usize
+}
+
+// verify that a newly created context may be used. this does nothing
+// unless fips mode is enabled
+pub(crate) fn approve(
+    ctx: &dyn FormatPreservingCipher,
+    key_len: usize,
+) -> Result<()> {
+    if !is_enabled() {
+        return Ok(());
+    }
+    self_test()?;
+
+    let radix = ctx.radix();
+    if ![16, 24, 32].contains(&key_len) {
+        return Err(not_approved(format!("key length {}", key_len)));
+    }
+    if !(2..=1 << 16).contains(&radix) {
+        return Err(not_approved(format!("radix {}", radix)));
+    }
+    let domain = (radix as u128).checked_pow(ctx.min_len() as u32);
+    if domain.is_some_and(|d| d < 1_000_000) {
+        return Err(not_approved(format!(
+            "domain of {} letters with radix {}",
+            ctx.min_len(),
+            radix
+        )));
+    }
+
+    let (max_len, tweak) = match ctx.algorithm() {
+        Algorithm::FF1 => (
+            (1 << 32) - 1,
+            ctx.max_tweak_len().is_none_or(|t| t <= u32::MAX as usize),
+        ),
+        Algorithm::FF3_1 => (
+            ff3_1_max_len(radix),
+            ctx.min_tweak_len() == 7 && ctx.max_tweak_len() == Some(7),
+        ),
+    };
+    if ctx.max_len() > max_len {
+        return Err(not_approved(format!(
+            "maximum text length {}",
+            ctx.max_len()
+        )));
+    }
+    if !tweak {
+        return Err(not_approved(match ctx.max_tweak_len() {
+            Some(t) => format!("maximum tweak length {}", t),
+            None => "unbounded ff3-1 tweak length".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+fn not_approved(reason: String) -> Error {
+    Error::new(ErrorKind::NotApproved { reason })
+}
+
+// the vectors are shared with the tests of the acvp module, which
+// check them against the same contexts as are used here
+const FF1_PROMPT: &str = include_str!("fips/vectors/ff1-prompt.json");
+const FF1_EXPECTED: &str =
+    include_str!("fips/vectors/ff1-expectedResults.json");
+const FF3_1_PROMPT: &str = include_str!("fips/vectors/ff3_1-prompt.json");
+const FF3_1_EXPECTED: &str =
+    include_str!("fips/vectors/ff3_1-expectedResults.json");
+
+fn run_self_tests() -> Result<()> {
+    check("ff1", FF1_PROMPT, FF1_EXPECTED)?;
+    check("ff3-1", FF3_1_PROMPT, FF3_1_EXPECTED)
+}
+
+// run each test of a vector set, in both directions. any error,
+// including in the vectors themselves, is reported as a failure
+fn check(name: &str, prompt: &str, expected: &str) -> Result<()> {
+    let failed = |test| Err(Error::new(ErrorKind::SelfTestFailed { test }));
+
+    let report = match VectorSet::parse(prompt, expected) {
+        Ok(vs) => vs.run(),
+        Err(_) => return failed(format!("{} vectors", name)),
+    };
+    match report.failures.first() {
+        None if report.total() > 0 => Ok(()),
+        None => failed(format!("{} vectors", name)),
+        Some(f) => failed(format!("{} test {}", name, f.tc_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_tests() {
+        assert!(self_test().is_ok());
+        assert!(run_self_tests().is_ok());
+    }
+
+    #[test]
+    fn failure() {
+        let wrong = FF1_EXPECTED.replace("2433477484", "2433477485");
+        assert!(matches!(
+            check("ff1", FF1_PROMPT, &wrong).err().unwrap().kind(),
+            ErrorKind::SelfTestFailed { test } if test == "ff1 test 1"
+        ));
+        assert!(matches!(
+            check("ff1", FF1_PROMPT, "[]").err().unwrap().kind(),
+            ErrorKind::SelfTestFailed { test } if test == "ff1 vectors"
+        ));
+    }
+
+    #[test]
+    fn ff3_1_max_len() {
+        // 2 * floor(28.9), rather than floor(57.8)
+        assert!(super::ff3_1_max_len(10) == 56);
+        assert!(super::ff3_1_max_len(2) == 192);
+        assert!(super::ff3_1_max_len(1 << 16) == 12);
+    }
+}
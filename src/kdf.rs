@@ -0,0 +1,251 @@
+//! Derivation of keys from a master key
+//!
+//! Rather than managing a separate key for every field, a single
+//! master key may be used to derive a key for each labeled purpose
+//! (e.g. a column) and algorithm. Keys are derived via HKDF-SHA256
+//! (RFC 5869), so keys derived for different labels, or for different
+//! algorithms with the same label, are cryptographically independent
+//! of each other and of the master key.
+//!
+//! # Example
+//! ```rust
+//! use fpe::cipher::Algorithm;
+//! use fpe::kdf::{self, KeySize};
+//!
+//! let master = [0x5a; 32];
+//! let k1 =
+//!     kdf::derive_key(&master, "ssn", Algorithm::FF1, KeySize::Aes256)
+//!         .unwrap();
+//! let k2 =
+//!     kdf::derive_key(&master, "ssn", Algorithm::FF3_1, KeySize::Aes256)
+//!         .unwrap();
+//! assert!(k1.len() == 32 && k1 != k2);
+//!
+//! let ff1 = fpe::ff1::FF1::from_master_key(
+//!     &master,
+//!     "ssn",
+//!     KeySize::Aes256,
+//!     10,
+//!     None,
+//! )
+//! .unwrap();
+//! ```
+
+use crate::cipher::Algorithm;
+use crate::error::{Error, ErrorKind};
+use crate::key::SecretKey;
+use crate::result::Result;
+
+use byteorder::ByteOrder;
+use hkdf::Hkdf;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+// identifies the purpose of derived keys so that they can't
+// collide with keys derived from the same master for other uses
+const DOMAIN: &[u8] = b"fpe-kdf-v1";
+
+/// The minimum length of a master key, in bytes
+pub const MIN_MASTER_KEY_LEN: usize = 16;
+
+/// The maximum length of the output of HKDF-SHA256, in bytes:
+/// 255 times the length of the hash
+pub const MAX_HKDF_OUTPUT_LEN: usize = 255 * 32;
+
+/// The sizes of AES keys that may be derived
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeySize {
+    Aes128,
+    Aes192,
+    Aes256,
+}
+
+impl KeySize {
+    /// The length of the key in bytes
+    pub fn bytes(&self) -> usize {
+        match self {
+            KeySize::Aes128 => 16,
+            KeySize::Aes192 => 24,
+            KeySize::Aes256 => 32,
+        }
+    }
+}
+
+/// HKDF-SHA256 as specified by RFC 5869
+///
+/// Fills `okm` with key material derived from the input key material
+/// and the optional salt and info. The output may be at most
+/// [`MAX_HKDF_OUTPUT_LEN`] bytes long.
+pub fn hkdf_sha256(
+    salt: Option<&[u8]>,
+    ikm: &[u8],
+    info: &[u8],
+    okm: &mut [u8],
+) -> Result<()> {
+    let length = okm.len();
+    let invalid_length = || {
+        Error::new(ErrorKind::InvalidOutputLength {
+            length,
+            max: MAX_HKDF_OUTPUT_LEN,
+        })
+    };
+
+    if length > MAX_HKDF_OUTPUT_LEN {
+        return Err(invalid_length());
+    }
+
+    Hkdf::<Sha256>::new(salt, ikm)
+        .expand(info, okm)
+        .map_err(|_| invalid_length())
+}
+
+/// Derive a key for a labeled purpose and algorithm from a master key
+///
+/// The master key must be at least [`MIN_MASTER_KEY_LEN`] bytes long.
+/// The label and algorithm are length-prefixed in the HKDF info, so
+/// distinct (label, algorithm) pairs always produce distinct inputs.
+pub fn derive_key(
+    master: &[u8],
+    label: &str,
+    algo: Algorithm,
+    size: KeySize,
+) -> Result<SecretKey> {
+    if master.len() < MIN_MASTER_KEY_LEN {
+        return Err(Error::new(ErrorKind::InvalidKey {
+            length: master.len(),
+        }));
+    }
+
+    let mut info = DOMAIN.to_vec();
+    for field in [algo.name().as_bytes(), label.as_bytes()] {
+        let mut n = [0; 8];
+        byteorder::BigEndian::write_u64(&mut n, field.len() as u64);
+        info.extend_from_slice(&n);
+        info.extend_from_slice(field);
+    }
+
+    let mut key = Zeroizing::new(vec![0; size.bytes()]);
+    hkdf_sha256(None, master, &info, &mut key)?;
+    Ok(key.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{derive_key, hkdf_sha256, KeySize, MAX_HKDF_OUTPUT_LEN};
+    use crate::cipher::Algorithm;
+    use crate::error::ErrorKind;
+    use crate::result::Result;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn test_hkdf(salt: &str, ikm: &str, info: &str, okm: &str) -> Result<()> {
+        let salt = hex(salt);
+        let exp = hex(okm);
+        let mut out = vec![0; exp.len()];
+
+        hkdf_sha256(
+            (!salt.is_empty()).then_some(&salt[..]),
+            &hex(ikm),
+            &hex(info),
+            &mut out,
+        )?;
+        assert!(out == exp);
+
+        Ok(())
+    }
+
+    // RFC 5869, appendix A.1
+    #[test]
+    fn rfc5869_a1() -> Result<()> {
+        test_hkdf(
+            "000102030405060708090a0b0c",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "f0f1f2f3f4f5f6f7f8f9",
+            "3cb25f25faacd57a90434f64d0362f2a\
+             2d2d0a90cf1a5a4c5db02d56ecc4c5bf\
+             34007208d5b887185865",
+        )
+    }
+
+    // RFC 5869, appendix A.2
+    #[test]
+    fn rfc5869_a2() -> Result<()> {
+        test_hkdf(
+            "606162636465666768696a6b6c6d6e6f\
+             707172737475767778797a7b7c7d7e7f\
+             808182838485868788898a8b8c8d8e8f\
+             909192939495969798999a9b9c9d9e9f\
+             a0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+            "000102030405060708090a0b0c0d0e0f\
+             101112131415161718191a1b1c1d1e1f\
+             202122232425262728292a2b2c2d2e2f\
+             303132333435363738393a3b3c3d3e3f\
+             404142434445464748494a4b4c4d4e4f",
+            "b0b1b2b3b4b5b6b7b8b9babbbcbdbebf\
+             c0c1c2c3c4c5c6c7c8c9cacbcccdcecf\
+             d0d1d2d3d4d5d6d7d8d9dadbdcdddedf\
+             e0e1e2e3e4e5e6e7e8e9eaebecedeeef\
+             f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            "b11e398dc80327a1c8e7f78c596a4934\
+             4f012eda2d4efad8a050cc4c19afa97c\
+             59045a99cac7827271cb41c65e590e09\
+             da3275600c2f09b8367793a9aca3db71\
+             cc30c58179ec3e87c14c01d5c1f3434f\
+             1d87",
+        )
+    }
+
+    // RFC 5869, appendix A.3
+    #[test]
+    fn rfc5869_a3() -> Result<()> {
+        test_hkdf(
+            "",
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "",
+            "8da4e775a563c18f715f802a063c5a31\
+             b8a11f5c5ee1879ec3454e5f3c738d2d\
+             9d201395faa4b61a96c8",
+        )
+    }
+
+    #[test]
+    fn output_length() -> Result<()> {
+        let mut okm = vec![0; MAX_HKDF_OUTPUT_LEN + 1];
+
+        hkdf_sha256(None, &[0; 32], &[], &mut okm[..MAX_HKDF_OUTPUT_LEN])?;
+        let res = hkdf_sha256(None, &[0; 32], &[], &mut okm);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidOutputLength {
+                length: 8161,
+                max: 8160
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn separation() -> Result<()> {
+        let m = [7; 32];
+
+        let k = derive_key(&m, "a", Algorithm::FF1, KeySize::Aes128)?;
+        assert!(k.len() == 16);
+        assert!(k == derive_key(&m, "a", Algorithm::FF1, KeySize::Aes128)?);
+        assert!(k != derive_key(&m, "b", Algorithm::FF1, KeySize::Aes128)?);
+        assert!(k != derive_key(&m, "a", Algorithm::FF3_1, KeySize::Aes128)?);
+
+        let res = derive_key(&m[..15], "a", Algorithm::FF1, KeySize::Aes128);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidKey { length: 15 }
+        ));
+
+        Ok(())
+    }
+}
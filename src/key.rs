@@ -0,0 +1,421 @@
+//! Handling of secret key material
+//!
+//! Keys passed to the library are copied into a [`SecretKey`], which
+//! wipes its contents when dropped and never prints them via `Debug`.
+//! Keys returned by the library, e.g. by [`crate::kdf::derive_key`],
+//! are returned in the same form.
+//!
+//! ```rust
+//! use fpe::key::SecretKey;
+//!
+//! let key = SecretKey::from(vec![0x2b; 16]);
+//! assert!(format!("{:?}", key) == "SecretKey(16 bytes)");
+//!
+//! let ff1 = fpe::ff1::FF1::builder().key(&key).radix(10).build().unwrap();
+//! ```
+//!
+//! Keys can be generated from the operating system's random number
+//! generator and converted to and from hex, base64, and JSON Web Key
+//! (JWK) encodings. Only keys of the lengths supported by AES are
+//! accepted by the conversions.
+//!
+//! ```rust
+//! use fpe::kdf::KeySize;
+//! use fpe::key::SecretKey;
+//!
+//! let key = SecretKey::generate(KeySize::Aes256).unwrap();
+//! let jwk = key.to_jwk();
+//! assert!(SecretKey::from_jwk(&jwk).unwrap() == key);
+//!
+//! let key = SecretKey::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+//! assert!(key.to_base64().as_str() == "K34VFiiu0qar9xWICc9PPA==");
+//! ```
+//!
+//! A key can be identified without revealing it by its
+//! [`KeyCheckValue`] or its [`Fingerprint`], both of which are also
+//! available from the contexts, e.g. via
+//! [`FF1::key_check_value`](crate::ff1::FF1::key_check_value).
+
+use crate::aes::Cipher;
+use crate::error::{Error, ErrorKind};
+use crate::kdf::KeySize;
+use crate::result::Result;
+
+use std::fmt;
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Key material that is wiped when dropped
+///
+/// The contents of the key are only accessible via
+/// [`SecretKey::expose_secret`], which makes uses of the key
+/// easy to find. Comparison of keys takes the same amount of
+/// time regardless of where they differ.
+#[derive(Clone)]
+pub struct SecretKey(Zeroizing<Vec<u8>>);
+
+impl SecretKey {
+    /// Copy key material into a new secret key
+    pub fn new(key: &[u8]) -> Self {
+        SecretKey(Zeroizing::new(key.to_vec()))
+    }
+
+    /// The key material
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The length of the key, in bytes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the key is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Generate a random key from the operating system's
+    /// random number generator
+    pub fn generate(size: KeySize) -> Result<Self> {
+        let mut key = Zeroizing::new(vec![0; size.bytes()]);
+        fill_random(&mut key)?;
+        Ok(SecretKey(key))
+    }
+
+    /// Parse a key from hexadecimal digits (in either case)
+    pub fn from_hex(s: &str) -> Result<Self> {
+        Self::checked(hex_decode(s)?)
+    }
+
+    /// Parse a key from standard, padded base64
+    pub fn from_base64(s: &str) -> Result<Self> {
+        Self::checked(Zeroizing::new(
+            STANDARD.decode(s).map_err(encoding_error)?,
+        ))
+    }
+
+    /// Parse a key from a JSON Web Key
+    ///
+    /// The key type (`kty`) must be `oct`, and the key (`k`) must be
+    /// unpadded, url-safe base64 as specified by RFC 7517. Other
+    /// members of the JWK are ignored.
+    pub fn from_jwk(s: &str) -> Result<Self> {
+        let jwk: Jwk = serde_json::from_str(s).map_err(encoding_error)?;
+        if jwk.kty != "oct" {
+            return Err(encoding_error(format!(
+                "unsupported key type: {}",
+                jwk.kty
+            )));
+        }
+
+        Self::checked(Zeroizing::new(
+            URL_SAFE_NO_PAD.decode(&jwk.k).map_err(encoding_error)?,
+        ))
+    }
+
+    /// The key as lowercase hexadecimal digits
+    pub fn to_hex(&self) -> Zeroizing<String> {
+        hex_encode(&self.0)
+    }
+
+    /// The key as standard, padded base64
+    pub fn to_base64(&self) -> Zeroizing<String> {
+        Zeroizing::new(STANDARD.encode(&*self.0))
+    }
+
+    /// The key as a JSON Web Key of type `oct`
+    pub fn to_jwk(&self) -> Zeroizing<String> {
+        let jwk = Jwk {
+            kty: "oct".to_string(),
+            k: URL_SAFE_NO_PAD.encode(&*self.0),
+        };
+        // serialization of strings can't fail
+        Zeroizing::new(serde_json::to_string(&jwk).unwrap())
+    }
+
+    // keys read from an encoding must be usable with aes
+    fn checked(key: Zeroizing<Vec<u8>>) -> Result<Self> {
+        match key.len() {
+            16 | 24 | 32 => Ok(SecretKey(key)),
+            n => Err(Error::new(ErrorKind::InvalidKey { length: n })),
+        }
+    }
+
+    /// The key check value of the key
+    ///
+    /// Fails if the key is not one of the lengths supported by AES.
+    pub fn check_value(&self) -> Result<KeyCheckValue> {
+        let mut blk = [0; 16];
+        Cipher::new(&self.0)?.encrypt_block(&[0; 16], &mut blk);
+        Ok(KeyCheckValue([blk[0], blk[1], blk[2]]))
+    }
+
+    /// The fingerprint of the key
+    pub fn fingerprint(&self) -> Fingerprint {
+        let h = Sha256::new()
+            .chain_update(FINGERPRINT_PREFIX)
+            .chain_update(&*self.0)
+            .finalize();
+        Fingerprint(h[..16].try_into().unwrap())
+    }
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_value(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
+
+pub(crate) fn hex_encode(data: &[u8]) -> Zeroizing<String> {
+    let mut s = Zeroizing::new(String::with_capacity(2 * data.len()));
+    for b in data {
+        s.push(HEX_DIGITS[(b >> 4) as usize] as char);
+        s.push(HEX_DIGITS[(b & 0xf) as usize] as char);
+    }
+    s
+}
+
+pub(crate) fn hex_decode(s: &str) -> Result<Zeroizing<Vec<u8>>> {
+    if !s.len().is_multiple_of(2) {
+        return Err(encoding_error("odd number of hex digits"));
+    }
+
+    let mut data = Zeroizing::new(Vec::with_capacity(s.len() / 2));
+    for pair in s.as_bytes().chunks(2) {
+        match (hex_value(pair[0]), hex_value(pair[1])) {
+            (Some(hi), Some(lo)) => data.push(hi << 4 | lo),
+            _ => return Err(encoding_error("invalid hex digit")),
+        }
+    }
+
+    Ok(data)
+}
+
+// fill the buffer from the operating system's random number generator
+pub(crate) fn fill_random(buf: &mut [u8]) -> Result<()> {
+    getrandom::getrandom(buf).map_err(|e| {
+        Error::new(ErrorKind::RandomGeneration {
+            reason: e.to_string(),
+        })
+    })
+}
+
+fn encoding_error(reason: impl ToString) -> Error {
+    Error::new(ErrorKind::InvalidKeyEncoding {
+        reason: reason.to_string(),
+    })
+}
+
+// the members of a json web key used by the library
+#[derive(Serialize, Deserialize)]
+struct Jwk {
+    kty: String,
+    k: String,
+}
+
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        self.k.zeroize();
+    }
+}
+
+// separates fingerprints from any other use of sha-256 over the key
+const FINGERPRINT_PREFIX: &[u8] = b"fpe-key-fingerprint-v1";
+
+/// The key check value (KCV) of a key
+///
+/// This is the conventional check value: the first 3 bytes of the
+/// encryption of a block of zeros with the key, displayed as 6
+/// uppercase hexadecimal digits. It reveals a little about the key,
+/// so it's intended for confirming keys during key ceremonies rather
+/// than for logging. Use the [`Fingerprint`] for the latter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCheckValue([u8; 3]);
+
+impl KeyCheckValue {
+    pub(crate) fn from_bytes(b: [u8; 3]) -> Self {
+        KeyCheckValue(b)
+    }
+
+    /// The bytes of the check value
+    pub fn as_bytes(&self) -> &[u8; 3] {
+        &self.0
+    }
+}
+
+impl fmt::Display for KeyCheckValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02X}", b))
+    }
+}
+
+/// A fingerprint of a key, suitable for logging
+///
+/// This is the first 16 bytes of a SHA-256 hash of the key (with a
+/// prefix specific to this purpose), displayed as 32 lowercase
+/// hexadecimal digits. It can't be reversed to recover the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint([u8; 16]);
+
+impl Fingerprint {
+    /// The bytes of the fingerprint
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretKey({} bytes)", self.0.len())
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl From<Vec<u8>> for SecretKey {
+    fn from(v: Vec<u8>) -> Self {
+        SecretKey(Zeroizing::new(v))
+    }
+}
+
+impl From<Zeroizing<Vec<u8>>> for SecretKey {
+    fn from(v: Zeroizing<Vec<u8>>) -> Self {
+        SecretKey(v)
+    }
+}
+
+impl From<&[u8]> for SecretKey {
+    fn from(v: &[u8]) -> Self {
+        SecretKey::new(v)
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for SecretKey {
+    fn from(v: &[u8; N]) -> Self {
+        SecretKey::new(v)
+    }
+}
+
+impl From<&Vec<u8>> for SecretKey {
+    fn from(v: &Vec<u8>) -> Self {
+        SecretKey::new(v)
+    }
+}
+
+impl From<&SecretKey> for SecretKey {
+    fn from(k: &SecretKey) -> Self {
+        k.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretKey;
+    use crate::error::ErrorKind;
+    use crate::kdf::KeySize;
+
+    #[test]
+    fn redacted() {
+        let k = SecretKey::new(b"0123456789abcdef");
+        assert!(format!("{:?}", k) == "SecretKey(16 bytes)");
+        assert!(!format!("{:?}", Some(&k)).contains("0123"));
+    }
+
+    #[test]
+    fn equality() {
+        let k = SecretKey::from(vec![1, 2, 3]);
+        assert!(k == SecretKey::new(&[1, 2, 3]));
+        assert!(k != SecretKey::new(&[1, 2, 4]));
+        assert!(k != SecretKey::new(&[1, 2]));
+        assert!(k.expose_secret() == [1, 2, 3]);
+    }
+
+    #[test]
+    fn check_value() -> crate::result::Result<()> {
+        // the encryption of a zero block with this key is given
+        // as the value of L in the examples of RFC 4493
+        let k = SecretKey::new(&[
+            0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15,
+            0x88, 0x09, 0xcf, 0x4f, 0x3c,
+        ]);
+        assert!(k.check_value()?.to_string() == "7DF76B");
+        assert!(SecretKey::new(&[0; 15]).check_value().is_err());
+
+        let fp = k.fingerprint();
+        assert!(fp.to_string().len() == 32);
+        assert!(fp == k.clone().fingerprint());
+        assert!(fp != SecretKey::new(&[0; 16]).fingerprint());
+
+        Ok(())
+    }
+
+    #[test]
+    fn encodings() -> crate::result::Result<()> {
+        for size in [KeySize::Aes128, KeySize::Aes192, KeySize::Aes256] {
+            let k = SecretKey::generate(size)?;
+            assert!(k.len() == size.bytes());
+            assert!(k != SecretKey::generate(size)?);
+
+            assert!(SecretKey::from_hex(&k.to_hex())? == k);
+            assert!(SecretKey::from_hex(&k.to_hex().to_uppercase())? == k);
+            assert!(SecretKey::from_base64(&k.to_base64())? == k);
+            assert!(SecretKey::from_jwk(&k.to_jwk())? == k);
+        }
+
+        // the 128-bit key of RFC 7517, appendix A.3
+        let k = SecretKey::from_jwk(
+            r#"{"kty":"oct","alg":"A128KW","k":"GawgguFyGrWKav7AX4VKUg"}"#,
+        )?;
+        assert!(k.to_hex().as_str() == "19ac2082e1721ab58a6afec05f854a52");
+
+        for res in [
+            SecretKey::from_hex("00112233445566778899aabbccddee"),
+            SecretKey::from_base64("AAAA"),
+            SecretKey::from_jwk(r#"{"kty":"oct","k":"AAAA"}"#),
+        ] {
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::InvalidKey { .. }
+            ));
+        }
+        for res in [
+            SecretKey::from_hex("0g112233445566778899aabbccddeeff"),
+            SecretKey::from_hex("0"),
+            SecretKey::from_base64("GawgguFyGrWKav7AX4VKUg"),
+            SecretKey::from_jwk(
+                r#"{"kty":"RSA","k":"GawgguFyGrWKav7AX4VKUg"}"#,
+            ),
+            SecretKey::from_jwk(r#"{"kty":"oct"}"#),
+        ] {
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::InvalidKeyEncoding { .. }
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,454 @@
+//! Versioned keys with the key version embedded in the ciphertext
+//!
+//! Format-preserving ciphertext has no room for a header, so when a
+//! key is rotated, there is no way to tell which key was used to
+//! produce a given ciphertext. A [`Keyring`] solves this by holding a
+//! context for each version of the key and inserting a letter that
+//! identifies the version into the ciphertext. The letter is the one
+//! at the position of the version number in the alphabet, so each
+//! ciphertext is one letter longer than its plaintext.
+//!
+//! Encryption always uses the active version, while decryption uses
+//! whichever version is identified by the ciphertext.
+//!
+//! # Example
+//! ```rust
+//! use fpe::ff1::FF1;
+//! use fpe::keyring::{Keyring, VersionPosition};
+//!
+//! let v1 = FF1::new(&[1; 16], None, 0, 0, 10, None).unwrap();
+//! let v2 = FF1::new(&[2; 16], None, 0, 0, 10, None).unwrap();
+//!
+//! let mut kr = Keyring::new(1, v1, VersionPosition::Leading).unwrap();
+//! let old = kr.encrypt("123456789", None).unwrap();
+//! assert!(old.starts_with('1'));
+//!
+//! kr.add(2, v2).unwrap();
+//! kr.activate(2).unwrap();
+//! let new = kr.encrypt("123456789", None).unwrap();
+//! assert!(new.starts_with('2'));
+//!
+//! assert!(kr.decrypt(&old, None).unwrap() == "123456789");
+//! assert!(kr.decrypt(&new, None).unwrap() == "123456789");
+//! ```
+
+use crate::alphabet::Alphabet;
+use crate::cipher::FormatPreservingCipher;
+use crate::error::{Error, ErrorKind};
+use crate::ffx::FFX;
+use crate::result::Result;
+
+use std::collections::BTreeMap;
+
+use zeroize::Zeroizing;
+
+/// Where the key version is placed within the ciphertext
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionPosition {
+    /// The first letter of the ciphertext
+    Leading,
+    /// The last letter of the ciphertext
+    Trailing,
+    /// The letter at the given index (in letters) of the ciphertext
+    Index(usize),
+}
+
+type Context = Box<dyn FormatPreservingCipher + Send + Sync>;
+
+/// A set of contexts, one per key version
+///
+/// All of the contexts must use the same alphabet and input options,
+/// since the letter identifying the version must be found before the
+/// version is known. The version numbers must be less than the radix
+/// so that each can be written as a letter of the alphabet. Other
+/// parameters, such as the algorithm and the limits on the lengths of
+/// the text and tweak, may differ: the remainder of the ciphertext is
+/// decrypted by the context of the version that it identifies.
+pub struct Keyring {
+    contexts: BTreeMap<usize, Context>,
+    active: usize,
+    pos: VersionPosition,
+}
+
+impl Keyring {
+    /// Create a keyring containing a single, active version
+    pub fn new(
+        version: usize,
+        ctx: impl FormatPreservingCipher + Send + Sync + 'static,
+        pos: VersionPosition,
+    ) -> Result<Self> {
+        let mut kr = Keyring {
+            contexts: BTreeMap::new(),
+            active: version,
+            pos,
+        };
+        kr.add(version, ctx)?;
+        Ok(kr)
+    }
+
+    /// Add a version to the keyring
+    ///
+    /// The new version is not made active. See [`Keyring::activate`].
+    pub fn add(
+        &mut self,
+        version: usize,
+        ctx: impl FormatPreservingCipher + Send + Sync + 'static,
+    ) -> Result<()> {
+        if version >= ctx.radix() {
+            return Err(Error::new(ErrorKind::InvalidKeyVersion { version }));
+        }
+        if self.contexts.contains_key(&version) {
+            return Err(Error::new(ErrorKind::DuplicateKeyVersion { version }));
+        }
+        if let Some(other) = self.contexts.values().next() {
+            if !same_alphabet(other.alphabet(), ctx.alphabet()) {
+                return Err(Error::new(ErrorKind::IncompatibleAlphabet));
+            }
+            if other.ffx().get_input_options() != ctx.ffx().get_input_options()
+            {
+                return Err(Error::new(ErrorKind::IncompatibleInputOptions));
+            }
+        }
+
+        self.contexts.insert(version, Box::new(ctx));
+        Ok(())
+    }
+
+    /// Select the version used for encryption
+    pub fn activate(&mut self, version: usize) -> Result<()> {
+        self.context(version)?;
+        self.active = version;
+        Ok(())
+    }
+
+    /// The version used for encryption
+    pub fn active_version(&self) -> usize {
+        self.active
+    }
+
+    /// The versions in the keyring, in ascending order
+    pub fn versions(&self) -> impl Iterator<Item = usize> + '_ {
+        self.contexts.keys().copied()
+    }
+
+    /// The context for the given version
+    pub fn context(
+        &self,
+        version: usize,
+    ) -> Result<&(dyn FormatPreservingCipher + Send + Sync)> {
+        self.contexts
+            .get(&version)
+            .map(|c| c.as_ref())
+            .ok_or(Error::new(ErrorKind::UnknownKeyVersion { version }))
+    }
+
+    /// Encrypt a string with the active version
+    ///
+    /// The letter identifying the version is inserted into the
+    /// ciphertext at the position configured for the keyring.
+    pub fn encrypt(&self, pt: &str, twk: Option<&[u8]>) -> Result<String> {
+        let ctx = self.context(self.active)?;
+        let ct = ctx.encrypt(pt, twk)?;
+
+        let bounds = self.letter_bounds(&ct);
+        let n = bounds.len() - 1;
+        let i = self
+            .index(n + 1)
+            .ok_or_else(|| self.length_error(n, 0, [self.active]))?;
+
+        let mut out = String::with_capacity(ct.len() + 4);
+        out.push_str(&ct[..bounds[i]]);
+        ctx.alphabet().write_letter(self.active, &mut out);
+        out.push_str(&ct[bounds[i]..]);
+
+        Ok(out)
+    }
+
+    /// Decrypt a string with the version identified by the ciphertext
+    pub fn decrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
+        let (version, ct) = self.split(ct)?;
+        self.context(version)?.decrypt(&ct, twk)
+    }
+
+    /// Re-encrypt a string with the active version
+    ///
+    /// The string is decrypted with the version identified by the
+    /// ciphertext and encrypted with the active version, as by
+    /// [`crate::cipher::reencrypt`]. A ciphertext that was produced by
+    /// the active version is returned unchanged.
+    pub fn reencrypt(&self, ct: &str, twk: Option<&[u8]>) -> Result<String> {
+        if self.version_of(ct)? == self.active {
+            return Ok(ct.to_string());
+        }
+
+        let pt = Zeroizing::new(self.decrypt(ct, twk)?);
+        self.encrypt(&pt, twk)
+    }
+
+    /// The version identified by the ciphertext
+    pub fn version_of(&self, ct: &str) -> Result<usize> {
+        self.split(ct).map(|(version, _)| version)
+    }
+
+    // separate the version from the rest of the ciphertext. the version
+    // letter is read as the contexts would read it, i.e. subject to the
+    // input options, which are the same for every context. the rest of
+    // the ciphertext is left to the context of the version
+    fn split(&self, ct: &str) -> Result<(usize, String)> {
+        let ffx = self.ffx();
+        let ct = ffx.normalize(ct);
+        let bounds = self.letter_bounds(&ct);
+        let n = bounds.len() - 1;
+        // the version isn't known yet, so any of them could apply
+        let i = self
+            .index(n)
+            .ok_or_else(|| self.length_error(n, 1, self.versions()))?;
+
+        let ltr = &ct[bounds[i]..bounds[i + 1]];
+        let version = match ffx.text_to_digits(ltr) {
+            Ok((d, _)) if d.len() == 1 => d[0] as usize,
+            _ => {
+                return Err(Error::new(ErrorKind::InvalidCharacter {
+                    ch: ltr.chars().next().unwrap(),
+                    position: ct[..bounds[i]].chars().count(),
+                }))
+            }
+        };
+
+        Ok((version, [&ct[..bounds[i]], &ct[bounds[i + 1]..]].concat()))
+    }
+
+    fn ffx(&self) -> &FFX {
+        self.contexts[&self.active].ffx()
+    }
+
+    // the byte offsets at which each letter of the (normalized) text
+    // begins, followed by the length of the text. characters that
+    // aren't part of a letter are counted as letters so that they're
+    // reported by the context
+    fn letter_bounds(&self, s: &str) -> Vec<usize> {
+        let offs: Vec<usize> = s.char_indices().map(|(b, _)| b).collect();
+
+        let mut bounds = Vec::with_capacity(offs.len() + 1);
+        self.ffx().scan(s, |_, i, _| {
+            bounds.push(offs[i]);
+            true
+        });
+        bounds.push(s.len());
+
+        bounds
+    }
+
+    // the index of the version letter in a ciphertext of n letters
+    fn index(&self, n: usize) -> Option<usize> {
+        let i = match self.pos {
+            VersionPosition::Leading => 0,
+            VersionPosition::Trailing => n.checked_sub(1)?,
+            VersionPosition::Index(i) => i,
+        };
+
+        (i < n).then_some(i)
+    }
+
+    // the error for text too short to hold the version letter, where
+    // `extra` is the number of letters already given to the version.
+    // the limits are the widest of those of the given versions
+    fn length_error(
+        &self,
+        n: usize,
+        extra: usize,
+        versions: impl IntoIterator<Item = usize>,
+    ) -> Error {
+        let (min, max) = versions
+            .into_iter()
+            .map(|v| &self.contexts[&v])
+            .map(|c| (c.min_len(), c.max_len()))
+            .reduce(|(a, b), (c, d)| (a.min(c), b.max(d)))
+            .unwrap_or_default();
+        let min = match self.pos {
+            VersionPosition::Index(i) => std::cmp::max(min, i),
+            _ => min,
+        };
+
+        Error::new(ErrorKind::InvalidTextLength {
+            min: min + extra,
+            max: max + extra,
+            actual: n,
+        })
+    }
+}
+
+fn same_alphabet(a: &dyn Alphabet, b: &dyn Alphabet) -> bool {
+    let (mut x, mut y) = (String::new(), String::new());
+
+    a.len() == b.len()
+        && (0..a.len()).all(|i| {
+            x.clear();
+            y.clear();
+            a.write_letter(i, &mut x) && b.write_letter(i, &mut y) && x == y
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Keyring, VersionPosition};
+    use crate::alphabet::input::{CaseMatching, InputOptions};
+    use crate::error::ErrorKind;
+    use crate::ff1::FF1;
+    use crate::ff3_1::FF3_1;
+    use crate::result::Result;
+
+    #[test]
+    fn positions() -> Result<()> {
+        for (pos, idx) in [
+            (VersionPosition::Leading, 0),
+            (VersionPosition::Trailing, 10),
+            (VersionPosition::Index(3), 3),
+        ] {
+            let ctx = FF1::new(&[0; 16], None, 0, 0, 10, None)?;
+            let kr = Keyring::new(7, ctx, pos)?;
+
+            let ct = kr.encrypt("0123456789", None)?;
+            assert!(ct.len() == 11);
+            assert!(ct.chars().nth(idx) == Some('7'));
+            assert!(kr.version_of(&ct)? == 7);
+            assert!(kr.decrypt(&ct, None)? == "0123456789");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn versions() -> Result<()> {
+        let mut kr = Keyring::new(
+            0,
+            FF1::new(&[0; 16], None, 0, 0, 10, None)?,
+            VersionPosition::Leading,
+        )?;
+
+        let res = kr.add(10, FF1::new(&[1; 16], None, 0, 0, 10, None)?);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidKeyVersion { version: 10 }
+        ));
+
+        let res = kr.add(0, FF1::new(&[1; 16], None, 0, 0, 10, None)?);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateKeyVersion { version: 0 }
+        ));
+
+        let res = kr.add(1, FF1::new(&[1; 16], None, 0, 0, 16, None)?);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::IncompatibleAlphabet
+        ));
+
+        // versions may use different algorithms
+        kr.add(1, FF3_1::new(&[1; 16], Some(&[0; 7]), 10, None)?)?;
+        let res = kr.activate(2);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::UnknownKeyVersion { version: 2 }
+        ));
+        assert!(kr.active_version() == 0);
+
+        let old = kr.encrypt("0123456789", None)?;
+        kr.activate(1)?;
+        let new = kr.encrypt("0123456789", None)?;
+        assert!(kr.versions().collect::<Vec<_>>() == [0, 1]);
+        assert!(kr.decrypt(&old, None)? == "0123456789");
+        assert!(kr.decrypt(&new, None)? == "0123456789");
+
+        assert!(kr.reencrypt(&old, None)? == new);
+        assert!(kr.reencrypt(&new, None)? == new);
+
+        let res = kr.decrypt(&["5", &new[1..]].concat(), None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::UnknownKeyVersion { version: 5 }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn lengths() -> Result<()> {
+        let mut kr = Keyring::new(
+            0,
+            FF3_1::new(&[0; 16], Some(&[0; 7]), 10, None)?,
+            VersionPosition::Index(8),
+        )?;
+        kr.add(1, FF1::new(&[1; 16], None, 0, 0, 10, None)?)?;
+
+        // each version decrypts according to its own limits,
+        // regardless of which version is active
+        let pt = "0".repeat(60);
+        kr.activate(1)?;
+        let ct = kr.encrypt(&pt, None)?;
+        kr.activate(0)?;
+        assert!(kr.decrypt(&ct, None)? == pt);
+        assert!(kr.encrypt(&pt, None).is_err());
+
+        // the version of a ciphertext that is too short can't be
+        // known, so the limits are those of every version
+        let res = kr.decrypt("0123", None);
+        let max = ErrorKind::InvalidTextLength {
+            min: 9,
+            max: u32::MAX as usize + 1,
+            actual: 4,
+        };
+        assert!(*res.err().unwrap().kind() == max);
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_options() -> Result<()> {
+        for case in [CaseMatching::Insensitive, CaseMatching::Preserve] {
+            let ctx = FF1::builder()
+                .key(&[0; 16])
+                .alphabet(crate::alphabet::HEX_LOWER)
+                .input_options(InputOptions::new().case(case))
+                .build()?;
+            let kr = Keyring::new(12, ctx, VersionPosition::Leading)?;
+
+            // the version letter is matched as the context
+            // would match any other letter of the ciphertext
+            let ct = kr.encrypt("0123456789", None)?;
+            assert!(ct.starts_with('c'));
+            let upper = ct.to_uppercase();
+            assert!(kr.version_of(&upper)? == 12);
+            assert!(kr
+                .decrypt(&upper, None)?
+                .eq_ignore_ascii_case("0123456789"));
+        }
+
+        // a version that reads letters differently would
+        // identify versions differently
+        let mut kr = Keyring::new(
+            0,
+            FF1::builder()
+                .key(&[0; 16])
+                .alphabet(crate::alphabet::HEX_LOWER)
+                .input_options(
+                    InputOptions::new().case(CaseMatching::Insensitive),
+                )
+                .build()?,
+            VersionPosition::Leading,
+        )?;
+        let res = kr.add(
+            1,
+            FF1::builder()
+                .key(&[1; 16])
+                .alphabet(crate::alphabet::HEX_LOWER)
+                .build()?,
+        );
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::IncompatibleInputOptions
+        ));
+
+        Ok(())
+    }
+}
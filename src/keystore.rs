@@ -0,0 +1,996 @@
+//! Password-protected storage of keys
+//!
+//! A [`Keystore`] holds any number of named keys along with the
+//! parameters needed to create a context from each of them: the
+//! algorithm, radix, and alphabet. It is saved as a JSON file in
+//! which each key is encrypted with AES-256-GCM under a key derived
+//! from a passphrase via Argon2id or PBKDF2-HMAC-SHA256. The metadata
+//! of each key is stored in the clear but is authenticated along with
+//! the key, so that it can't be altered without detection.
+//!
+//! The file as a whole, i.e. the parameters of the key derivation and
+//! the complete set of keys, is also authenticated, so that removing a
+//! key, or replacing one with an older copy of itself, is detected
+//! when the keystore is loaded. Replacing the entire file with an
+//! older version of itself can't be detected.
+//!
+//! A keystore is also a [`KeyProvider`], so contexts may be built from
+//! its keys via [`provider_key`] of the FF1 and FF3-1 builders.
+//!
+//! [`provider_key`]: crate::ff1::Builder::provider_key
+//!
+//! # Example
+//! ```rust
+//! use fpe::cipher::Algorithm;
+//! use fpe::keystore::{Keystore, PassphraseKdf};
+//!
+//! let path = std::env::temp_dir().join("fpe-keystore-example.json");
+//!
+//! let mut ks = Keystore::new("passphrase", PassphraseKdf::default()).unwrap();
+//! ks.insert("ssn", &[0; 16], Algorithm::FF1, 10, None).unwrap();
+//! ks.save(&path).unwrap();
+//!
+//! let ks = Keystore::load(&path, "passphrase").unwrap();
+//! std::fs::remove_file(&path).unwrap();
+//!
+//! let ctx = ks.context("ssn").unwrap();
+//! let ct = ctx.encrypt("123456789", None).unwrap();
+//! assert!(ctx.decrypt(&ct, None).unwrap() == "123456789");
+//! ```
+
+use crate::alphabet::{Alphabet, CharAlphabet, IntoAlphabet, SymbolAlphabet};
+use crate::cipher::{Algorithm, FormatPreservingCipher};
+use crate::error::{Error, ErrorKind};
+use crate::ff1::FF1;
+use crate::ff3_1::FF3_1;
+use crate::kdf;
+use crate::key::{self, SecretKey};
+use crate::provider::{self, KeyMetadata, KeyProvider};
+use crate::result::Result;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::Engine;
+use byteorder::ByteOrder;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// The function used to derive the key that encrypts the keystore
+///
+/// The costs are limited to [`MAX_ARGON2_MEMORY`], [`MAX_ITERATIONS`],
+/// and [`MAX_PARALLELISM`], so that a keystore file can't cause its
+/// reader to exhaust its memory or to spend an unreasonable amount of
+/// time deriving the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassphraseKdf {
+    /// Argon2id, with the memory cost given in KiB
+    Argon2id {
+        memory: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+    /// PBKDF2 with HMAC-SHA256
+    Pbkdf2Sha256 { iterations: u32 },
+}
+
+/// The largest memory cost of Argon2id, in KiB (1 GiB)
+pub const MAX_ARGON2_MEMORY: u32 = 1 << 20;
+/// The largest number of iterations of either function. For PBKDF2,
+/// this is about 16 times the number recommended by OWASP
+pub const MAX_ITERATIONS: u32 = 10_000_000;
+/// The largest parallelism of Argon2id
+pub const MAX_PARALLELISM: u32 = 64;
+
+impl Default for PassphraseKdf {
+    /// Argon2id with 19 MiB of memory, 2 iterations, and a parallelism
+    /// of 1, as recommended by OWASP
+    fn default() -> Self {
+        PassphraseKdf::Argon2id {
+            memory: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Information about a key held in a keystore
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryMetadata {
+    /// The name of the key
+    pub name: String,
+    /// The algorithm with which the key is used
+    pub algorithm: Algorithm,
+    /// The radix of the context
+    pub radix: usize,
+    /// The letters of the alphabet of the context
+    pub alphabet: Vec<String>,
+    /// When the key was added, in seconds since the Unix epoch
+    pub created: Option<u64>,
+}
+
+struct Entry {
+    md: EntryMetadata,
+    key: SecretKey,
+}
+
+/// A set of named keys protected by a passphrase
+///
+/// The keys are held in memory in the clear (but wiped when dropped)
+/// and are only encrypted when the keystore is saved.
+pub struct Keystore {
+    kdf: PassphraseKdf,
+    salt: [u8; SALT_LEN],
+    kek: Zeroizing<[u8; 32]>,
+    entries: BTreeMap<String, Entry>,
+}
+
+impl Keystore {
+    /// Create an empty keystore protected by the given passphrase
+    pub fn new(
+        passphrase: impl AsRef<[u8]>,
+        kdf: PassphraseKdf,
+    ) -> Result<Self> {
+        let mut salt = [0; SALT_LEN];
+        key::fill_random(&mut salt)?;
+        let kek = derive_kek(passphrase.as_ref(), &kdf, &salt)?;
+
+        Ok(Keystore {
+            kdf,
+            salt,
+            kek,
+            entries: BTreeMap::new(),
+        })
+    }
+
+    /// Read a keystore from a file and decrypt its keys
+    pub fn load(
+        path: impl AsRef<Path>,
+        passphrase: impl AsRef<[u8]>,
+    ) -> Result<Self> {
+        let b64 = base64::engine::general_purpose::STANDARD;
+
+        let data = std::fs::read(path).map_err(keystore_error)?;
+        let file: KeystoreFile =
+            serde_json::from_slice(&data).map_err(keystore_error)?;
+        if file.format != KEYSTORE_FORMAT {
+            return Err(keystore_error(format!(
+                "unsupported keystore format: {}",
+                file.format
+            )));
+        }
+
+        let (kdf, salt) = file.kdf.decode()?;
+        let kek = derive_kek(passphrase.as_ref(), &kdf, &salt)?;
+
+        // the file is authenticated as a whole before any of its keys
+        // are decrypted. the keys are authenticated in order of their
+        // names, as they are written, regardless of their order here
+        let tag = b64.decode(&file.mac).map_err(keystore_error)?;
+        file_mac(&kek, &file.kdf, &file.keys)?
+            .verify_slice(&tag)
+            .map_err(|_| keystore_error("authentication failed"))?;
+
+        let cipher = Aes256Gcm::new(kek.as_slice().into());
+
+        let mut entries = BTreeMap::new();
+        for k in &file.keys {
+            let nonce = b64.decode(&k.nonce).map_err(keystore_error)?;
+            if nonce.len() != 12 {
+                return Err(keystore_error("invalid nonce"));
+            }
+            let ct = b64.decode(&k.ciphertext).map_err(keystore_error)?;
+            let key = SecretKey::from(Zeroizing::new(
+                cipher
+                    .decrypt(
+                        Nonce::from_slice(&nonce),
+                        Payload {
+                            msg: &ct,
+                            aad: &k.metadata.aad()?,
+                        },
+                    )
+                    .map_err(|_| {
+                        keystore_error(format!(
+                            "authentication failed for key: {}",
+                            k.metadata.name
+                        ))
+                    })?,
+            ));
+
+            let md = k.metadata.decode()?;
+            if entries.contains_key(&md.name) {
+                return Err(Error::new(ErrorKind::DuplicateKey {
+                    name: md.name,
+                }));
+            }
+            entries.insert(md.name.clone(), Entry { md, key });
+        }
+
+        Ok(Keystore {
+            kdf,
+            salt,
+            kek,
+            entries,
+        })
+    }
+
+    /// Encrypt the keys and write the keystore to a file
+    ///
+    /// The keystore is written to a temporary file alongside the
+    /// destination, which then replaces any existing file at the
+    /// path, so that a failure can't leave a partially written file.
+    /// On Unix, the file is only readable and writable by its owner.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let b64 = base64::engine::general_purpose::STANDARD;
+        let cipher = Aes256Gcm::new(self.kek.as_slice().into());
+
+        let mut keys = Vec::with_capacity(self.entries.len());
+        for e in self.entries.values() {
+            let metadata = FileMetadata::encode(&e.md);
+
+            let mut nonce = [0; 12];
+            key::fill_random(&mut nonce)?;
+            let ct = cipher
+                .encrypt(
+                    Nonce::from_slice(&nonce),
+                    Payload {
+                        msg: e.key.expose_secret(),
+                        aad: &metadata.aad()?,
+                    },
+                )
+                .map_err(keystore_error)?;
+
+            keys.push(FileKey {
+                metadata,
+                nonce: b64.encode(nonce),
+                ciphertext: b64.encode(ct),
+            });
+        }
+
+        let kdf = FileKdf::encode(&self.kdf, &self.salt);
+        let mac = file_mac(&self.kek, &kdf, &keys)?.finalize().into_bytes();
+
+        let file = KeystoreFile {
+            format: KEYSTORE_FORMAT,
+            kdf,
+            keys,
+            mac: b64.encode(mac),
+        };
+        let data = serde_json::to_vec_pretty(&file).map_err(keystore_error)?;
+
+        provider::write_secret_file(path.as_ref(), &data)
+            .map_err(keystore_error)
+    }
+
+    /// Change the passphrase (and key derivation) of the keystore
+    ///
+    /// The change takes effect when the keystore is next saved.
+    pub fn set_passphrase(
+        &mut self,
+        passphrase: impl AsRef<[u8]>,
+        kdf: PassphraseKdf,
+    ) -> Result<()> {
+        let mut salt = [0; SALT_LEN];
+        key::fill_random(&mut salt)?;
+        self.kek = derive_kek(passphrase.as_ref(), &kdf, &salt)?;
+        self.kdf = kdf;
+        self.salt = salt;
+        Ok(())
+    }
+
+    /// Add a key to the keystore
+    ///
+    /// The parameters are those with which contexts are created from
+    /// the key and are checked by creating one. Only the letters of
+    /// the alphabet are stored, so any other properties of the
+    /// alphabet, such as [`CharAlphabet::constant_time`], must be
+    /// reapplied by the caller.
+    pub fn insert(
+        &mut self,
+        name: &str,
+        key: impl Into<SecretKey>,
+        algorithm: Algorithm,
+        radix: usize,
+        alpha: impl IntoAlphabet,
+    ) -> Result<()> {
+        if self.entries.contains_key(name) {
+            return Err(Error::new(ErrorKind::DuplicateKey {
+                name: name.to_string(),
+            }));
+        }
+
+        let key = key.into();
+        let alpha = alpha.into_alphabet()?;
+        let ctx = create_context(&key, algorithm, radix, alpha)?;
+
+        let letters = (0..ctx.radix())
+            .map(|i| {
+                let mut s = String::new();
+                ctx.alphabet().write_letter(i, &mut s);
+                s
+            })
+            .collect();
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+
+        self.entries.insert(
+            name.to_string(),
+            Entry {
+                md: EntryMetadata {
+                    name: name.to_string(),
+                    algorithm,
+                    radix,
+                    alphabet: letters,
+                    created,
+                },
+                key,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a key from the keystore
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        self.entries
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| unknown_key(name))
+    }
+
+    /// The names of the keys in the keystore, in ascending order
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Information about a key
+    pub fn metadata(&self, name: &str) -> Result<&EntryMetadata> {
+        self.entry(name).map(|e| &e.md)
+    }
+
+    /// Create a context from a key
+    pub fn context(
+        &self,
+        name: &str,
+    ) -> Result<Box<dyn FormatPreservingCipher + Send + Sync>> {
+        let e = self.entry(name)?;
+        create_context(
+            &e.key,
+            e.md.algorithm,
+            e.md.radix,
+            alphabet_of(&e.md.alphabet)?,
+        )
+    }
+
+    /// Create an FF1 context from a key intended for FF1
+    pub fn ff1(&self, name: &str) -> Result<FF1> {
+        let e = self.entry_for(name, Algorithm::FF1)?;
+        FF1::builder()
+            .key(&e.key)
+            .radix(e.md.radix)
+            .alphabet(alphabet_of(&e.md.alphabet)?)
+            .build()
+    }
+
+    /// Create an FF3-1 context from a key intended for FF3-1
+    pub fn ff3_1(&self, name: &str) -> Result<FF3_1> {
+        let e = self.entry_for(name, Algorithm::FF3_1)?;
+        FF3_1::builder()
+            .key(&e.key)
+            .radix(e.md.radix)
+            .alphabet(alphabet_of(&e.md.alphabet)?)
+            .build()
+    }
+
+    fn entry(&self, name: &str) -> Result<&Entry> {
+        self.entries.get(name).ok_or_else(|| unknown_key(name))
+    }
+
+    fn entry_for(&self, name: &str, algorithm: Algorithm) -> Result<&Entry> {
+        let e = self.entry(name)?;
+        if e.md.algorithm != algorithm {
+            return Err(keystore_error(format!(
+                "key {} is for {}, not {}",
+                name, e.md.algorithm, algorithm
+            )));
+        }
+        Ok(e)
+    }
+
+    // the keys aren't versioned, so each is the only version of itself
+    fn versioned_entry(
+        &self,
+        id: &str,
+        version: Option<u32>,
+    ) -> Result<&Entry> {
+        match version {
+            None | Some(KEY_VERSION) => self.entry(id),
+            Some(_) => Err(Error::new(ErrorKind::UnknownKey {
+                id: id.to_string(),
+                version,
+            })),
+        }
+    }
+}
+
+// the version of every key, when the keystore is used as a provider
+const KEY_VERSION: u32 = 1;
+
+/// The keys of a keystore are not versioned; each is provided as
+/// version 1 and is restricted to the algorithm given when it was
+/// inserted.
+impl KeyProvider for Keystore {
+    fn fetch(&self, id: &str, version: Option<u32>) -> Result<SecretKey> {
+        self.versioned_entry(id, version).map(|e| e.key.clone())
+    }
+
+    fn versions(&self, id: &str) -> Result<Vec<u32>> {
+        self.entry(id).map(|_| vec![KEY_VERSION])
+    }
+
+    fn metadata(&self, id: &str, version: Option<u32>) -> Result<KeyMetadata> {
+        self.versioned_entry(id, version).map(|e| KeyMetadata {
+            id: id.to_string(),
+            version: KEY_VERSION,
+            algorithm: Some(e.md.algorithm),
+            created: e.md.created,
+            length: e.key.len(),
+        })
+    }
+}
+
+fn create_context(
+    key: &SecretKey,
+    algorithm: Algorithm,
+    radix: usize,
+    alpha: Arc<dyn Alphabet>,
+) -> Result<Box<dyn FormatPreservingCipher + Send + Sync>> {
+    Ok(match algorithm {
+        Algorithm::FF1 => Box::new(
+            FF1::builder()
+                .key(key)
+                .radix(radix)
+                .alphabet(alpha)
+                .build()?,
+        ),
+        Algorithm::FF3_1 => Box::new(
+            FF3_1::builder()
+                .key(key)
+                .radix(radix)
+                .alphabet(alpha)
+                .build()?,
+        ),
+    })
+}
+
+// rebuilds an alphabet from its letters
+fn alphabet_of(letters: &[String]) -> Result<Arc<dyn Alphabet>> {
+    if letters.iter().all(|l| l.chars().count() == 1) {
+        CharAlphabet::new(&letters.concat())?.into_alphabet()
+    } else {
+        SymbolAlphabet::new(letters)?.into_alphabet()
+    }
+}
+
+fn unknown_key(name: &str) -> Error {
+    Error::new(ErrorKind::UnknownKey {
+        id: name.to_string(),
+        version: None,
+    })
+}
+
+fn keystore_error(reason: impl ToString) -> Error {
+    Error::new(ErrorKind::Keystore {
+        reason: reason.to_string(),
+    })
+}
+
+const SALT_LEN: usize = 16;
+
+fn derive_kek(
+    passphrase: &[u8],
+    kdf: &PassphraseKdf,
+    salt: &[u8],
+) -> Result<Zeroizing<[u8; 32]>> {
+    let mut kek = Zeroizing::new([0; 32]);
+
+    // the parameters may have been read from a file and
+    // are checked before any resources are committed
+    let (memory, iterations, parallelism) = match *kdf {
+        PassphraseKdf::Argon2id {
+            memory,
+            iterations,
+            parallelism,
+        } => (memory, iterations, parallelism),
+        PassphraseKdf::Pbkdf2Sha256 { iterations } => (0, iterations, 1),
+    };
+    if memory > MAX_ARGON2_MEMORY {
+        return Err(keystore_error(format!(
+            "memory cost exceeds the maximum of {} KiB",
+            MAX_ARGON2_MEMORY
+        )));
+    }
+    if iterations > MAX_ITERATIONS {
+        return Err(keystore_error(format!(
+            "iteration count exceeds the maximum of {}",
+            MAX_ITERATIONS
+        )));
+    }
+    if parallelism > MAX_PARALLELISM {
+        return Err(keystore_error(format!(
+            "parallelism exceeds the maximum of {}",
+            MAX_PARALLELISM
+        )));
+    }
+
+    match *kdf {
+        PassphraseKdf::Argon2id {
+            memory,
+            iterations,
+            parallelism,
+        } => {
+            let params =
+                argon2::Params::new(memory, iterations, parallelism, None)
+                    .map_err(keystore_error)?;
+            argon2::Argon2::new(
+                argon2::Algorithm::Argon2id,
+                argon2::Version::V0x13,
+                params,
+            )
+            .hash_password_into(passphrase, salt, kek.as_mut_slice())
+            .map_err(keystore_error)?;
+        }
+        PassphraseKdf::Pbkdf2Sha256 { iterations } => {
+            if iterations == 0 {
+                return Err(keystore_error("invalid iteration count"));
+            }
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(
+                passphrase,
+                salt,
+                iterations,
+                kek.as_mut_slice(),
+            );
+        }
+    }
+
+    Ok(kek)
+}
+
+// identifies the format of keystores and is authenticated along with
+// each key so that the keys can't be used in another context
+const KEYSTORE_AAD: &[u8] = b"fpe-keystore-v1";
+// the info with which the key that authenticates the file as a whole
+// is derived from the kek, so that neither key serves two purposes
+const KEYSTORE_MAC_INFO: &[u8] = b"fpe-keystore-v2-mac";
+const KEYSTORE_FORMAT: u32 = 2;
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    format: u32,
+    kdf: FileKdf,
+    keys: Vec<FileKey>,
+    // absent from files of earlier formats, which are
+    // rejected (by their format) before it is needed
+    #[serde(default)]
+    mac: String,
+}
+
+// the mac of the format, the key derivation parameters and salt, and
+// every key, sorted by name, along with its metadata. each field is
+// preceded by its length so that the fields can't run into each other
+fn file_mac(
+    kek: &[u8; 32],
+    kdf: &FileKdf,
+    keys: &[FileKey],
+) -> Result<Hmac<Sha256>> {
+    let mut mac_key = Zeroizing::new([0; 32]);
+    kdf::hkdf_sha256(None, kek, KEYSTORE_MAC_INFO, mac_key.as_mut_slice())?;
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(mac_key.as_slice())
+        .map_err(keystore_error)?;
+
+    let mut field = |data: &[u8]| {
+        let mut n = [0; 8];
+        byteorder::BigEndian::write_u64(&mut n, data.len() as u64);
+        mac.update(&n);
+        mac.update(data);
+    };
+
+    field(&KEYSTORE_FORMAT.to_be_bytes());
+    field(&serde_json::to_vec(kdf).map_err(keystore_error)?);
+
+    let mut sorted: Vec<&FileKey> = keys.iter().collect();
+    sorted.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    for k in sorted {
+        field(&k.metadata.aad()?);
+        field(k.nonce.as_bytes());
+        field(k.ciphertext.as_bytes());
+    }
+
+    Ok(mac)
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "algorithm", rename_all = "kebab-case")]
+enum FileKdf {
+    Argon2id {
+        salt: String,
+        memory: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+    Pbkdf2Sha256 {
+        salt: String,
+        iterations: u32,
+    },
+}
+
+impl FileKdf {
+    fn encode(kdf: &PassphraseKdf, salt: &[u8]) -> Self {
+        let salt = base64::engine::general_purpose::STANDARD.encode(salt);
+        match *kdf {
+            PassphraseKdf::Argon2id {
+                memory,
+                iterations,
+                parallelism,
+            } => FileKdf::Argon2id {
+                salt,
+                memory,
+                iterations,
+                parallelism,
+            },
+            PassphraseKdf::Pbkdf2Sha256 { iterations } => {
+                FileKdf::Pbkdf2Sha256 { salt, iterations }
+            }
+        }
+    }
+
+    fn decode(&self) -> Result<(PassphraseKdf, [u8; SALT_LEN])> {
+        let (kdf, salt) = match self {
+            FileKdf::Argon2id {
+                salt,
+                memory,
+                iterations,
+                parallelism,
+            } => (
+                PassphraseKdf::Argon2id {
+                    memory: *memory,
+                    iterations: *iterations,
+                    parallelism: *parallelism,
+                },
+                salt,
+            ),
+            FileKdf::Pbkdf2Sha256 { salt, iterations } => (
+                PassphraseKdf::Pbkdf2Sha256 {
+                    iterations: *iterations,
+                },
+                salt,
+            ),
+        };
+
+        let salt = base64::engine::general_purpose::STANDARD
+            .decode(salt)
+            .map_err(keystore_error)?
+            .try_into()
+            .map_err(|_| keystore_error("invalid salt"))?;
+        Ok((kdf, salt))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileKey {
+    #[serde(flatten)]
+    metadata: FileMetadata,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileMetadata {
+    name: String,
+    algorithm: String,
+    radix: usize,
+    alphabet: Vec<String>,
+    created: Option<u64>,
+}
+
+impl FileMetadata {
+    fn encode(md: &EntryMetadata) -> Self {
+        FileMetadata {
+            name: md.name.clone(),
+            algorithm: md.algorithm.name().to_string(),
+            radix: md.radix,
+            alphabet: md.alphabet.clone(),
+            created: md.created,
+        }
+    }
+
+    fn decode(&self) -> Result<EntryMetadata> {
+        let algorithm = match self.algorithm.as_str() {
+            "ff1" => Algorithm::FF1,
+            "ff3-1" => Algorithm::FF3_1,
+            a => {
+                return Err(keystore_error(format!("unknown algorithm: {}", a)))
+            }
+        };
+
+        Ok(EntryMetadata {
+            name: self.name.clone(),
+            algorithm,
+            radix: self.radix,
+            alphabet: self.alphabet.clone(),
+            created: self.created,
+        })
+    }
+
+    // the metadata, as authenticated with the key
+    fn aad(&self) -> Result<Vec<u8>> {
+        let md = serde_json::to_vec(self).map_err(keystore_error)?;
+        Ok([KEYSTORE_AAD, &md].concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Keystore, PassphraseKdf, MAX_ARGON2_MEMORY};
+    use crate::alphabet::SymbolAlphabet;
+    use crate::cipher::Algorithm;
+    use crate::error::ErrorKind;
+    use crate::ff1::FF1;
+    use crate::ff3_1::FF3_1;
+    use crate::provider::KeyProvider;
+    use crate::result::Result;
+
+    // cheap parameters so that the tests run quickly
+    const ARGON2: PassphraseKdf = PassphraseKdf::Argon2id {
+        memory: 64,
+        iterations: 1,
+        parallelism: 1,
+    };
+    const PBKDF2: PassphraseKdf =
+        PassphraseKdf::Pbkdf2Sha256 { iterations: 10 };
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "fpe-keystore-{}-{}.json",
+            name,
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn save_load() -> Result<()> {
+        for (name, kdf) in [("argon2", ARGON2), ("pbkdf2", PBKDF2)] {
+            let path = temp_path(name);
+
+            let mut ks = Keystore::new("secret", kdf)?;
+            ks.insert("a", &[1; 16], Algorithm::FF1, 10, None)?;
+            ks.insert("b", &[2; 32], Algorithm::FF3_1, 4, "ACGT")?;
+            let sym = SymbolAlphabet::new(&["ab", "c", "d"])?;
+            ks.insert("c", &[3; 24], Algorithm::FF1, 3, sym)?;
+            let ct =
+                ks.context("b")?.encrypt("GATTACAGATTACA", Some(&[0; 7]))?;
+
+            ks.save(&path)?;
+            let data = std::fs::read_to_string(&path).unwrap();
+            #[cfg(unix)]
+            let mode = {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::metadata(&path).unwrap().permissions().mode()
+            };
+            let loaded = Keystore::load(&path, "secret");
+            let wrong = Keystore::load(&path, "guess");
+            std::fs::remove_file(&path).unwrap();
+
+            // the metadata, but not the keys, are in the clear, and
+            // the file is only accessible to its owner
+            assert!(!data.contains("AgICAgICAgICAgICAgICAg"));
+            #[cfg(unix)]
+            assert!(mode & 0o777 == 0o600);
+            assert!(data.contains("\"ff3-1\""));
+
+            let loaded = loaded?;
+            assert!(loaded.names().collect::<Vec<_>>() == ["a", "b", "c"]);
+            for n in ["a", "b", "c"] {
+                assert!(loaded.metadata(n)? == ks.metadata(n)?);
+            }
+            assert!(loaded.metadata("b")?.alphabet == ["A", "C", "G", "T"]);
+            assert!(
+                loaded.ff3_1("b")?.decrypt(&ct, Some(&[0; 7]))?
+                    == "GATTACAGATTACA"
+            );
+            let ctx = loaded.context("c")?;
+            let pt = "abcd".repeat(5);
+            assert!(ctx.decrypt(&ctx.encrypt(&pt, None)?, None)? == pt);
+
+            assert!(matches!(
+                wrong.err().unwrap().kind(),
+                ErrorKind::Keystore { .. }
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn entries() -> Result<()> {
+        let mut ks = Keystore::new("secret", PBKDF2)?;
+        ks.insert("a", &[1; 16], Algorithm::FF1, 10, None)?;
+
+        let res = ks.insert("a", &[1; 16], Algorithm::FF1, 10, None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateKey { .. }
+        ));
+        let res = ks.insert("b", &[1; 15], Algorithm::FF1, 10, None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidKey { length: 15 }
+        ));
+        let res = ks.insert("b", &[1; 16], Algorithm::FF1, 11, "0123456789");
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::AlphabetTooSmall { .. }
+        ));
+
+        assert!(matches!(
+            ks.ff3_1("a").err().unwrap().kind(),
+            ErrorKind::Keystore { .. }
+        ));
+        ks.remove("a")?;
+        assert!(matches!(
+            ks.ff1("a").err().unwrap().kind(),
+            ErrorKind::UnknownKey { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tampered() -> Result<()> {
+        let path = temp_path("tampered");
+
+        let mut ks = Keystore::new("secret", PBKDF2)?;
+        ks.insert("a", &[1; 16], Algorithm::FF1, 10, None)?;
+        ks.save(&path)?;
+
+        let data = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, data.replace("\"radix\": 10", "\"radix\": 8"))
+            .unwrap();
+        let res = Keystore::load(&path, "secret");
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::Keystore { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tampered_entries() -> Result<()> {
+        let path = temp_path("tampered-entries");
+        let read = |p: &std::path::Path| -> serde_json::Value {
+            serde_json::from_slice(&std::fs::read(p).unwrap()).unwrap()
+        };
+        let load = |v: &serde_json::Value| {
+            std::fs::write(&path, serde_json::to_vec(v).unwrap()).unwrap();
+            Keystore::load(&path, "secret")
+        };
+
+        let mut ks = Keystore::new("secret", PBKDF2)?;
+        ks.insert("a", &[1; 16], Algorithm::FF1, 10, None)?;
+        ks.insert("b", &[2; 16], Algorithm::FF1, 10, None)?;
+        ks.save(&path)?;
+        let old = read(&path);
+
+        // the same keystore, with "a" replaced by a new key
+        ks.remove("a")?;
+        ks.insert("a", &[3; 16], Algorithm::FF1, 10, None)?;
+        ks.save(&path)?;
+        let new = read(&path);
+
+        // the order of the keys within the file doesn't matter
+        let mut v = new.clone();
+        v["keys"].as_array_mut().unwrap().reverse();
+        assert!(load(&v)?.fetch("a", None)?.expose_secret() == [3; 16]);
+
+        // a key that is removed
+        let mut v = new.clone();
+        v["keys"].as_array_mut().unwrap().remove(1);
+        let removed = load(&v);
+
+        // a key that is rolled back to its previous version
+        let mut v = new.clone();
+        v["keys"][0] = old["keys"][0].clone();
+        let rolled_back = load(&v);
+
+        // different key derivation parameters, with the mac
+        // of the file computed under the original parameters
+        let mut v = new.clone();
+        v["kdf"]["iterations"] = 11.into();
+        let kdf = load(&v);
+
+        std::fs::remove_file(&path).unwrap();
+
+        for res in [removed, rolled_back, kdf] {
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::Keystore { .. }
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn provider() -> Result<()> {
+        let mut ks = Keystore::new("secret", PBKDF2)?;
+        ks.insert("a", &[1; 16], Algorithm::FF1, 10, None)?;
+
+        assert!(ks.versions("a")? == [1]);
+        let md = KeyProvider::metadata(&ks, "a", None)?;
+        assert!(md.version == 1 && md.length == 16);
+        assert!(md.algorithm == Some(Algorithm::FF1));
+
+        let ff1 = FF1::builder()
+            .provider_key(&ks, "a", None)
+            .radix(10)
+            .build()?;
+        assert!(
+            ff1.encrypt("0123456789", None)?
+                == ks.ff1("a")?.encrypt("0123456789", None)?
+        );
+
+        let res = ks.fetch("a", Some(2));
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::UnknownKey {
+                version: Some(2),
+                ..
+            }
+        ));
+        let res = FF3_1::builder()
+            .provider_key(&ks, "a", None)
+            .radix(10)
+            .tweak(&[0; 7])
+            .build();
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::KeyProvider { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn kdf_limits() {
+        for kdf in [
+            PassphraseKdf::Argon2id {
+                memory: MAX_ARGON2_MEMORY + 1,
+                iterations: 1,
+                parallelism: 1,
+            },
+            PassphraseKdf::Argon2id {
+                memory: 64,
+                iterations: u32::MAX,
+                parallelism: 1,
+            },
+            PassphraseKdf::Pbkdf2Sha256 {
+                iterations: u32::MAX,
+            },
+        ] {
+            assert!(matches!(
+                Keystore::new("secret", kdf).err().unwrap().kind(),
+                ErrorKind::Keystore { .. }
+            ));
+        }
+    }
+}
@@ -0,0 +1,329 @@
+//! AES key wrap (RFC 3394) and key wrap with padding (RFC 5649)
+//!
+//! Keys exchanged between systems are commonly protected by wrapping
+//! them under a key-encryption key (KEK). The functions in this module
+//! wrap and unwrap keys using either algorithm, and the contexts can
+//! be created directly from a wrapped key via, e.g.
+//! [`FF1::from_wrapped_key`](crate::ff1::FF1::from_wrapped_key).
+//!
+//! The KEK may be any of the lengths supported by AES.
+//!
+//! # Example
+//! ```rust
+//! use fpe::keywrap;
+//!
+//! let kek = [0x42; 32];
+//! let wrapped = keywrap::wrap(&kek, &[0x17; 16]).unwrap();
+//! assert!(wrapped.len() == 24);
+//!
+//! let key = keywrap::unwrap(&kek, &wrapped).unwrap();
+//! assert!(key.expose_secret() == [0x17; 16]);
+//!
+//! let ff1 = fpe::ff1::FF1::from_wrapped_key(&wrapped, &kek, 10, None);
+//! assert!(ff1.is_ok());
+//! ```
+
+use crate::aes::Cipher;
+use crate::error::{Error, ErrorKind};
+use crate::key::SecretKey;
+use crate::result::Result;
+
+use zeroize::Zeroizing;
+
+// the initial value of RFC 3394, section 2.2.3.1
+const IV: [u8; 8] = [0xa6; 8];
+// the high order half of the alternative initial value of RFC 5649,
+// the low order half of which is the length of the key
+const AIV: [u8; 4] = [0xa6, 0x59, 0x59, 0xa6];
+
+/// Wrap a key as specified by RFC 3394
+///
+/// The length of the key must be a multiple of 8 bytes and at least 16
+/// bytes. The wrapped key is 8 bytes longer than the key.
+pub fn wrap(kek: &[u8], key: &[u8]) -> Result<Vec<u8>> {
+    if key.len() < 16 || !key.len().is_multiple_of(8) {
+        return Err(Error::new(ErrorKind::InvalidKey { length: key.len() }));
+    }
+
+    let c = Cipher::new(kek)?;
+    let mut out = [&IV, key].concat();
+    wrap_blocks(&c, &mut out);
+    Ok(out)
+}
+
+/// Unwrap a key as specified by RFC 3394
+pub fn unwrap(kek: &[u8], wrapped: &[u8]) -> Result<SecretKey> {
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+        return Err(Error::new(ErrorKind::InvalidWrappedKey));
+    }
+
+    let c = Cipher::new(kek)?;
+    let mut buf = Zeroizing::new(wrapped.to_vec());
+    unwrap_blocks(&c, &mut buf);
+    check_iv(&buf)
+}
+
+/// Wrap a key as specified by RFC 5649
+///
+/// The key may be of any (non-zero) length. It is padded to a multiple
+/// of 8 bytes, and the wrapped key is 8 bytes longer than the padded key.
+pub fn wrap_with_padding(kek: &[u8], key: &[u8]) -> Result<Vec<u8>> {
+    if key.is_empty() || key.len() > u32::MAX as usize {
+        return Err(Error::new(ErrorKind::InvalidKey { length: key.len() }));
+    }
+
+    let c = Cipher::new(kek)?;
+    let mli = (key.len() as u32).to_be_bytes();
+    let padded = 8 + key.len().div_ceil(8) * 8;
+
+    if padded == 16 {
+        // a single block is simply encrypted (section 4.1). the block
+        // holds the key and is wiped once it has been encrypted
+        let mut blk = Zeroizing::new([0u8; 16]);
+        blk[..4].copy_from_slice(&AIV);
+        blk[4..8].copy_from_slice(&mli);
+        blk[8..8 + key.len()].copy_from_slice(key);
+
+        let mut out = vec![0; 16];
+        c.encrypt_block(blk.as_slice(), &mut out);
+        return Ok(out);
+    }
+
+    // the key is wrapped in place, so the buffer
+    // never holds the key once this returns
+    let mut out = Vec::with_capacity(padded);
+    out.extend_from_slice(&AIV);
+    out.extend_from_slice(&mli);
+    out.extend_from_slice(key);
+    out.resize(padded, 0);
+    wrap_blocks(&c, &mut out);
+
+    Ok(out)
+}
+
+/// Unwrap a key as specified by RFC 5649
+pub fn unwrap_with_padding(kek: &[u8], wrapped: &[u8]) -> Result<SecretKey> {
+    if wrapped.len() < 16 || !wrapped.len().is_multiple_of(8) {
+        return Err(Error::new(ErrorKind::InvalidWrappedKey));
+    }
+
+    let c = Cipher::new(kek)?;
+    let mut buf = Zeroizing::new(wrapped.to_vec());
+    if buf.len() == 16 {
+        c.decrypt_block(wrapped, &mut buf);
+    } else {
+        unwrap_blocks(&c, &mut buf);
+    }
+    check_aiv(&buf)
+}
+
+// unwrap a key wrapped by either algorithm. the algorithms differ
+// only in the initial value (for keys longer than 8 bytes), so the
+// algorithm is identified by the value recovered by the unwrapping
+pub(crate) fn unwrap_key(kek: &[u8], wrapped: &[u8]) -> Result<SecretKey> {
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+        return unwrap_with_padding(kek, wrapped);
+    }
+
+    let c = Cipher::new(kek)?;
+    let mut buf = Zeroizing::new(wrapped.to_vec());
+    unwrap_blocks(&c, &mut buf);
+    match buf[..8] == IV {
+        true => check_iv(&buf),
+        false => check_aiv(&buf),
+    }
+}
+
+// the wrapping process, W, of RFC 3394, section 2.2.1, operating on
+// the initial value followed by the key, all of which is in `buf`
+fn wrap_blocks(c: &Cipher, buf: &mut [u8]) {
+    let n = buf.len() / 8 - 1;
+    let mut b = Zeroizing::new([0u8; 16]);
+
+    for j in 0..6 {
+        for i in 1..=n {
+            b[..8].copy_from_slice(&buf[..8]);
+            b[8..].copy_from_slice(&buf[i * 8..(i + 1) * 8]);
+            let blk = *b;
+            c.encrypt_block(&blk, b.as_mut_slice());
+
+            let t = ((n * j + i) as u64).to_be_bytes();
+            for (a, (b, t)) in buf[..8].iter_mut().zip(b.iter().zip(t)) {
+                *a = b ^ t;
+            }
+            buf[i * 8..(i + 1) * 8].copy_from_slice(&b[8..]);
+        }
+    }
+}
+
+// the unwrapping process, W^-1, of RFC 3394, section 2.2.2. the
+// recovered initial value is left in the first 8 bytes of `buf`
+fn unwrap_blocks(c: &Cipher, buf: &mut [u8]) {
+    let n = buf.len() / 8 - 1;
+    let mut b = Zeroizing::new([0u8; 16]);
+
+    for j in (0..6).rev() {
+        for i in (1..=n).rev() {
+            let t = ((n * j + i) as u64).to_be_bytes();
+            for (b, (a, t)) in b.iter_mut().zip(buf[..8].iter().zip(t)) {
+                *b = a ^ t;
+            }
+            b[8..].copy_from_slice(&buf[i * 8..(i + 1) * 8]);
+            let blk = *b;
+            c.decrypt_block(&blk, b.as_mut_slice());
+
+            buf[..8].copy_from_slice(&b[..8]);
+            buf[i * 8..(i + 1) * 8].copy_from_slice(&b[8..]);
+        }
+    }
+}
+
+// the integrity check of RFC 3394, section 2.2.3
+fn check_iv(buf: &[u8]) -> Result<SecretKey> {
+    match buf[..8] == IV {
+        true => Ok(SecretKey::new(&buf[8..])),
+        false => Err(Error::new(ErrorKind::InvalidWrappedKey)),
+    }
+}
+
+// the integrity check of RFC 5649, section 3
+fn check_aiv(buf: &[u8]) -> Result<SecretKey> {
+    let n = buf.len() - 8;
+    let mli = u32::from_be_bytes(buf[4..8].try_into().unwrap()) as usize;
+
+    if buf[..4] != AIV
+        || mli > n
+        || mli + 8 <= n
+        || buf[8 + mli..].iter().any(|&b| b != 0)
+    {
+        return Err(Error::new(ErrorKind::InvalidWrappedKey));
+    }
+
+    Ok(SecretKey::new(&buf[8..8 + mli]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        let s: String = s.split_whitespace().collect();
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const KEK: &str = "000102030405060708090A0B0C0D0E0F\
+                       101112131415161718191A1B1C1D1E1F";
+    const KEY: &str = "00112233445566778899AABBCCDDEEFF\
+                       000102030405060708090A0B0C0D0E0F";
+
+    // RFC 3394, section 4
+    #[test]
+    fn rfc3394() -> Result<()> {
+        for (kek, key, wrapped) in [
+            (16, 16, "1FA68B0A8112B447 AEF34BD8FB5A7B82 9D3E862371D2CFE5"),
+            (24, 16, "96778B25AE6CA435 F92B5B97C050AED2 468AB8A17AD84E5D"),
+            (32, 16, "64E8C3F9CE0F5BA2 63E9777905818A2A 93C8191E7D6E8AE7"),
+            (
+                24,
+                24,
+                "031D33264E15D332 68F24EC260743EDC E1C6C7DDEE725A93 \
+                 6BA814915C6762D2",
+            ),
+            (
+                32,
+                24,
+                "A8F9BC1612C68B3F F6E6F4FBE30E71E4 769C8B80A32CB895 \
+                 8CD5D17D6B254DA1",
+            ),
+            (
+                32,
+                32,
+                "28C9F404C4B810F4 CBCCB35CFB87F826 3F5786E2D80ED326 \
+                 CBC7F0E71A99F43B FB988B9B7A02DD21",
+            ),
+        ] {
+            let kek = &hex(KEK)[..kek];
+            let key = &hex(KEY)[..key];
+            let wrapped = hex(wrapped);
+
+            assert!(wrap(kek, key)? == wrapped);
+            assert!(unwrap(kek, &wrapped)?.expose_secret() == key);
+            assert!(unwrap_key(kek, &wrapped)?.expose_secret() == key);
+        }
+
+        Ok(())
+    }
+
+    // RFC 5649, section 6
+    #[test]
+    fn rfc5649() -> Result<()> {
+        let kek = hex("5840df6e29b02af1 ab493b705bf16ea1 ae8338f4dcc176a8");
+
+        for (key, wrapped) in [
+            (
+                "c37b7e6492584340 bed1220780894115 5068f738",
+                "138bdeaa9b8fa7fc 61f97742e72248ee \
+                 5ae6ae5360d1ae6a 5f54f373fa543b6a",
+            ),
+            ("466f7250617369", "afbeb0f07dfbf541 9200f2ccb50bb24f"),
+        ] {
+            let key = hex(key);
+            let wrapped = hex(wrapped);
+
+            assert!(wrap_with_padding(&kek, &key)? == wrapped);
+            assert!(
+                unwrap_with_padding(&kek, &wrapped)?.expose_secret() == key
+            );
+            assert!(unwrap_key(&kek, &wrapped)?.expose_secret() == key);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn padded_lengths() -> Result<()> {
+        let kek = [1; 16];
+
+        // the key is padded to the next multiple of 8 bytes, to
+        // which the initial value adds another 8 bytes
+        for len in 1..=33usize {
+            let key: Vec<u8> = (1..=len as u8).collect();
+            let wrapped = wrap_with_padding(&kek, &key)?;
+            assert!(wrapped.len() == 8 + len.div_ceil(8) * 8);
+            assert!(
+                unwrap_with_padding(&kek, &wrapped)?.expose_secret() == key
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn integrity() -> Result<()> {
+        let kek = [1; 16];
+        let mut wrapped = wrap(&kek, &[2; 16])?;
+        let padded = wrap_with_padding(&kek, &[2; 16])?;
+
+        // the algorithms can't be confused with one another
+        assert!(unwrap_with_padding(&kek, &wrapped).is_err());
+        assert!(unwrap(&kek, &padded).is_err());
+
+        wrapped[5] ^= 1;
+        for res in [
+            unwrap(&kek, &wrapped),
+            unwrap_key(&kek, &wrapped),
+            unwrap(&[3; 16], &padded),
+            unwrap(&kek, &wrapped[..20]),
+        ] {
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::InvalidWrappedKey
+            ));
+        }
+
+        Ok(())
+    }
+}
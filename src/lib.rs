@@ -12,16 +12,15 @@
 //!
 //! # Example
 //! ```rust
-//! let ff1 = fpe::ff1::FF1::new(
-//!     &[
+//! let ff1 = fpe::ff1::FF1::builder()
+//!     .key(&[
 //!         0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
 //!         0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
-//!     ],    // the encryption key
-//!     None, // no tweak specified, use an empty one
-//!     0, 0, // no minimum and maximum tweak size
-//!     10,   // radix specifies the number of characters in the alphabet
-//!     None  // use (the first 10 characters of) the default alphabet
-//! ).unwrap();
+//!     ])
+//!     // use (the first 10 characters of) the default alphabet
+//!     .radix(10)
+//!     .build()
+//!     .unwrap();
 //!
 //! // these are from the first NIST-specified test for FF1
 //! let pt = "0123456789";
@@ -34,32 +33,26 @@
 //! assert!(out == pt);
 //! ```
 
+pub mod acvp;
 pub(crate) mod aes;
-pub(crate) mod alphabet;
+pub mod alphabet;
+pub mod cipher;
+pub mod error;
 pub mod ff1;
 pub mod ff3_1;
 pub(crate) mod ffx;
-
-/// Errors returned by the FPE library
-pub mod error {
-
-    /// Structure used by the library to convey errors
-    #[derive(Debug)]
-    pub struct Error {
-        // compiler thinks "why" is unused because we
-        // allow the Debug trait to format it for us.
-        #[allow(dead_code)]
-        why: String,
-    }
-
-    impl Error {
-        pub fn new(why: &str) -> Self {
-            Error {
-                why: why.to_string(),
-            }
-        }
-    }
-}
+pub mod fips;
+pub mod kdf;
+pub mod key;
+pub mod keyring;
+pub mod keystore;
+pub mod keywrap;
+pub mod memory;
+pub mod provider;
+pub mod shamir;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod tweak;
 
 /// Results returned by the FPE library
 pub mod result {
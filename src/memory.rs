@@ -0,0 +1,238 @@
+//! Protection of key material in memory
+//!
+//! When the `mlock` feature is enabled, the expanded AES keys held by
+//! the contexts are stored in memory that is locked into RAM so that
+//! it is never written to swap. The memory is surrounded by guard
+//! pages that can't be accessed, and on Linux, it is excluded from
+//! core dumps. Locking memory may not be permitted, e.g. when the
+//! process's `RLIMIT_MEMLOCK` is exhausted, in which case the keys
+//! are stored without it. The outcome is reported by the
+//! `key_lock_status` functions of the contexts.
+
+/// Whether the key material of a context is in locked memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockStatus {
+    /// The memory is locked and surrounded by guard pages. On Linux,
+    /// it is also excluded from core dumps.
+    Locked,
+    /// The memory is surrounded by guard pages, but the operating
+    /// system did not permit it to be locked
+    NotPermitted,
+    /// Memory with guard pages could not be allocated, so the key
+    /// material is stored in ordinary memory
+    Unavailable,
+    /// The `mlock` feature is not enabled
+    Disabled,
+}
+
+pub(crate) use imp::Protected;
+
+#[cfg(not(all(feature = "mlock", unix)))]
+mod imp {
+    use super::LockStatus;
+
+    // without support for locking, values are stored on the heap
+    pub struct Protected<T>(Box<T>);
+
+    impl<T> Protected<T> {
+        pub fn new(val: T) -> Self {
+            Protected(Box::new(val))
+        }
+
+        pub fn status(&self) -> LockStatus {
+            if cfg!(feature = "mlock") {
+                LockStatus::Unavailable
+            } else {
+                LockStatus::Disabled
+            }
+        }
+    }
+
+    impl<T> std::ops::Deref for Protected<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+}
+
+#[cfg(all(feature = "mlock", unix))]
+mod imp {
+    use super::LockStatus;
+
+    use zeroize::Zeroize;
+
+    // a mapping of pages laid out as: guard page, data pages, guard page
+    struct Mapping {
+        base: *mut u8,
+        page: usize,
+        len: usize,
+    }
+
+    impl Mapping {
+        fn new(size: usize) -> Option<Self> {
+            let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+            if page <= 0 {
+                return None;
+            }
+            let page = page as usize;
+            let len = (size.div_ceil(page) + 2) * page;
+
+            let base = unsafe {
+                libc::mmap(
+                    std::ptr::null_mut(),
+                    len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+            if base == libc::MAP_FAILED {
+                return None;
+            }
+
+            let m = Mapping {
+                base: base as *mut u8,
+                page,
+                len,
+            };
+            let guarded = unsafe {
+                libc::mprotect(m.base as _, page, libc::PROT_NONE) == 0
+                    && libc::mprotect(
+                        m.base.add(len - page) as _,
+                        page,
+                        libc::PROT_NONE,
+                    ) == 0
+            };
+
+            guarded.then_some(m)
+        }
+
+        fn data(&self) -> *mut u8 {
+            unsafe { self.base.add(self.page) }
+        }
+
+        fn data_len(&self) -> usize {
+            self.len - 2 * self.page
+        }
+
+        fn lock(&self) -> bool {
+            let locked =
+                unsafe { libc::mlock(self.data() as _, self.data_len()) == 0 };
+
+            #[cfg(target_os = "linux")]
+            unsafe {
+                libc::madvise(
+                    self.data() as _,
+                    self.data_len(),
+                    libc::MADV_DONTDUMP,
+                );
+            }
+
+            locked
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            unsafe {
+                std::slice::from_raw_parts_mut(self.data(), self.data_len())
+                    .zeroize();
+                libc::munlock(self.data() as _, self.data_len());
+                libc::munmap(self.base as _, self.len);
+            }
+        }
+    }
+
+    enum Storage<T> {
+        // the mapping is only held so that it is released on drop
+        Mapped { _map: Mapping, ptr: *mut T },
+        Boxed(Box<T>),
+    }
+
+    // stores a value in locked memory if possible. note that the
+    // value is constructed by the caller and moved into the locked
+    // memory, so a copy may remain on the stack of the caller
+    pub struct Protected<T> {
+        storage: Storage<T>,
+        status: LockStatus,
+    }
+
+    // the value is owned exclusively by the structure, just as
+    // it would be if it were held in a Box
+    unsafe impl<T: Send> Send for Protected<T> {}
+    unsafe impl<T: Sync> Sync for Protected<T> {}
+
+    impl<T> Protected<T> {
+        pub fn new(val: T) -> Self {
+            match Mapping::new(std::mem::size_of::<T>()) {
+                Some(m) => {
+                    let status = match m.lock() {
+                        true => LockStatus::Locked,
+                        false => LockStatus::NotPermitted,
+                    };
+
+                    // the data begins on a page boundary, which
+                    // satisfies the alignment of any value
+                    let ptr = m.data() as *mut T;
+                    unsafe { ptr.write(val) };
+
+                    Protected {
+                        storage: Storage::Mapped { _map: m, ptr },
+                        status,
+                    }
+                }
+                None => Protected {
+                    storage: Storage::Boxed(Box::new(val)),
+                    status: LockStatus::Unavailable,
+                },
+            }
+        }
+
+        pub fn status(&self) -> LockStatus {
+            self.status
+        }
+    }
+
+    impl<T> std::ops::Deref for Protected<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            match &self.storage {
+                Storage::Mapped { ptr, .. } => unsafe { &**ptr },
+                Storage::Boxed(b) => b,
+            }
+        }
+    }
+
+    impl<T> Drop for Protected<T> {
+        fn drop(&mut self) {
+            // the value is dropped in place, after which the
+            // mapping wipes and releases the memory
+            if let Storage::Mapped { ptr, .. } = &self.storage {
+                unsafe { std::ptr::drop_in_place(*ptr) };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LockStatus, Protected};
+
+    #[test]
+    fn protected() {
+        let p = Protected::new([7u8; 100]);
+        assert!(*p == [7u8; 100]);
+
+        if cfg!(all(feature = "mlock", unix)) {
+            assert!(p.status() != LockStatus::Disabled);
+        } else if cfg!(feature = "mlock") {
+            assert!(p.status() == LockStatus::Unavailable);
+        } else {
+            assert!(p.status() == LockStatus::Disabled);
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Sources of keys
+//!
+//! The [`KeyProvider`] trait abstracts the retrieval of keys by id and
+//! version so that contexts may be created from a key management
+//! system without each caller implementing the retrieval themselves.
+//! Two implementations are provided: [`MemoryKeyProvider`], which
+//! holds keys in memory and is mostly useful for testing, and
+//! [`FileKeyProvider`], which reads keys from a local file that is
+//! encrypted under a key-encryption key.
+//!
+//! # Example
+//! ```rust
+//! use fpe::provider::{KeyProvider, MemoryKeyProvider};
+//!
+//! let mut keys = MemoryKeyProvider::new();
+//! keys.insert("ssn", 1, &[1; 16], None).unwrap();
+//! keys.insert("ssn", 2, &[2; 16], None).unwrap();
+//! assert!(keys.versions("ssn").unwrap() == [1, 2]);
+//!
+//! // the latest version of the key is used by default
+//! let ff1 =
+//!     fpe::ff1::FF1::from_provider(&keys, "ssn", None, 10, None).unwrap();
+//! ```
+
+use crate::cipher::Algorithm;
+use crate::error::{Error, ErrorKind};
+use crate::key::{self, SecretKey};
+use crate::result::Result;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Information about a key, other than the key itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMetadata {
+    /// The id of the key
+    pub id: String,
+    /// The version of the key
+    pub version: u32,
+    /// The algorithm for which the key is intended, if restricted
+    pub algorithm: Option<Algorithm>,
+    /// When the key was created, in seconds since the Unix epoch
+    pub created: Option<u64>,
+    /// The length of the key, in bytes
+    pub length: usize,
+}
+
+/// A source of versioned keys
+///
+/// A version of `None` refers to the latest (highest) version of a key.
+pub trait KeyProvider: Send + Sync {
+    /// Retrieve a key
+    fn fetch(&self, id: &str, version: Option<u32>) -> Result<SecretKey>;
+
+    /// The versions of a key, in ascending order
+    fn versions(&self, id: &str) -> Result<Vec<u32>>;
+
+    /// Retrieve information about a key
+    fn metadata(&self, id: &str, version: Option<u32>) -> Result<KeyMetadata>;
+}
+
+// retrieve a key for use with the given algorithm, failing if the key
+// is restricted to another. the version is taken from the metadata so
+// that both refer to the same key, even if a newer version is added
+pub(crate) fn fetch_for(
+    provider: &dyn KeyProvider,
+    id: &str,
+    version: Option<u32>,
+    algorithm: Algorithm,
+) -> Result<SecretKey> {
+    let md = provider.metadata(id, version)?;
+    match md.algorithm {
+        Some(a) if a != algorithm => Err(provider_error(format!(
+            "key {} version {} is for {}, not {}",
+            id, md.version, a, algorithm
+        ))),
+        _ => provider.fetch(id, Some(md.version)),
+    }
+}
+
+struct Entry {
+    key: SecretKey,
+    algorithm: Option<Algorithm>,
+    created: Option<u64>,
+}
+
+/// A key provider that holds its keys in memory
+#[derive(Default)]
+pub struct MemoryKeyProvider {
+    keys: BTreeMap<String, BTreeMap<u32, Entry>>,
+}
+
+impl MemoryKeyProvider {
+    /// Create a provider with no keys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a version of a key
+    ///
+    /// The key must be one of the lengths supported by AES, and the
+    /// version must not already exist. The creation time of the key
+    /// is recorded as the current time.
+    pub fn insert(
+        &mut self,
+        id: &str,
+        version: u32,
+        key: &[u8],
+        algorithm: Option<Algorithm>,
+    ) -> Result<()> {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs());
+        self.insert_entry(
+            id,
+            version,
+            Entry {
+                key: SecretKey::new(key),
+                algorithm,
+                created,
+            },
+        )
+    }
+
+    fn insert_entry(&mut self, id: &str, version: u32, e: Entry) -> Result<()> {
+        if ![16, 24, 32].contains(&e.key.len()) {
+            return Err(Error::new(ErrorKind::InvalidKey {
+                length: e.key.len(),
+            }));
+        }
+
+        let versions = self.keys.entry(id.to_string()).or_default();
+        if versions.contains_key(&version) {
+            return Err(Error::new(ErrorKind::DuplicateProviderKey {
+                id: id.to_string(),
+                version,
+            }));
+        }
+
+        versions.insert(version, e);
+        Ok(())
+    }
+
+    fn entry(&self, id: &str, version: Option<u32>) -> Result<(u32, &Entry)> {
+        let versions = self.keys.get(id);
+        let found = match version {
+            Some(v) => versions.and_then(|vs| vs.get_key_value(&v)),
+            None => versions.and_then(|vs| vs.last_key_value()),
+        };
+
+        found
+            .map(|(v, e)| (*v, e))
+            .ok_or(Error::new(ErrorKind::UnknownKey {
+                id: id.to_string(),
+                version,
+            }))
+    }
+}
+
+impl KeyProvider for MemoryKeyProvider {
+    fn fetch(&self, id: &str, version: Option<u32>) -> Result<SecretKey> {
+        self.entry(id, version).map(|(_, e)| e.key.clone())
+    }
+
+    fn versions(&self, id: &str) -> Result<Vec<u32>> {
+        self.keys
+            .get(id)
+            .map(|vs| vs.keys().copied().collect())
+            .ok_or(Error::new(ErrorKind::UnknownKey {
+                id: id.to_string(),
+                version: None,
+            }))
+    }
+
+    fn metadata(&self, id: &str, version: Option<u32>) -> Result<KeyMetadata> {
+        self.entry(id, version).map(|(v, e)| KeyMetadata {
+            id: id.to_string(),
+            version: v,
+            algorithm: e.algorithm,
+            created: e.created,
+            length: e.key.len(),
+        })
+    }
+}
+
+// identifies the format of key files and is authenticated along with
+// the encrypted keys so that the contents can't be used in another
+// context
+const KEY_FILE_AAD: &[u8] = b"fpe-key-file-v1";
+const KEY_FILE_FORMAT: u32 = 1;
+
+// the outer, unencrypted structure of a key file
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    format: u32,
+    nonce: String,
+    ciphertext: String,
+}
+
+// the encrypted contents of a key file
+#[derive(Serialize, Deserialize)]
+struct KeyFileContents {
+    keys: Vec<KeyFileEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyFileEntry {
+    id: String,
+    version: u32,
+    algorithm: Option<String>,
+    created: Option<u64>,
+    key: String,
+}
+
+impl Drop for KeyFileEntry {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+fn provider_error(reason: impl ToString) -> Error {
+    Error::new(ErrorKind::KeyProvider {
+        reason: reason.to_string(),
+    })
+}
+
+// write a file holding secrets. the data is written to a temporary
+// file alongside the destination that only the owner may read, which
+// is synced to disk and then replaces any existing file at the path,
+// so that a failure can't leave a partially written file. the
+// temporary file is removed if any step fails
+pub(crate) fn write_secret_file(
+    path: &Path,
+    data: &[u8],
+) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    // a stale temporary file, e.g. from a crash, may have been
+    // created with other permissions, so it isn't reused
+    match std::fs::remove_file(&tmp) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => (),
+    }
+
+    let mut opts = std::fs::OpenOptions::new();
+    opts.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut opts, 0o600);
+    let mut f = opts.open(&tmp)?;
+
+    let res = f
+        .write_all(data)
+        .and_then(|_| f.sync_all())
+        .and_then(|_| std::fs::rename(&tmp, path));
+    if res.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    res
+}
+
+fn key_file_cipher(kek: &[u8]) -> Result<Aes256Gcm> {
+    Aes256Gcm::new_from_slice(kek)
+        .map_err(|_| Error::new(ErrorKind::InvalidKey { length: kek.len() }))
+}
+
+/// A key provider backed by an encrypted file
+///
+/// The file is JSON, containing the keys and their metadata encrypted
+/// with AES-256-GCM under a 32-byte key-encryption key. The file is
+/// read in its entirety when opened; changes to the file after that
+/// are not seen by the provider.
+///
+/// This is intended as a stand-in for a key management system during
+/// development and testing, or for deployments where a local file
+/// is acceptable.
+pub struct FileKeyProvider {
+    keys: MemoryKeyProvider,
+}
+
+impl FileKeyProvider {
+    /// Read and decrypt a key file
+    pub fn open(path: impl AsRef<Path>, kek: &[u8]) -> Result<Self> {
+        let cipher = key_file_cipher(kek)?;
+        let b64 = base64::engine::general_purpose::STANDARD;
+
+        let data = std::fs::read(path).map_err(provider_error)?;
+        let file: KeyFile =
+            serde_json::from_slice(&data).map_err(provider_error)?;
+        if file.format != KEY_FILE_FORMAT {
+            return Err(provider_error(format!(
+                "unsupported key file format: {}",
+                file.format
+            )));
+        }
+
+        let nonce = b64.decode(&file.nonce).map_err(provider_error)?;
+        if nonce.len() != 12 {
+            return Err(provider_error("invalid nonce"));
+        }
+        let ct = b64.decode(&file.ciphertext).map_err(provider_error)?;
+        let pt = Zeroizing::new(
+            cipher
+                .decrypt(
+                    Nonce::from_slice(&nonce),
+                    Payload {
+                        msg: &ct,
+                        aad: KEY_FILE_AAD,
+                    },
+                )
+                .map_err(|_| {
+                    provider_error("key file authentication failed")
+                })?,
+        );
+        let contents: KeyFileContents =
+            serde_json::from_slice(&pt).map_err(provider_error)?;
+
+        let mut keys = MemoryKeyProvider::new();
+        for e in &contents.keys {
+            let algorithm = match e.algorithm.as_deref() {
+                None => None,
+                Some("ff1") => Some(Algorithm::FF1),
+                Some("ff3-1") => Some(Algorithm::FF3_1),
+                Some(a) => {
+                    return Err(provider_error(format!(
+                        "unknown algorithm: {}",
+                        a
+                    )))
+                }
+            };
+            let key =
+                SecretKey::from(b64.decode(&e.key).map_err(provider_error)?);
+            keys.insert_entry(
+                &e.id,
+                e.version,
+                Entry {
+                    key,
+                    algorithm,
+                    created: e.created,
+                },
+            )?;
+        }
+
+        Ok(FileKeyProvider { keys })
+    }
+
+    /// Encrypt the keys held by a provider and write them to a file
+    ///
+    /// Any existing file at the path is replaced, as described for
+    /// [`Keystore::save`](crate::keystore::Keystore::save).
+    pub fn write(
+        path: impl AsRef<Path>,
+        kek: &[u8],
+        keys: &MemoryKeyProvider,
+    ) -> Result<()> {
+        let cipher = key_file_cipher(kek)?;
+        let b64 = base64::engine::general_purpose::STANDARD;
+
+        let contents = KeyFileContents {
+            keys: keys
+                .keys
+                .iter()
+                .flat_map(|(id, vs)| {
+                    vs.iter().map(|(v, e)| KeyFileEntry {
+                        id: id.clone(),
+                        version: *v,
+                        algorithm: e.algorithm.map(|a| a.name().to_string()),
+                        created: e.created,
+                        key: b64.encode(e.key.expose_secret()),
+                    })
+                })
+                .collect(),
+        };
+        let pt = Zeroizing::new(
+            serde_json::to_vec(&contents).map_err(provider_error)?,
+        );
+
+        let mut nonce = [0; 12];
+        key::fill_random(&mut nonce)?;
+        let ct = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &pt,
+                    aad: KEY_FILE_AAD,
+                },
+            )
+            .map_err(provider_error)?;
+
+        let file = KeyFile {
+            format: KEY_FILE_FORMAT,
+            nonce: b64.encode(nonce),
+            ciphertext: b64.encode(ct),
+        };
+        let data = serde_json::to_vec_pretty(&file).map_err(provider_error)?;
+        write_secret_file(path.as_ref(), &data).map_err(provider_error)
+    }
+}
+
+impl KeyProvider for FileKeyProvider {
+    fn fetch(&self, id: &str, version: Option<u32>) -> Result<SecretKey> {
+        self.keys.fetch(id, version)
+    }
+
+    fn versions(&self, id: &str) -> Result<Vec<u32>> {
+        self.keys.versions(id)
+    }
+
+    fn metadata(&self, id: &str, version: Option<u32>) -> Result<KeyMetadata> {
+        self.keys.metadata(id, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileKeyProvider, KeyProvider, MemoryKeyProvider};
+    use crate::cipher::Algorithm;
+    use crate::error::ErrorKind;
+    use crate::ff1::FF1;
+    use crate::ff3_1::FF3_1;
+    use crate::result::Result;
+
+    #[test]
+    fn memory() -> Result<()> {
+        let mut keys = MemoryKeyProvider::new();
+        keys.insert("a", 3, &[3; 16], Some(Algorithm::FF1))?;
+        keys.insert("a", 1, &[1; 32], None)?;
+
+        assert!(keys.versions("a")? == [1, 3]);
+        assert!(keys.fetch("a", None)?.expose_secret() == [3; 16]);
+        assert!(keys.fetch("a", Some(1))?.expose_secret() == [1; 32]);
+
+        let md = keys.metadata("a", Some(3))?;
+        assert!(md.version == 3 && md.length == 16);
+        assert!(md.algorithm == Some(Algorithm::FF1));
+
+        let res = keys.fetch("a", Some(2));
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::UnknownKey {
+                version: Some(2),
+                ..
+            }
+        ));
+        let res = keys.insert("a", 1, &[1; 16], None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::DuplicateProviderKey { id, version: 1 } if id == "a"
+        ));
+        let res = keys.insert("b", 1, &[1; 15], None);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InvalidKey { length: 15 }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn file() -> Result<()> {
+        let path = std::env::temp_dir()
+            .join(format!("fpe-provider-test-{}.json", std::process::id()));
+        let kek = [0x42; 32];
+
+        let mut keys = MemoryKeyProvider::new();
+        keys.insert("a", 1, &[1; 16], Some(Algorithm::FF3_1))?;
+        keys.insert("b", 7, &[7; 24], None)?;
+        FileKeyProvider::write(&path, &kek, &keys)?;
+
+        let data = std::fs::read_to_string(&path).unwrap();
+        let file = FileKeyProvider::open(&path, &kek);
+        let wrong = FileKeyProvider::open(&path, &[0x24; 32]);
+        std::fs::remove_file(&path).unwrap();
+
+        // the keys are not stored in the clear
+        assert!(!data.contains("\"a\""));
+
+        let file = file?;
+        assert!(file.versions("b")? == [7]);
+        assert!(file.fetch("b", None)?.expose_secret() == [7; 24]);
+        assert!(file.metadata("a", None)? == keys.metadata("a", None)?);
+
+        assert!(matches!(
+            wrong.err().unwrap().kind(),
+            ErrorKind::KeyProvider { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn algorithm() -> Result<()> {
+        let mut keys = MemoryKeyProvider::new();
+        keys.insert("a", 1, &[1; 16], Some(Algorithm::FF3_1))?;
+        keys.insert("b", 1, &[1; 16], None)?;
+
+        // a key restricted to one algorithm can't be used with
+        // the other, while an unrestricted key works with either
+        let res = FF1::builder()
+            .provider_key(&keys, "a", None)
+            .radix(10)
+            .build();
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::KeyProvider { .. }
+        ));
+        FF3_1::builder()
+            .provider_key(&keys, "a", Some(1))
+            .radix(10)
+            .tweak(&[0; 7])
+            .build()?;
+        FF1::builder()
+            .provider_key(&keys, "b", None)
+            .radix(10)
+            .build()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,346 @@
+//! Splitting keys among custodians
+//!
+//! Shamir's secret sharing splits a key into `n` shares, any `k` of
+//! which (the threshold) can be combined to recover the key, while
+//! fewer than `k` reveal nothing about it. Each byte of the key is
+//! split independently over GF(2^8).
+//!
+//! Every share records the threshold and the [`KeyCheckValue`] of the
+//! key so that the recovered key can be verified. Shares are encoded
+//! as text, with a checksum to catch errors in transcription.
+//!
+//! # Example
+//! ```rust
+//! use fpe::key::SecretKey;
+//! use fpe::shamir::{self, Share};
+//!
+//! let key = SecretKey::new(&[0x2b; 16]);
+//! let shares = shamir::split(&key, 2, 3).unwrap();
+//!
+//! // each share is given to a different custodian
+//! let encoded: Vec<_> = shares.iter().map(|s| s.encode()).collect();
+//!
+//! // any two of them recover the key
+//! let shares = [
+//!     Share::decode(&encoded[2]).unwrap(),
+//!     Share::decode(&encoded[0]).unwrap(),
+//! ];
+//! let key = shamir::combine(&shares).unwrap();
+//! let ff1 = fpe::ff1::FF1::new(key.expose_secret(), None, 0, 0, 10, None);
+//! assert!(ff1.is_ok());
+//! ```
+
+use crate::error::{Error, ErrorKind};
+use crate::key::{self, KeyCheckValue, SecretKey};
+use crate::result::Result;
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+// identifies encoded shares and the version of the encoding
+const PREFIX: &str = "fpes1-";
+// the length of the checksum of an encoded share, in bytes
+const CHECKSUM_LEN: usize = 4;
+
+/// One share of a key
+#[derive(Clone)]
+pub struct Share {
+    threshold: u8,
+    index: u8,
+    kcv: KeyCheckValue,
+    data: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// The number of shares required to recover the key
+    pub fn threshold(&self) -> usize {
+        self.threshold as usize
+    }
+
+    /// The index of the share, from 1 to the number of shares
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// The key check value of the key from which the share was made
+    pub fn key_check_value(&self) -> KeyCheckValue {
+        self.kcv
+    }
+
+    /// Encode the share as text
+    ///
+    /// The text consists of a prefix identifying the encoding, followed
+    /// by hexadecimal digits encoding the threshold, index, key check
+    /// value, share data, and a checksum of all of those.
+    pub fn encode(&self) -> Zeroizing<String> {
+        let mut raw = Zeroizing::new(Vec::with_capacity(9 + self.data.len()));
+        raw.push(self.threshold);
+        raw.push(self.index);
+        raw.extend_from_slice(self.kcv.as_bytes());
+        raw.extend_from_slice(&self.data);
+        let sum = checksum(&raw);
+        raw.extend_from_slice(&sum);
+
+        let mut s = Zeroizing::new(PREFIX.to_string());
+        s.push_str(&key::hex_encode(&raw));
+        s
+    }
+
+    /// Decode a share from text produced by [`Share::encode`]
+    pub fn decode(s: &str) -> Result<Self> {
+        let hex = s
+            .trim()
+            .strip_prefix(PREFIX)
+            .ok_or_else(|| share_error("unrecognized share encoding"))?;
+        let raw = key::hex_decode(hex)
+            .map_err(|_| share_error("invalid hex digits in share"))?;
+
+        // threshold, index, kcv, at least 16 bytes of key, checksum
+        if raw.len() < 5 + 16 + CHECKSUM_LEN {
+            return Err(share_error("share is too short"));
+        }
+        let (body, sum) = raw.split_at(raw.len() - CHECKSUM_LEN);
+        if checksum(body) != sum {
+            return Err(share_error("share checksum mismatch"));
+        }
+
+        let share = Share {
+            threshold: body[0],
+            index: body[1],
+            kcv: KeyCheckValue::from_bytes([body[2], body[3], body[4]]),
+            data: Zeroizing::new(body[5..].to_vec()),
+        };
+        if share.threshold < 2 || share.index == 0 {
+            return Err(share_error("invalid share parameters"));
+        }
+
+        Ok(share)
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("threshold", &self.threshold)
+            .field("index", &self.index)
+            .field("kcv", &self.kcv)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Split a key into shares
+///
+/// Any `threshold` of the `shares` returned can be combined to recover
+/// the key. The threshold must be at least 2 and no more than the
+/// number of shares, which may be at most 255. The key must be one of
+/// the lengths supported by AES.
+pub fn split(
+    key: &SecretKey,
+    threshold: usize,
+    shares: usize,
+) -> Result<Vec<Share>> {
+    if threshold < 2 || threshold > shares || shares > 255 {
+        return Err(Error::new(ErrorKind::InvalidShareThreshold {
+            threshold,
+            shares,
+        }));
+    }
+    let kcv = key.check_value()?;
+
+    // the coefficients of the polynomial for each byte of the key,
+    // the constant term of which is the key byte itself
+    let mut coef = Zeroizing::new(vec![0u8; threshold]);
+    let mut out: Vec<Share> = (1..=shares)
+        .map(|x| Share {
+            threshold: threshold as u8,
+            index: x as u8,
+            kcv,
+            data: Zeroizing::new(Vec::with_capacity(key.len())),
+        })
+        .collect();
+
+    for &k in key.expose_secret() {
+        coef[0] = k;
+        key::fill_random(&mut coef[1..])?;
+
+        for s in &mut out {
+            // horner's method
+            let y = coef.iter().rev().fold(0, |y, &c| gf_mul(y, s.index) ^ c);
+            s.data.push(y);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Recover a key from its shares
+///
+/// At least as many shares as the threshold must be supplied. All of
+/// the shares must have been made from the same key, and the recovered
+/// key must match the key check value recorded in the shares.
+pub fn combine(shares: &[Share]) -> Result<SecretKey> {
+    let first = shares
+        .first()
+        .ok_or_else(|| share_error("no shares supplied"))?;
+    let threshold = first.threshold();
+
+    for (i, s) in shares.iter().enumerate() {
+        if s.threshold != first.threshold
+            || s.kcv != first.kcv
+            || s.data.len() != first.data.len()
+        {
+            return Err(share_error("shares are from different splits"));
+        }
+        if shares[..i].iter().any(|o| o.index == s.index) {
+            return Err(share_error(format!("duplicate share: {}", s.index)));
+        }
+    }
+    if shares.len() < threshold {
+        return Err(Error::new(ErrorKind::InsufficientShares {
+            threshold,
+            actual: shares.len(),
+        }));
+    }
+
+    // lagrange interpolation at x = 0. only `threshold` shares are
+    // needed; any others are redundant
+    let shares = &shares[..threshold];
+    let mut key = Zeroizing::new(vec![0u8; first.data.len()]);
+    for (j, sj) in shares.iter().enumerate() {
+        let l = shares.iter().enumerate().filter(|(m, _)| *m != j).fold(
+            1,
+            |l, (_, sm)| {
+                gf_mul(l, gf_mul(sm.index, gf_inv(sm.index ^ sj.index)))
+            },
+        );
+
+        for (k, y) in key.iter_mut().zip(sj.data.iter()) {
+            *k ^= gf_mul(l, *y);
+        }
+    }
+
+    let key = SecretKey::from(key);
+    if key.check_value()? != first.kcv {
+        return Err(Error::new(ErrorKind::KeyCheckValueMismatch));
+    }
+
+    Ok(key)
+}
+
+// multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1, in time
+// independent of the operands
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    for _ in 0..8 {
+        p ^= a & (b & 1).wrapping_neg();
+        a = (a << 1) ^ (0x1b & (a >> 7).wrapping_neg());
+        b >>= 1;
+    }
+    p
+}
+
+// the multiplicative inverse, computed as a^254
+fn gf_inv(a: u8) -> u8 {
+    let a2 = gf_mul(a, a);
+    let a4 = gf_mul(a2, a2);
+    let a8 = gf_mul(a4, a4);
+    let a16 = gf_mul(a8, a8);
+    let a32 = gf_mul(a16, a16);
+    let a64 = gf_mul(a32, a32);
+    let a128 = gf_mul(a64, a64);
+    // 254 = 128 + 64 + 32 + 16 + 8 + 4 + 2
+    [a64, a32, a16, a8, a4, a2]
+        .iter()
+        .fold(a128, |r, &x| gf_mul(r, x))
+}
+
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    Sha256::digest(data)[..CHECKSUM_LEN].try_into().unwrap()
+}
+
+fn share_error(reason: impl ToString) -> Error {
+    Error::new(ErrorKind::InvalidShare {
+        reason: reason.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field() {
+        // the example of FIPS 197, section 4.2
+        assert!(gf_mul(0x57, 0x83) == 0xc1);
+        assert!(gf_mul(0x57, 0x13) == 0xfe);
+        for a in 1..=255 {
+            assert!(gf_mul(a, gf_inv(a)) == 1);
+        }
+    }
+
+    #[test]
+    fn split_combine() -> Result<()> {
+        let key = SecretKey::generate(crate::kdf::KeySize::Aes192)?;
+        let shares = split(&key, 3, 5)?;
+        assert!(shares.len() == 5);
+
+        for set in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+            let s: Vec<_> = set.iter().map(|&i| shares[i].clone()).collect();
+            assert!(combine(&s)? == key);
+        }
+        assert!(combine(&shares)? == key);
+
+        let res = combine(&shares[..2]);
+        assert!(matches!(
+            res.err().unwrap().kind(),
+            ErrorKind::InsufficientShares {
+                threshold: 3,
+                actual: 2
+            }
+        ));
+
+        let mut bad = shares[..3].to_vec();
+        bad[1].data[0] ^= 1;
+        assert!(matches!(
+            combine(&bad).err().unwrap().kind(),
+            ErrorKind::KeyCheckValueMismatch
+        ));
+
+        for (t, n) in [(1, 3), (4, 3), (2, 256)] {
+            assert!(matches!(
+                split(&key, t, n).err().unwrap().kind(),
+                ErrorKind::InvalidShareThreshold { .. }
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn encoding() -> Result<()> {
+        let key = SecretKey::new(&[7; 16]);
+        let shares = split(&key, 2, 2)?;
+
+        let enc = shares[1].encode();
+        assert!(enc.starts_with(PREFIX));
+        let dec = Share::decode(&enc)?;
+        assert!(dec.index() == 2 && dec.threshold() == 2);
+        assert!(dec.key_check_value() == key.check_value()?);
+        assert!(combine(&[dec, shares[0].clone()])? == key);
+
+        // a single altered digit is caught by the checksum
+        let mut altered = enc.to_string();
+        let c = if altered.ends_with('0') { "1" } else { "0" };
+        altered.replace_range(altered.len() - 1.., c);
+        assert!(matches!(
+            Share::decode(&altered).err().unwrap().kind(),
+            ErrorKind::InvalidShare { .. }
+        ));
+        assert!(Share::decode("fpes1-zz").is_err());
+        assert!(Share::decode("0011").is_err());
+
+        Ok(())
+    }
+}
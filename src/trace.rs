@@ -0,0 +1,329 @@
+//! Tracing of the intermediate values of the Feistel rounds
+//!
+//! When the output of this library disagrees with that of another
+//! implementation, the values computed in each round show where the
+//! two diverge. A [`Tracer`] supplied to the builder of a context, e.g.
+//! [`ff1::Builder::tracer`](crate::ff1::Builder::tracer), is called at
+//! the end of every round of every operation performed by the context.
+//! The [`Display`](std::fmt::Display) implementation of [`Round`]
+//! formats the values like the NIST sample documents.
+//!
+//! This module is only available with the `trace` feature. The values
+//! are derived from the plaintext and the key and are not wiped, so
+//! tracing is meant for diagnostics, not for production use.
+//!
+//! # Example
+//! ```rust
+//! use fpe::trace::Recorder;
+//! use std::sync::Arc;
+//!
+//! let rec = Arc::new(Recorder::new());
+//! let ff1 = fpe::ff1::FF1::builder()
+//!     .key(&[
+//!         0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+//!         0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+//!     ])
+//!     .radix(10)
+//!     .tracer(rec.clone())
+//!     .build()
+//!     .unwrap();
+//!
+//! ff1.encrypt("0123456789", None).unwrap();
+//!
+//! let rounds = rec.take();
+//! assert!(rounds.len() == 10);
+//! assert!(rounds[0].y == "14103068008476060536");
+//! ```
+
+use crate::cipher::Algorithm;
+
+use std::fmt;
+use std::sync::Mutex;
+
+/// The values computed in a single round of FF1 or FF3-1
+///
+/// The names of the values are those used by NIST SP 800-38G. Numbers
+/// are given in decimal, and strings are given as the positions of
+/// their letters in the alphabet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub algorithm: Algorithm,
+    /// The round number, which counts down during decryption
+    pub i: usize,
+    /// For FF1, the block P of step 5, which is the same in every
+    /// round. For FF3-1, the block P of the round
+    pub p: Vec<u8>,
+    /// The block Q of the round (FF1 only)
+    pub q: Option<Vec<u8>>,
+    /// The output of PRF(P || Q) (FF1 only)
+    pub r: Option<Vec<u8>>,
+    pub s: Vec<u8>,
+    pub y: String,
+    pub c: String,
+    /// A at the end of the round
+    pub a: Vec<u16>,
+    /// B at the end of the round
+    pub b: Vec<u16>,
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list<T: fmt::Display>(v: &[T]) -> String {
+            v.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        writeln!(f, "Round #{}", self.i)?;
+        match (&self.q, &self.r) {
+            (Some(q), Some(r)) => {
+                writeln!(f, "  Q is {}", list(q))?;
+                writeln!(f, "  PQ is {}", list(&[&self.p[..], q].concat()))?;
+                writeln!(f, "  R is {}", list(r))?;
+            }
+            _ => writeln!(f, "  P is {}", list(&self.p))?,
+        }
+        writeln!(f, "  S is {}", list(&self.s))?;
+        writeln!(f, "  y is {}", self.y)?;
+        writeln!(f, "  c is {}", self.c)?;
+        writeln!(f, "  A is {}", list(&self.a))?;
+        write!(f, "  B is {}", list(&self.b))
+    }
+}
+
+/// A receiver of the values computed in each round
+///
+/// Any function or closure taking a [`Round`] is a tracer.
+pub trait Tracer: Send + Sync {
+    fn round(&self, round: &Round);
+}
+
+impl<F: Fn(&Round) + Send + Sync> Tracer for F {
+    fn round(&self, round: &Round) {
+        self(round)
+    }
+}
+
+/// A tracer that records the rounds it receives
+#[derive(Debug, Default)]
+pub struct Recorder {
+    rounds: Mutex<Vec<Round>>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove and return the rounds recorded so far
+    pub fn take(&self) -> Vec<Round> {
+        std::mem::take(&mut *self.rounds.lock().unwrap())
+    }
+}
+
+impl Tracer for Recorder {
+    fn round(&self, round: &Round) {
+        self.rounds.lock().unwrap().push(round.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::acvp::{Direction, VectorSet};
+    use crate::ff1::FF1;
+    use crate::ff3_1::FF3_1;
+    use crate::result::Result;
+
+    use std::sync::Arc;
+
+    const KEY: [u8; 32] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88,
+        0x09, 0xcf, 0x4f, 0x3c, 0xef, 0x43, 0x59, 0xd8, 0xd5, 0x80, 0xaa, 0x4f,
+        0x7f, 0x03, 0x6d, 0x6f, 0x04, 0xfc, 0x6a, 0x94,
+    ];
+    const TWEAK_10: &[u8] = b"9876543210";
+    const ALPHA_36: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+    fn trace(
+        key: &[u8],
+        tweak: &[u8],
+        radix: usize,
+        pt: &str,
+    ) -> Result<(String, Vec<Round>)> {
+        let rec = Arc::new(Recorder::new());
+        let ff1 = FF1::builder()
+            .key(key)
+            .tweak(tweak)
+            .radix(radix)
+            .alphabet(ALPHA_36)
+            .tracer(rec.clone())
+            .build()?;
+        let ct = ff1.encrypt(pt, None)?;
+        Ok((ct, rec.take()))
+    }
+
+    // nist ff1 sample #1, every round
+    #[test]
+    fn sample1() -> Result<()> {
+        let (ct, rounds) = trace(&KEY[..16], &[], 10, "0123456789")?;
+        assert!(ct == "2433477484");
+        assert!(rounds.len() == 10);
+
+        let r = &rounds[0];
+        assert!(r.algorithm == Algorithm::FF1);
+        assert!(r.p == [1, 2, 1, 0, 0, 10, 10, 5, 0, 0, 0, 10, 0, 0, 0, 0]);
+        assert!(
+            r.q.as_deref()
+                == Some(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 221, 213])
+        );
+        assert!(
+            r.r.as_deref()
+                == Some(&[
+                    195, 184, 41, 161, 232, 100, 43, 120, 204, 41, 148, 123,
+                    59, 147, 219, 99
+                ])
+        );
+        assert!(r.s == [195, 184, 41, 161, 232, 100, 43, 120]);
+
+        // each round's A is the previous round's B
+        let mut a = [5, 6, 7, 8, 9];
+        for (i, (r, (y, c, b))) in rounds
+            .iter()
+            .zip([
+                ("14103068008476060536", "61770", [6, 1, 7, 7, 0]),
+                ("8747184128798655248", "12037", [1, 2, 0, 3, 7]),
+                ("685755756528994867", "56637", [5, 6, 6, 3, 7]),
+                ("9015538716128482370", "94407", [9, 4, 4, 0, 7]),
+                ("4862289542687487727", "44364", [4, 4, 3, 6, 4]),
+                ("15616947223490990358", "84765", [8, 4, 7, 6, 5]),
+                ("13695186585294339560", "83924", [8, 3, 9, 2, 4]),
+                ("5699619512164348570", "33335", [3, 3, 3, 3, 5]),
+                ("13993940188006640410", "24334", [2, 4, 3, 3, 4]),
+                ("10359645068231344149", "77484", [7, 7, 4, 8, 4]),
+            ])
+            .enumerate()
+        {
+            assert!(r.i == i && r.y == y && r.c == c);
+            assert!(r.a == a && r.b == b);
+            a = b;
+        }
+
+        let s = rounds[0].to_string();
+        assert!(s.starts_with("Round #0\n  Q is 0, 0,"));
+        assert!(s.contains("\n  PQ is 1, 2, 1, 0, 0, 10, 10, 5,"));
+        assert!(s.ends_with("\n  B is 6, 1, 7, 7, 0"));
+
+        Ok(())
+    }
+
+    // every nist ff1 sample ends with the ciphertext in A and B
+    #[test]
+    fn samples() -> Result<()> {
+        let vs = VectorSet::parse(
+            include_str!("fips/vectors/ff1-prompt.json"),
+            include_str!("fips/vectors/ff1-expectedResults.json"),
+        )?;
+
+        for tc in vs.cases() {
+            if tc.direction != Direction::Encrypt {
+                continue;
+            }
+
+            let rec = Arc::new(Recorder::new());
+            let ff1 = FF1::builder()
+                .key(&tc.key)
+                .radix(tc.radix)
+                .alphabet(tc.alphabet.as_str())
+                .tracer(rec.clone())
+                .build()?;
+            assert!(ff1.encrypt(&tc.input, Some(&tc.tweak))? == tc.expected);
+
+            let rounds = rec.take();
+            assert!(rounds.len() == 10);
+
+            let last = &rounds[9];
+            let digits: String = [&last.a[..], &last.b[..]]
+                .concat()
+                .iter()
+                .map(|&d| tc.alphabet.as_bytes()[d as usize] as char)
+                .collect();
+            assert!(digits == tc.expected);
+
+            for (i, r) in rounds.iter().enumerate() {
+                let q = r.q.as_ref().unwrap();
+                assert!(r.i == i);
+                assert!(q[..tc.tweak.len()] == tc.tweak[..]);
+                assert!(r.r.as_ref().unwrap()[..r.s.len()] == r.s);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn ff1_decrypt() -> Result<()> {
+        let rec = Arc::new(Recorder::new());
+        let ff1 = FF1::builder()
+            .key(&KEY[..16])
+            .tweak(TWEAK_10)
+            .radix(10)
+            .tracer(rec.clone())
+            .build()?;
+
+        ff1.encrypt("0123456789", None)?;
+        let enc = rec.take();
+        ff1.decrypt("6124200773", None)?;
+        let dec = rec.take();
+
+        // decryption counts the rounds down, from 9 to 0, computing
+        // the same values of each round as encryption did. A and B
+        // at the end of each round of decryption are their values at
+        // the start of the same round of encryption
+        assert!(enc.len() == 10 && dec.len() == 10);
+        let mut ab = (vec![0, 1, 2, 3, 4], vec![5, 6, 7, 8, 9]);
+        for (e, d) in enc.iter().zip(dec.iter().rev()) {
+            assert!(e.i == d.i && e.q == d.q && e.r == d.r && e.y == d.y);
+            assert!(d.a == ab.0 && d.b == ab.1);
+            ab = (e.a.clone(), e.b.clone());
+        }
+        assert!(dec[0].i == 9 && dec[9].i == 0);
+        assert!(enc[9].a == [6, 1, 2, 4, 2] && enc[9].b == [0, 0, 7, 7, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ff3_1_decrypt() -> Result<()> {
+        let rec = Arc::new(Recorder::new());
+        let tracer = rec.clone();
+        let ff3_1 = FF3_1::builder()
+            .key(&[
+                0xad, 0x41, 0xec, 0x5d, 0x23, 0x56, 0xde, 0xae, 0x53, 0xae,
+                0x76, 0xf5, 0x0b, 0x4b, 0xa6, 0xd2,
+            ])
+            .tweak(&[0xcf, 0x29, 0xda, 0x1e, 0x18, 0xd9, 0x70])
+            .radix(10)
+            .tracer(Arc::new(move |r: &Round| tracer.round(r)))
+            .build()?;
+
+        ff3_1.encrypt("6520935496", None)?;
+        let enc = rec.take();
+        ff3_1.decrypt("4716569208", None)?;
+        let dec = rec.take();
+
+        // the rounds of decryption are those of encryption, in
+        // reverse, with the roles of A and B (and the numerical
+        // values, c, of their halves) exchanged
+        assert!(enc.len() == 8 && dec.len() == 8);
+        for (e, d) in enc.iter().zip(dec.iter().rev()) {
+            assert!(e.algorithm == Algorithm::FF3_1 && e.q.is_none());
+            assert!(e.i == d.i && e.p == d.p && e.s == d.s && e.y == d.y);
+        }
+        assert!(enc[7].a == [4, 7, 1, 6, 5] && enc[7].b == [6, 9, 2, 0, 8]);
+        assert!(dec[7].a == [6, 5, 2, 0, 9] && dec[7].b == [3, 5, 4, 9, 6]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+mod tests {
+    mod acvp {
+        use fpe::acvp::VectorSet;
+        use fpe::result::Result;
+
+        use std::fs;
+        use std::path::{Path, PathBuf};
+
+        fn data_dir() -> PathBuf {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data")
+        }
+
+        // the sample sets are those of the fips self-tests
+        fn sample_dir() -> PathBuf {
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/fips/vectors")
+        }
+
+        fn run(prompt: &Path, expected: &Path) -> Result<()> {
+            let read = |p: &Path| fs::read_to_string(p).unwrap();
+
+            let vs = VectorSet::parse(&read(prompt), &read(expected))?;
+            let report = vs.run();
+            assert!(report.is_success(), "{}: {}", prompt.display(), report);
+            assert!(report.total() == vs.cases().len());
+            assert!(report.total() > 0, "{}: no tests", prompt.display());
+
+            Ok(())
+        }
+
+        fn run_sample(name: &str) -> Result<()> {
+            let dir = sample_dir();
+            run(
+                &dir.join(format!("{}-prompt.json", name)),
+                &dir.join(format!("{}-expectedResults.json", name)),
+            )
+        }
+
+        #[test]
+        fn ff1() -> Result<()> {
+            run_sample("ff1")
+        }
+
+        #[test]
+        fn ff3_1() -> Result<()> {
+            run_sample("ff3_1")
+        }
+
+        // official vector sets are kept, as downloaded, in a
+        // subdirectory per set. each must contain at least one test
+        #[test]
+        #[ignore = "requires the official sets, see tests/data/README.md"]
+        fn official() -> Result<()> {
+            let mut sets = 0;
+            for entry in fs::read_dir(data_dir()).unwrap() {
+                let dir = entry.unwrap().path();
+                if dir.is_dir() {
+                    run(
+                        &dir.join("prompt.json"),
+                        &dir.join("expectedResults.json"),
+                    )?;
+                    sets += 1;
+                }
+            }
+            assert!(sets > 0, "no official vector sets in tests/data");
+
+            Ok(())
+        }
+    }
+}
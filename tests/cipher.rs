@@ -0,0 +1,293 @@
+mod tests {
+    mod cipher {
+        use fpe::cipher::FormatPreservingCipher;
+        use fpe::result::Result;
+
+        fn contexts(
+        ) -> Result<Vec<Box<dyn FormatPreservingCipher + Send + Sync>>>
+        {
+            Ok(vec![
+                Box::new(fpe::ff1::FF1::new(&[0; 16], None, 0, 0, 10, None)?),
+                Box::new(fpe::ff3_1::FF3_1::new(
+                    &[0; 16],
+                    Some(&[0; 7]),
+                    10,
+                    None,
+                )?),
+            ])
+        }
+
+        #[test]
+        fn round_trip() -> Result<()> {
+            for c in contexts()? {
+                let ct = c.encrypt("0123456789", None)?;
+                assert!(c.decrypt(&ct, None)? == "0123456789");
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn limits() -> Result<()> {
+            let c = contexts()?;
+
+            assert!(c[0].radix() == 10);
+            assert!(c[0].min_len() == 6);
+            assert!(c[0].max_len() == (1 << 32) - 1);
+            assert!(c[0].min_tweak_len() == 0);
+            assert!(c[0].max_tweak_len().is_none());
+
+            assert!(c[1].radix() == 10);
+            assert!(c[1].min_len() == 6);
+            assert!(c[1].max_len() == 56);
+            assert!(c[1].min_tweak_len() == 7);
+            assert!(c[1].max_tweak_len() == Some(7));
+
+            Ok(())
+        }
+
+        #[test]
+        fn send_and_sync() -> Result<()> {
+            let c = std::sync::Arc::new(contexts()?);
+            let t = {
+                let c = c.clone();
+                std::thread::spawn(move || c[0].encrypt("0123456789", None))
+            };
+            assert!(t.join().unwrap()? == c[0].encrypt("0123456789", None)?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn validate() -> Result<()> {
+            use fpe::cipher::InvalidCharacter;
+
+            for c in contexts()? {
+                assert!(c.validate("0123456789").is_valid());
+
+                let v = c.validate("0a23b567c9");
+                assert!(!v.is_valid());
+                assert!(v.is_valid_length());
+                assert!(
+                    v.invalid_characters()
+                        == [('a', 1), ('b', 4), ('c', 8)].map(
+                            |(ch, position)| {
+                                InvalidCharacter { ch, position }
+                            }
+                        )
+                );
+
+                let e = c.encrypt("0a23b567c9", None).err().unwrap();
+                let v = v.into_result().err().unwrap();
+                assert!(e.kind() == v.kind());
+                assert!(
+                    *e.kind()
+                        == fpe::error::ErrorKind::InvalidCharacter {
+                            ch: 'a',
+                            position: 1
+                        }
+                );
+
+                let v = c.validate("012");
+                assert!(!v.is_valid() && v.invalid_characters().is_empty());
+                assert!(v.length() == 3 && v.min_len() == c.min_len());
+                assert!(v.max_len() == c.max_len());
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn master_key() -> Result<()> {
+            use fpe::cipher::Algorithm;
+            use fpe::kdf::{self, KeySize};
+
+            let master = [0xa5; 32];
+            let key = kdf::derive_key(
+                &master,
+                "ssn",
+                Algorithm::FF1,
+                KeySize::Aes128,
+            )?;
+
+            let ff1 = fpe::ff1::FF1::from_master_key(
+                &master,
+                "ssn",
+                KeySize::Aes128,
+                10,
+                None,
+            )?;
+            let exp =
+                fpe::ff1::FF1::new(key.expose_secret(), None, 0, 0, 10, None)?;
+            let ct = ff1.encrypt("123456789", None)?;
+            assert!(ct == exp.encrypt("123456789", None)?);
+
+            let other = fpe::ff1::FF1::from_master_key(
+                &master,
+                "phone",
+                KeySize::Aes128,
+                10,
+                None,
+            )?;
+            assert!(ct != other.encrypt("123456789", None)?);
+
+            let ff3_1 = fpe::ff3_1::FF3_1::builder()
+                .master_key(&master, "ssn", KeySize::Aes256)
+                .tweak(&[0; 7])
+                .radix(10)
+                .build()?;
+            assert!(ff3_1.algorithm() == Algorithm::FF3_1);
+            let ct = ff3_1.encrypt("123456789", None)?;
+            assert!(ff3_1.decrypt(&ct, None)? == "123456789");
+
+            let res = fpe::ff1::FF1::from_master_key(
+                &master[..8],
+                "ssn",
+                KeySize::Aes128,
+                10,
+                None,
+            );
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidKey { length: 8 }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn reencrypt() -> Result<()> {
+            let from = contexts()?;
+            let to = fpe::ff1::FF1::new(&[1; 16], None, 0, 0, 10, None)?;
+
+            for c in &from {
+                let ct = c.encrypt("0123456789", None)?;
+                let exp = to.encrypt("0123456789", Some(b"twk"))?;
+                assert!(
+                    fpe::cipher::reencrypt(
+                        &ct,
+                        c.as_ref(),
+                        None,
+                        &to,
+                        Some(b"twk")
+                    )? == exp
+                );
+            }
+
+            let pts = ["0123456789", "9876543210", "x", "5555555555"];
+            let cts: Vec<String> = pts
+                .iter()
+                .map(|pt| from[0].encrypt(pt, None).unwrap_or(pt.to_string()))
+                .collect();
+            let res: Vec<Result<String>> = fpe::cipher::reencrypt_batch(
+                &cts,
+                from[0].as_ref(),
+                None,
+                from[1].as_ref(),
+                None,
+            )
+            .collect();
+
+            assert!(res.len() == 4);
+            for (pt, r) in pts.iter().zip(res) {
+                match r {
+                    Ok(ct) => assert!(from[1].decrypt(&ct, None)? == *pt),
+                    Err(_) => assert!(*pt == "x"),
+                }
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn provider() -> Result<()> {
+            use fpe::provider::MemoryKeyProvider;
+
+            let mut keys = MemoryKeyProvider::new();
+            keys.insert("ssn", 1, &[1; 16], None)?;
+            keys.insert("ssn", 2, &[2; 16], None)?;
+
+            let v1 = fpe::ff1::FF1::new(&[1; 16], None, 0, 0, 10, None)?;
+            let v2 = fpe::ff3_1::FF3_1::new(&[2; 16], Some(&[0; 7]), 10, None)?;
+            let ct1 = v1.encrypt("123456789", None)?;
+            let ct2 = v2.encrypt("123456789", None)?;
+
+            let ff1 =
+                fpe::ff1::FF1::from_provider(&keys, "ssn", Some(1), 10, None)?;
+            assert!(ff1.encrypt("123456789", None)? == ct1);
+
+            let ff3_1 = fpe::ff3_1::FF3_1::builder()
+                .provider_key(&keys, "ssn", None)
+                .tweak(&[0; 7])
+                .radix(10)
+                .build()?;
+            assert!(ff3_1.encrypt("123456789", None)? == ct2);
+
+            let res =
+                fpe::ff1::FF1::from_provider(&keys, "dob", None, 10, None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::UnknownKey { .. }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn wrapped_key() -> Result<()> {
+            use fpe::keywrap;
+
+            let kek = [0x42; 24];
+            let v1 = fpe::ff1::FF1::new(&[1; 16], None, 0, 0, 10, None)?;
+            let v2 = fpe::ff3_1::FF3_1::new(&[2; 32], Some(&[0; 7]), 10, None)?;
+            let ct1 = v1.encrypt("123456789", None)?;
+            let ct2 = v2.encrypt("123456789", None)?;
+
+            let wrapped = keywrap::wrap(&kek, &[1; 16])?;
+            let ff1 =
+                fpe::ff1::FF1::from_wrapped_key(&wrapped, &kek, 10, None)?;
+            assert!(ff1.encrypt("123456789", None)? == ct1);
+
+            let wrapped = keywrap::wrap_with_padding(&kek, &[2; 32])?;
+            let ff3_1 = fpe::ff3_1::FF3_1::builder()
+                .wrapped_key(&wrapped, &kek)
+                .tweak(&[0; 7])
+                .radix(10)
+                .build()?;
+            assert!(ff3_1.encrypt("123456789", None)? == ct2);
+
+            let res = fpe::ff1::FF1::from_wrapped_key(
+                &wrapped,
+                &[0x24; 24],
+                10,
+                None,
+            );
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidWrappedKey
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn key_check_value() -> Result<()> {
+            let key = [
+                0xef, 0x43, 0x59, 0xd8, 0xd5, 0x80, 0xaa, 0x4f, 0x7f, 0x03,
+                0x6d, 0x6f, 0x04, 0xfc, 0x6a, 0x94,
+            ];
+            let ff1 = fpe::ff1::FF1::new(&key, None, 0, 0, 10, None)?;
+            let ff3_1 = fpe::ff3_1::FF3_1::new(&key, Some(&[0; 7]), 10, None)?;
+            let other = fpe::ff1::FF1::new(&[0; 16], None, 0, 0, 10, None)?;
+
+            // both algorithms report the values of the key as supplied
+            let sk = fpe::key::SecretKey::new(&key);
+            assert!(ff1.key_check_value() == sk.check_value()?);
+            assert!(ff3_1.key_check_value() == ff1.key_check_value());
+            assert!(ff3_1.key_fingerprint() == ff1.key_fingerprint());
+            assert!(other.key_fingerprint() != ff1.key_fingerprint());
+
+            Ok(())
+        }
+    }
+}
@@ -21,6 +21,15 @@ mod tests {
             assert!(ct == fpe::ff1::encrypt(k, opt_t, pt, r, opt_a)?);
             assert!(pt == fpe::ff1::decrypt(k, opt_t, ct, r, opt_a)?);
 
+            let mut b = fpe::ff1::FF1::builder().key(k).radix(r);
+            if let Some(t) = opt_t {
+                b = b.tweak(t);
+            }
+            if let Some(a) = opt_a {
+                b = b.alphabet(a);
+            }
+            assert!(ct == b.build()?.encrypt(pt, None)?);
+
             Ok(())
         }
 
@@ -182,5 +191,244 @@ mod tests {
                 None,
             )
         }
+
+        #[test]
+        fn builder_missing_key() -> Result<()> {
+            let res = fpe::ff1::FF1::builder().radix(10).build();
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::MissingParameter { name: "key" }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn builder_radix_from_alphabet() -> Result<()> {
+            let ff1 = fpe::ff1::FF1::builder()
+                .key(&[0; 16])
+                .alphabet("0123456789abcdef")
+                .build()?;
+
+            let ct = ff1.encrypt("0123456789abcdef", None)?;
+            assert!(
+                ct == fpe::ff1::encrypt(
+                    &[0; 16],
+                    None,
+                    "0123456789abcdef",
+                    16,
+                    None
+                )?
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn custom_alphabet() -> Result<()> {
+            // the uppercase letters, defined arithmetically
+            struct Upper;
+            impl fpe::alphabet::Alphabet for Upper {
+                fn len(&self) -> usize {
+                    26
+                }
+                fn char_at(&self, pos: usize) -> Option<char> {
+                    if pos < 26 {
+                        Some((b'A' + pos as u8) as char)
+                    } else {
+                        None
+                    }
+                }
+                fn position_of(&self, c: char) -> Option<usize> {
+                    if c.is_ascii_uppercase() {
+                        Some(c as usize - 'A' as usize)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            let k = [0; 16];
+            let ff1 = fpe::ff1::FF1::new(&k, None, 0, 0, 26, Upper)?;
+            let ct = ff1.encrypt("HELLOWORLD", None)?;
+            assert!(
+                ct == fpe::ff1::encrypt(
+                    &k,
+                    None,
+                    "HELLOWORLD",
+                    26,
+                    Some("ABCDEFGHIJKLMNOPQRSTUVWXYZ")
+                )?
+            );
+            assert!(ff1.decrypt(&ct, None)? == "HELLOWORLD");
+
+            Ok(())
+        }
+
+        #[test]
+        fn preset_alphabet() -> Result<()> {
+            use fpe::alphabet::Preset;
+
+            let k = [0; 16];
+            let ff1 = fpe::ff1::FF1::builder()
+                .key(&k)
+                .alphabet("hex-lower".parse::<Preset>()?)
+                .build()?;
+            let ct = ff1.encrypt("0123456789abcdef", None)?;
+            assert!(
+                ct == fpe::ff1::encrypt(
+                    &k,
+                    None,
+                    "0123456789abcdef",
+                    16,
+                    Some(fpe::alphabet::HEX_LOWER)
+                )?
+            );
+
+            // the case insensitive preset accepts either case as
+            // input but always produces lowercase output
+            let ff1 = fpe::ff1::FF1::new(
+                &k,
+                None,
+                0,
+                0,
+                36,
+                Preset::AlphanumericCaseInsensitive,
+            )?;
+            let ct = ff1.encrypt("HELLOWORLD", None)?;
+            assert!(ct == ff1.encrypt("helloworld", None)?);
+            assert!(ff1.decrypt(&ct, None)? == "helloworld");
+
+            Ok(())
+        }
+
+        #[test]
+        fn unicode_alphabet() -> Result<()> {
+            use fpe::alphabet::unicode::GeneralCategory;
+            use fpe::alphabet::CharAlphabet;
+
+            let han = CharAlphabet::from_categories(
+                &['\u{3400}'..='\u{4dbf}', '\u{4e00}'..='\u{9fff}'],
+                &[GeneralCategory::OtherLetter],
+            )?;
+            let ff1 = fpe::ff1::FF1::builder()
+                .key(&[0; 16])
+                .alphabet(han)
+                .build()?;
+
+            let pt = "\u{4e2d}\u{6587}\u{5b57}\u{7b26}";
+            let ct = ff1.encrypt(pt, None)?;
+            assert!(ct.chars().count() == 4);
+            assert!(ff1.decrypt(&ct, None)? == pt);
+
+            Ok(())
+        }
+
+        #[test]
+        fn symbol_alphabet() -> Result<()> {
+            use fpe::alphabet::SymbolAlphabet;
+
+            // the accented letters are written with combining marks
+            let alpha = SymbolAlphabet::from_graphemes(
+                "abcdefghijklmnopqrstuvwxyza\u{300}e\u{301}e\u{300}u\u{308}",
+            )?;
+            let ff1 = fpe::ff1::FF1::builder()
+                .key(&[0; 16])
+                .alphabet(alpha)
+                .build()?;
+            assert!(ff1.encrypt("abc", None).is_err());
+
+            let pt = "cre\u{300}mebru\u{308}le\u{301}e";
+            let ct = ff1.encrypt(pt, None)?;
+            assert!(ct != pt);
+            assert!(ff1.decrypt(&ct, None)? == pt);
+
+            // the position is that of the character, not the letter
+            let res = ff1.encrypt("e\u{301}e\u{301}!abcdef", None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidCharacter {
+                    ch: '!',
+                    position: 4
+                }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn invalid_character() -> Result<()> {
+            let ff1 = fpe::ff1::FF1::new(&[0; 16], None, 0, 0, 10, None)?;
+
+            let res = ff1.encrypt("01234a6789", None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidCharacter {
+                    ch: 'a',
+                    position: 5
+                }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn invalid_text_length() -> Result<()> {
+            let ff1 = fpe::ff1::FF1::new(&[0; 16], None, 0, 0, 10, None)?;
+
+            let res = ff1.encrypt("01234", None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidTextLength {
+                    min: 6,
+                    actual: 5,
+                    ..
+                }
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn input_options() -> Result<()> {
+            use fpe::alphabet::input::{
+                CaseMatching, InputOptions, Normalization,
+            };
+
+            let b = fpe::ff1::FF1::builder()
+                .key(&[0; 16])
+                .alphabet("0123456789abcdef\u{e9}");
+            let opts = InputOptions::new()
+                .normalization(Normalization::Nfc)
+                .ascii_digits(true);
+
+            let ff1 = b
+                .clone()
+                .input_options(opts.case(CaseMatching::Insensitive))
+                .build()?;
+            let ct = ff1.encrypt("0123abcde\u{e9}", None)?;
+            assert!(ff1.encrypt("0123ABCDE\u{c9}", None)? == ct);
+            assert!(
+                ff1.encrypt("\u{ff10}\u{661}\u{6f2}3abcdeE\u{301}", None)?
+                    == ct
+            );
+            assert!(ff1.decrypt(&ct, None)? == "0123abcde\u{e9}");
+
+            let ff1 =
+                b.input_options(opts.case(CaseMatching::Preserve)).build()?;
+            let ct = ff1.encrypt("0123ABCde\u{e9}", None)?;
+            assert!(ct.to_lowercase() == ff1.encrypt("0123abcde\u{e9}", None)?);
+            // letters of the output are only upper case where
+            // the letters of the input were upper case
+            assert!(ct
+                .chars()
+                .enumerate()
+                .all(|(i, c)| (4..7).contains(&i) || !c.is_uppercase()));
+            assert!(
+                ff1.decrypt(&ct, None)?.to_lowercase() == "0123abcde\u{e9}"
+            );
+
+            Ok(())
+        }
     }
 }
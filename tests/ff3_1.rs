@@ -21,6 +21,15 @@ mod tests {
             assert!(ct == fpe::ff3_1::encrypt(k, opt_t, pt, r, opt_a)?);
             assert!(pt == fpe::ff3_1::decrypt(k, opt_t, ct, r, opt_a)?);
 
+            let mut b = fpe::ff3_1::FF3_1::builder().key(k).radix(r);
+            if let Some(t) = opt_t {
+                b = b.tweak(t);
+            }
+            if let Some(a) = opt_a {
+                b = b.alphabet(a);
+            }
+            assert!(ct == b.build()?.encrypt(pt, None)?);
+
             Ok(())
         }
 
@@ -173,5 +182,22 @@ mod tests {
                 None,
             )
         }
+
+        #[test]
+        fn invalid_tweak_length() -> Result<()> {
+            let ff3_1 = fpe::ff3_1::FF3_1::new(&[0; 16], None, 10, None)?;
+
+            let res = ff3_1.encrypt("0123456789", Some(&[0; 8]));
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                fpe::error::ErrorKind::InvalidTweakLength {
+                    min: 7,
+                    max: Some(7),
+                    actual: 8
+                }
+            ));
+
+            Ok(())
+        }
     }
 }
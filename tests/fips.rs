@@ -0,0 +1,84 @@
+// fips mode applies to the whole process, so these tests are
+// kept apart from the others, which create unapproved contexts
+mod tests {
+    mod fips {
+        use fpe::error::ErrorKind;
+        use fpe::result::Result;
+
+        #[test]
+        fn enabled() -> Result<()> {
+            fpe::fips::enable()?;
+            assert!(fpe::fips::is_enabled());
+
+            // the tweak length of ff1 need not be bounded
+            for maxtwk in [0, 64] {
+                let ff1 =
+                    fpe::ff1::FF1::new(&[0; 16], None, 0, maxtwk, 10, None)?;
+                let ct = ff1.encrypt("0123456789", None)?;
+                assert!(ff1.decrypt(&ct, None)? == "0123456789");
+            }
+
+            // but a bound must be one that ff1 can encode
+            let res = fpe::ff1::FF1::new(&[0; 16], None, 0, 1 << 32, 10, None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::NotApproved { .. }
+            ));
+
+            // the maximum length of ff3-1 text is that of the standard
+            let ff3_1 =
+                fpe::ff3_1::FF3_1::new(&[0; 16], Some(&[0; 7]), 10, None)?;
+            assert!(fpe::cipher::FormatPreservingCipher::max_len(&ff3_1) == 56);
+            let res = ff3_1.encrypt(&"0".repeat(57), None);
+            assert!(matches!(
+                res.err().unwrap().kind(),
+                ErrorKind::InvalidTextLength { .. }
+            ));
+
+            Ok(())
+        }
+
+        // each way of creating an ff1 context, none of which
+        // bounds the length of the tweak, is approved
+        #[test]
+        fn ff1_constructors() -> Result<()> {
+            use fpe::cipher::{Algorithm, FormatPreservingCipher};
+            use fpe::ff1::FF1;
+            use fpe::kdf::KeySize;
+            use fpe::keystore::{Keystore, PassphraseKdf};
+            use fpe::provider::MemoryKeyProvider;
+
+            fpe::fips::enable()?;
+
+            let mut keys = MemoryKeyProvider::new();
+            keys.insert("a", 1, &[1; 16], Some(Algorithm::FF1))?;
+            let wrapped = fpe::keywrap::wrap(&[2; 16], &[3; 16])?;
+            let mut ks = Keystore::new(
+                "secret",
+                PassphraseKdf::Pbkdf2Sha256 { iterations: 10 },
+            )?;
+            ks.insert("a", &[4; 16], Algorithm::FF1, 10, None)?;
+
+            let ctxs: Vec<Box<dyn FormatPreservingCipher>> = vec![
+                Box::new(FF1::from_master_key(
+                    &[5; 32],
+                    "a",
+                    KeySize::Aes256,
+                    10,
+                    None,
+                )?),
+                Box::new(FF1::from_provider(&keys, "a", None, 10, None)?),
+                Box::new(FF1::from_wrapped_key(&wrapped, &[2; 16], 10, None)?),
+                Box::new(ks.ff1("a")?),
+                ks.context("a")?,
+            ];
+            for ctx in ctxs {
+                assert!(ctx.max_tweak_len().is_none());
+                let ct = ctx.encrypt("0123456789", Some(b"tweak"))?;
+                assert!(ctx.decrypt(&ct, Some(b"tweak"))? == "0123456789");
+            }
+
+            Ok(())
+        }
+    }
+}
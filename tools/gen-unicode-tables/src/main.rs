@@ -0,0 +1,134 @@
+//! Generates the general category table of `src/alphabet/unicode/tables.rs`
+//!
+//! From the root of the repository:
+//! ```text
+//! cargo run --manifest-path tools/gen-unicode-tables/Cargo.toml \
+//!     > src/alphabet/unicode/tables.rs
+//! ```
+//!
+//! The table is written to standard output. See the header of the
+//! generated file before replacing it.
+
+use icu_properties::props::GeneralCategory;
+use icu_properties::{CodePointMapData, PropertyParser};
+
+// the version of the unicode character database from which the
+// data in the pinned version of icu_properties was built
+const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+const ICU_PROPERTIES_VERSION: &str = "2.3";
+
+// the abbreviations of the general categories in the order in
+// which their constants are declared by the generated file
+const CATEGORIES: &[(&str, &str)] = &[
+    ("Cc", "Control"),
+    ("Cf", "Format"),
+    ("Co", "PrivateUse"),
+    ("Cs", "Surrogate"),
+    ("Ll", "LowercaseLetter"),
+    ("Lm", "ModifierLetter"),
+    ("Lo", "OtherLetter"),
+    ("Lt", "TitlecaseLetter"),
+    ("Lu", "UppercaseLetter"),
+    ("Mc", "SpacingMark"),
+    ("Me", "EnclosingMark"),
+    ("Mn", "NonspacingMark"),
+    ("Nd", "DecimalNumber"),
+    ("Nl", "LetterNumber"),
+    ("No", "OtherNumber"),
+    ("Pc", "ConnectorPunctuation"),
+    ("Pd", "DashPunctuation"),
+    ("Pe", "ClosePunctuation"),
+    ("Pf", "FinalPunctuation"),
+    ("Pi", "InitialPunctuation"),
+    ("Po", "OtherPunctuation"),
+    ("Ps", "OpenPunctuation"),
+    ("Sc", "CurrencySymbol"),
+    ("Sk", "ModifierSymbol"),
+    ("Sm", "MathSymbol"),
+    ("So", "OtherSymbol"),
+    ("Zl", "LineSeparator"),
+    ("Zp", "ParagraphSeparator"),
+    ("Zs", "SpaceSeparator"),
+];
+
+// the number of ranges written on each line of the table
+const RANGES_PER_LINE: usize = 3;
+
+fn main() {
+    let map = CodePointMapData::<GeneralCategory>::new();
+    let parser = PropertyParser::<GeneralCategory>::new();
+
+    // each abbreviation is looked up by name so that the table
+    // can't silently disagree with the constants it refers to
+    let abbr = |gc: GeneralCategory| {
+        CATEGORIES
+            .iter()
+            .find(|(a, _)| parser.get_strict(a) == Some(gc))
+            .map(|(a, _)| *a)
+            .unwrap_or_else(|| panic!("no abbreviation for {:?}", gc))
+    };
+
+    // maximal runs of consecutive code points in the same category
+    let mut ranges = Vec::<(u32, u32, GeneralCategory)>::new();
+    for cp in 0..=0x10ffff {
+        let gc = map.get32(cp);
+        match ranges.last_mut() {
+            Some(r) if r.2 == gc && r.1 + 1 == cp => r.1 = cp,
+            _ => ranges.push((cp, cp, gc)),
+        }
+    }
+    ranges.retain(|r| r.2 != GeneralCategory::Unassigned);
+
+    let (major, minor, patch) = UNICODE_VERSION;
+    print!(
+        "\
+// This file is generated from version {major}.{minor}.{patch} of the Unicode Character
+// Database (via the data in ICU4X's icu_properties crate, version {ver}).
+// Do not edit it by hand; regenerate it from the root of the repository:
+//
+//   cargo run --manifest-path tools/gen-unicode-tables/Cargo.toml \\
+//       > src/alphabet/unicode/tables.rs
+//
+// Alphabets built from general categories depend on the exact contents
+// of this table, so updating it to a newer version of Unicode changes
+// the radix and letter positions of those alphabets. It may only be
+// regenerated as part of a release that is allowed to break them.
+
+// the general categories are referred to by their abbreviations
+// so that the table remains (somewhat) readable
+#![allow(non_upper_case_globals)]
+
+use super::GeneralCategory;
+
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {patch});
+
+",
+        ver = ICU_PROPERTIES_VERSION,
+    );
+
+    for (a, name) in CATEGORIES {
+        println!("const {a}: GeneralCategory = GeneralCategory::{name};");
+    }
+
+    print!(
+        "
+// inclusive ranges of assigned code points and their general
+// categories, sorted by code point. unassigned code points (Cn)
+// are those that do not appear in the table.
+#[rustfmt::skip]
+pub(super) const GENERAL_CATEGORY: &[(u32, u32, GeneralCategory)] = &[
+"
+    );
+
+    for line in ranges.chunks(RANGES_PER_LINE) {
+        let entries: Vec<String> = line
+            .iter()
+            .map(|(lo, hi, gc)| {
+                format!("(0x{lo:05x}, 0x{hi:05x}, {})", abbr(*gc))
+            })
+            .collect();
+        println!("    {},", entries.join(", "));
+    }
+
+    println!("];");
+}